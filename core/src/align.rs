@@ -11,6 +11,57 @@ pub enum Align {
     End,
 }
 
+/// Alignment of the contents of a flex container, like a `Row` or a
+/// `Column`, on its cross axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AlignItems {
+    /// Align at the start of the cross axis.
+    Start,
+
+    /// Align at the center of the cross axis.
+    Center,
+
+    /// Align at the end of the cross axis.
+    End,
+
+    /// Stretch to fill the whole cross axis.
+    Stretch,
+}
+
+impl From<Align> for AlignItems {
+    fn from(align: Align) -> Self {
+        match align {
+            Align::Start => AlignItems::Start,
+            Align::Center => AlignItems::Center,
+            Align::End => AlignItems::End,
+        }
+    }
+}
+
+/// Distribution of the contents of a container along its main axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Justify {
+    /// Pack the contents at the start of the axis.
+    Start,
+
+    /// Pack the contents at the center of the axis.
+    Center,
+
+    /// Pack the contents at the end of the axis.
+    End,
+
+    /// Distribute the free space evenly between the contents, with no space
+    /// at the edges.
+    SpaceBetween,
+
+    /// Distribute the free space evenly around the contents, so the space at
+    /// the edges is half the space between them.
+    SpaceAround,
+
+    /// Distribute the free space evenly between the contents and the edges.
+    SpaceEvenly,
+}
+
 /// The horizontal alignment of some resource.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum HorizontalAlignment {
@@ -0,0 +1,20 @@
+//! Animate values over time.
+//!
+//! An [`Animated`] value moves towards its target following a [`Transition`].
+//! It can be sampled at any [`Instant`], producing a [`Frame`] which can be
+//! used to [`Interpolate`] between any values—like the styles of a widget.
+//!
+//! [`Animated`]: struct.Animated.html
+//! [`Transition`]: struct.Transition.html
+//! [`Instant`]: https://doc.rust-lang.org/std/time/struct.Instant.html
+//! [`Frame`]: struct.Frame.html
+//! [`Interpolate`]: trait.Interpolate.html
+mod animated;
+mod easing;
+mod interpolate;
+mod transition;
+
+pub use animated::{Animated, Frame};
+pub use easing::Easing;
+pub use interpolate::Interpolate;
+pub use transition::Transition;
@@ -0,0 +1,254 @@
+use crate::animation::{Interpolate, Transition};
+
+use std::time::Instant;
+
+/// A value that changes over time.
+///
+/// When the target of an [`Animated`] value changes, it moves from its
+/// previous target following a [`Transition`].
+///
+/// [`Animated`]: struct.Animated.html
+/// [`Transition`]: struct.Transition.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Animated<T> {
+    source: T,
+    target: T,
+    started_at: Option<Instant>,
+    transition: Transition,
+}
+
+impl<T> Animated<T>
+where
+    T: Clone + PartialEq,
+{
+    /// Creates a new [`Animated`] value resting at the given value.
+    ///
+    /// [`Animated`]: struct.Animated.html
+    pub fn new(value: T) -> Self {
+        Animated {
+            source: value.clone(),
+            target: value,
+            started_at: None,
+            transition: Transition::default(),
+        }
+    }
+
+    /// Returns the value the [`Animated`] value is moving away from.
+    ///
+    /// [`Animated`]: struct.Animated.html
+    pub fn source(&self) -> &T {
+        &self.source
+    }
+
+    /// Returns the value the [`Animated`] value is moving towards.
+    ///
+    /// [`Animated`]: struct.Animated.html
+    pub fn target(&self) -> &T {
+        &self.target
+    }
+
+    /// Starts moving the [`Animated`] value towards a new target at the given
+    /// [`Instant`], following the provided [`Transition`].
+    ///
+    /// Going back to the previous target before the [`Transition`] completes
+    /// reverses it smoothly.
+    ///
+    /// [`Animated`]: struct.Animated.html
+    /// [`Instant`]: https://doc.rust-lang.org/std/time/struct.Instant.html
+    /// [`Transition`]: struct.Transition.html
+    pub fn transition(
+        &mut self,
+        value: T,
+        transition: Transition,
+        now: Instant,
+    ) {
+        if value == self.target {
+            return;
+        }
+
+        if transition.is_instant() {
+            *self = Animated::new(value);
+            return;
+        }
+
+        let progress = self.linear_progress(now);
+
+        if value == self.source && progress < 1.0 {
+            std::mem::swap(&mut self.source, &mut self.target);
+
+            let elapsed = transition.duration.mul_f32(1.0 - progress);
+
+            self.started_at = Some(now.checked_sub(elapsed).unwrap_or(now));
+        } else {
+            self.source = std::mem::replace(&mut self.target, value);
+            self.started_at = Some(now);
+        }
+
+        self.transition = transition;
+    }
+
+    /// Samples the [`Animated`] value at the given [`Instant`].
+    ///
+    /// [`Animated`]: struct.Animated.html
+    /// [`Instant`]: https://doc.rust-lang.org/std/time/struct.Instant.html
+    pub fn at(&self, now: Instant) -> Frame<T> {
+        Frame {
+            source: self.source.clone(),
+            target: self.target.clone(),
+            progress: self.transition.easing.apply(self.linear_progress(now)),
+        }
+    }
+
+    /// Returns whether the [`Animated`] value is still moving at the given
+    /// [`Instant`].
+    ///
+    /// [`Animated`]: struct.Animated.html
+    /// [`Instant`]: https://doc.rust-lang.org/std/time/struct.Instant.html
+    pub fn is_animating(&self, now: Instant) -> bool {
+        self.linear_progress(now) < 1.0
+    }
+
+    /// Returns the [`Instant`] when the [`Animated`] value needs to be drawn
+    /// again, if it is still moving.
+    ///
+    /// [`Animated`]: struct.Animated.html
+    /// [`Instant`]: https://doc.rust-lang.org/std/time/struct.Instant.html
+    pub fn redraw_request(&self, now: Instant) -> Option<Instant> {
+        if self.is_animating(now) {
+            Some(now)
+        } else {
+            None
+        }
+    }
+
+    fn linear_progress(&self, now: Instant) -> f32 {
+        match self.started_at {
+            Some(started_at) if !self.transition.is_instant() => {
+                let elapsed = now.saturating_duration_since(started_at);
+
+                (elapsed.as_secs_f32() / self.transition.duration.as_secs_f32())
+                    .min(1.0)
+            }
+            _ => 1.0,
+        }
+    }
+}
+
+impl<T> Animated<T>
+where
+    T: Interpolate + Clone + PartialEq,
+{
+    /// Returns the current value of the [`Animated`] value at the given
+    /// [`Instant`].
+    ///
+    /// [`Animated`]: struct.Animated.html
+    /// [`Instant`]: https://doc.rust-lang.org/std/time/struct.Instant.html
+    pub fn value(&self, now: Instant) -> T {
+        self.at(now).interpolate(T::clone)
+    }
+}
+
+/// A sample of an [`Animated`] value.
+///
+/// [`Animated`]: struct.Animated.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Frame<T> {
+    /// The value the [`Animated`] value is moving away from.
+    ///
+    /// [`Animated`]: struct.Animated.html
+    pub source: T,
+
+    /// The value the [`Animated`] value is moving towards.
+    ///
+    /// [`Animated`]: struct.Animated.html
+    pub target: T,
+
+    /// The eased progress of the transition, from `0.0` to `1.0`.
+    pub progress: f32,
+}
+
+impl<T> Frame<T> {
+    /// Creates a [`Frame`] of a value that is not moving.
+    ///
+    /// [`Frame`]: struct.Frame.html
+    pub fn settled(value: T) -> Self
+    where
+        T: Clone,
+    {
+        Frame {
+            source: value.clone(),
+            target: value,
+            progress: 1.0,
+        }
+    }
+
+    /// Blends the images of the source and the target of the [`Frame`] under
+    /// the given function.
+    ///
+    /// This is useful to transition smoothly between the styles of different
+    /// widget statuses.
+    ///
+    /// [`Frame`]: struct.Frame.html
+    pub fn interpolate<U>(&self, f: impl Fn(&T) -> U) -> U
+    where
+        U: Interpolate,
+    {
+        if self.progress >= 1.0 {
+            f(&self.target)
+        } else {
+            f(&self.source).interpolate(&f(&self.target), self.progress)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::animation::Easing;
+
+    use std::time::Duration;
+
+    #[test]
+    fn linear_transition() {
+        let start = Instant::now();
+        let transition =
+            Transition::new(Duration::from_millis(100)).easing(Easing::Linear);
+
+        let mut animated = Animated::new(0.0f32);
+        animated.transition(1.0, transition, start);
+
+        assert_eq!(animated.value(start), 0.0);
+        assert_eq!(animated.value(start + Duration::from_millis(50)), 0.5);
+        assert_eq!(animated.value(start + Duration::from_millis(200)), 1.0);
+
+        assert!(animated.is_animating(start + Duration::from_millis(50)));
+        assert!(!animated.is_animating(start + Duration::from_millis(100)));
+    }
+
+    #[test]
+    fn reversed_transition() {
+        let start = Instant::now();
+        let transition =
+            Transition::new(Duration::from_millis(100)).easing(Easing::Linear);
+
+        let mut animated = Animated::new(0.0f32);
+        animated.transition(1.0, transition, start);
+
+        let now = start + Duration::from_millis(25);
+        animated.transition(0.0, transition, now);
+
+        assert_eq!(animated.value(now), 0.25);
+        assert_eq!(animated.value(now + Duration::from_millis(25)), 0.0);
+    }
+
+    #[test]
+    fn instant_transition() {
+        let now = Instant::now();
+
+        let mut animated = Animated::new(0.0f32);
+        animated.transition(1.0, Transition::default(), now);
+
+        assert_eq!(animated.value(now), 1.0);
+        assert_eq!(animated.redraw_request(now), None);
+    }
+}
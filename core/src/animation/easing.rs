@@ -0,0 +1,45 @@
+/// The rate of change of an animation over time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Easing {
+    /// Constant speed.
+    Linear,
+
+    /// Starts slowly and speeds up.
+    EaseIn,
+
+    /// Starts quickly and slows down.
+    EaseOut,
+
+    /// Starts and ends slowly, speeding up in the middle.
+    #[default]
+    EaseInOut,
+}
+
+impl Easing {
+    /// Applies the [`Easing`] curve to the given linear progress, which must
+    /// be in the `[0, 1]` range.
+    ///
+    /// [`Easing`]: enum.Easing.html
+    pub fn apply(self, progress: f32) -> f32 {
+        let t = progress.clamp(0.0, 1.0);
+
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t * t,
+            Easing::EaseOut => {
+                let t = 1.0 - t;
+
+                1.0 - t * t * t
+            }
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    let t = -2.0 * t + 2.0;
+
+                    1.0 - t * t * t / 2.0
+                }
+            }
+        }
+    }
+}
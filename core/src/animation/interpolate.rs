@@ -0,0 +1,72 @@
+use crate::{Background, Color, Vector};
+
+/// A value that can be blended with another one.
+pub trait Interpolate {
+    /// Blends the value with another one.
+    ///
+    /// A `progress` of `0.0` produces `self`, while a `progress` of `1.0`
+    /// produces `other`.
+    fn interpolate(&self, other: &Self, progress: f32) -> Self;
+}
+
+impl Interpolate for f32 {
+    fn interpolate(&self, other: &Self, progress: f32) -> Self {
+        self + (other - self) * progress
+    }
+}
+
+impl Interpolate for u16 {
+    fn interpolate(&self, other: &Self, progress: f32) -> Self {
+        f32::from(*self)
+            .interpolate(&f32::from(*other), progress)
+            .round() as u16
+    }
+}
+
+impl Interpolate for Color {
+    fn interpolate(&self, other: &Self, progress: f32) -> Self {
+        Color {
+            r: self.r.interpolate(&other.r, progress),
+            g: self.g.interpolate(&other.g, progress),
+            b: self.b.interpolate(&other.b, progress),
+            a: self.a.interpolate(&other.a, progress),
+        }
+    }
+}
+
+impl Interpolate for Vector {
+    fn interpolate(&self, other: &Self, progress: f32) -> Self {
+        Vector::new(
+            self.x.interpolate(&other.x, progress),
+            self.y.interpolate(&other.y, progress),
+        )
+    }
+}
+
+impl Interpolate for Background {
+    fn interpolate(&self, other: &Self, progress: f32) -> Self {
+        match (self, other) {
+            (Background::Color(a), Background::Color(b)) => {
+                Background::Color(a.interpolate(b, progress))
+            }
+        }
+    }
+}
+
+impl Interpolate for Option<Background> {
+    fn interpolate(&self, other: &Self, progress: f32) -> Self {
+        // A missing background fades as a transparent version of the other
+        let transparent = |background: &Background| match background {
+            Background::Color(color) => {
+                Background::Color(Color { a: 0.0, ..*color })
+            }
+        };
+
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.interpolate(b, progress)),
+            (Some(a), None) => Some(a.interpolate(&transparent(a), progress)),
+            (None, Some(b)) => Some(transparent(b).interpolate(b, progress)),
+            (None, None) => None,
+        }
+    }
+}
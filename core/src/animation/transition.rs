@@ -0,0 +1,53 @@
+use crate::animation::Easing;
+
+use std::time::Duration;
+
+/// The timing of the changes of an [`Animated`] value.
+///
+/// By default, a [`Transition`] is instant.
+///
+/// [`Animated`]: struct.Animated.html
+/// [`Transition`]: struct.Transition.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Transition {
+    /// The time it takes to complete the [`Transition`].
+    ///
+    /// [`Transition`]: struct.Transition.html
+    pub duration: Duration,
+
+    /// The [`Easing`] curve of the [`Transition`].
+    ///
+    /// [`Easing`]: enum.Easing.html
+    /// [`Transition`]: struct.Transition.html
+    pub easing: Easing,
+}
+
+impl Transition {
+    /// Creates a new [`Transition`] with the given duration and the default
+    /// [`Easing`].
+    ///
+    /// [`Transition`]: struct.Transition.html
+    /// [`Easing`]: enum.Easing.html
+    pub fn new(duration: Duration) -> Self {
+        Transition {
+            duration,
+            easing: Easing::default(),
+        }
+    }
+
+    /// Sets the [`Easing`] of the [`Transition`].
+    ///
+    /// [`Easing`]: enum.Easing.html
+    /// [`Transition`]: struct.Transition.html
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Returns whether the [`Transition`] completes instantly.
+    ///
+    /// [`Transition`]: struct.Transition.html
+    pub fn is_instant(&self) -> bool {
+        self.duration == Duration::from_secs(0)
+    }
+}
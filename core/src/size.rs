@@ -1,3 +1,4 @@
+use crate::Padding;
 use std::f32;
 
 /// An amount of space in 2 dimensions.
@@ -34,13 +35,14 @@ impl Size {
     /// [`Size`]: struct.Size.html
     pub const INFINITY: Size = Size::new(f32::INFINITY, f32::INFINITY);
 
-    /// Increments the [`Size`] to account for the given padding.
+    /// Increments the [`Size`] to account for the given [`Padding`].
     ///
     /// [`Size`]: struct.Size.html
-    pub fn pad(&self, padding: f32) -> Self {
+    /// [`Padding`]: struct.Padding.html
+    pub fn pad(&self, padding: Padding) -> Self {
         Size {
-            width: self.width + padding * 2.0,
-            height: self.height + padding * 2.0,
+            width: self.width + f32::from(padding.horizontal()),
+            height: self.height + f32::from(padding.vertical()),
         }
     }
 }
@@ -12,13 +12,23 @@ use crate::Renderer;
 pub mod button;
 pub mod checkbox;
 pub mod container;
+pub mod context_menu;
+pub mod grid;
+pub mod menu_bar;
+pub mod modal;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
 pub mod radio;
 pub mod scrollable;
 pub mod slider;
+pub mod table;
+pub mod tabs;
+pub mod text_editor;
 pub mod text_input;
+pub mod tooltip;
+pub mod tree_view;
+pub mod virtual_list;
 
 #[doc(no_inline)]
 pub use button::Button;
@@ -39,6 +49,8 @@ pub use scrollable::Scrollable;
 #[doc(no_inline)]
 pub use slider::Slider;
 #[doc(no_inline)]
+pub use text_editor::TextEditor;
+#[doc(no_inline)]
 pub use text_input::TextInput;
 
 #[cfg(feature = "canvas")]
@@ -48,6 +60,24 @@ pub mod canvas;
 #[cfg(feature = "canvas")]
 #[doc(no_inline)]
 pub use canvas::Canvas;
+#[doc(no_inline)]
+pub use context_menu::ContextMenu;
+#[doc(no_inline)]
+pub use grid::Grid;
+#[doc(no_inline)]
+pub use menu_bar::MenuBar;
+#[doc(no_inline)]
+pub use modal::Modal;
+#[doc(no_inline)]
+pub use table::Table;
+#[doc(no_inline)]
+pub use tabs::Tabs;
+#[doc(no_inline)]
+pub use tooltip::Tooltip;
+#[doc(no_inline)]
+pub use tree_view::TreeView;
+#[doc(no_inline)]
+pub use virtual_list::VirtualList;
 
 pub use iced_native::{Image, Space};
 
@@ -57,5 +87,12 @@ pub type Column<'a, Message> = iced_native::Column<'a, Message, Renderer>;
 /// A container that distributes its contents horizontally.
 pub type Row<'a, Message> = iced_native::Row<'a, Message, Renderer>;
 
+/// A container that lays out its contents on top of each other.
+pub type Stack<'a, Message> = iced_native::Stack<'a, Message, Renderer>;
+
 /// A paragraph of text.
 pub type Text = iced_native::Text<Renderer>;
+
+/// A container that distributes its contents horizontally, wrapping them in
+/// many lines.
+pub type Wrap<'a, Message> = iced_native::Wrap<'a, Message, Renderer>;
@@ -0,0 +1,10 @@
+//! Distribute content in the cells of a grid of rows and columns.
+use crate::Renderer;
+
+pub use iced_graphics::grid::Area;
+
+/// A container that distributes its contents in the cells of a grid of
+/// column and row tracks.
+///
+/// This is an alias of an `iced_native` grid with an `iced_glow::Renderer`.
+pub type Grid<'a, Message> = iced_native::Grid<'a, Message, Renderer>;
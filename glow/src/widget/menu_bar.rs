@@ -0,0 +1,16 @@
+//! Show an application menu bar.
+//!
+//! A [`MenuBar`] has some local [`State`].
+//!
+//! [`MenuBar`]: type.MenuBar.html
+//! [`State`]: struct.State.html
+use crate::Renderer;
+
+pub use iced_graphics::menu_bar::{Menu, State, Style, StyleSheet};
+
+/// A horizontal bar of menus, like the `File`, `Edit` and `View` menus of a
+/// desktop application.
+///
+/// This is an alias of an `iced_native` menu bar with an
+/// `iced_glow::Renderer`.
+pub type MenuBar<'a, Message> = iced_native::MenuBar<'a, Message, Renderer>;
@@ -0,0 +1,11 @@
+//! Display a dialog on top of other widgets, blocking any interaction with
+//! them.
+use crate::Renderer;
+
+pub use iced_graphics::modal::{Style, StyleSheet};
+
+/// An element that displays some content centered on top of an underlay,
+/// dimming it and blocking any interaction with it.
+///
+/// This is an alias of an `iced_native` modal with an `iced_glow::Renderer`.
+pub type Modal<'a, Message> = iced_native::Modal<'a, Message, Renderer>;
@@ -2,9 +2,10 @@
 use crate::Renderer;
 
 pub use iced_graphics::radio::{Style, StyleSheet};
+pub use iced_native::radio::{State, Status};
 
 /// A circular button representing a choice.
 ///
 /// This is an alias of an `iced_native` radio button with an
 /// `iced_wgpu::Renderer`.
-pub type Radio<Message> = iced_native::Radio<Message, Renderer>;
+pub type Radio<'a, Message> = iced_native::Radio<'a, Message, Renderer>;
@@ -7,7 +7,7 @@
 use crate::Renderer;
 
 pub use iced_graphics::slider::{Handle, HandleShape, Style, StyleSheet};
-pub use iced_native::slider::State;
+pub use iced_native::slider::{State, Status};
 
 /// An horizontal bar and a handle that selects a single value from a range of
 /// values.
@@ -0,0 +1,18 @@
+//! Display tabular data with sortable and resizable columns.
+//!
+//! A [`Table`] has some local [`State`].
+//!
+//! [`Table`]: type.Table.html
+//! [`State`]: struct.State.html
+use crate::Renderer;
+
+pub use iced_graphics::table::{
+    Column, Order, SelectionMode, State, StyleSheet,
+};
+
+/// A table displaying a header row and a very large amount of rows, aligned
+/// in columns.
+///
+/// This is an alias of an `iced_native` table with an
+/// `iced_glow::Renderer`.
+pub type Table<'a, Message> = iced_native::Table<'a, Message, Renderer>;
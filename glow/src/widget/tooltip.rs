@@ -0,0 +1,14 @@
+//! Display a hint when hovering over other widgets.
+//!
+//! A [`Tooltip`] has some local [`State`].
+//!
+//! [`Tooltip`]: type.Tooltip.html
+//! [`State`]: struct.State.html
+use crate::Renderer;
+
+pub use iced_graphics::tooltip::{Position, State, Style, StyleSheet};
+
+/// An element that displays a hint on top of its content when hovered.
+///
+/// This is an alias of an `iced_native` tooltip with an `iced_glow::Renderer`.
+pub type Tooltip<'a, Message> = iced_native::Tooltip<'a, Message, Renderer>;
@@ -0,0 +1,20 @@
+//! Display a hierarchy of nodes that can be expanded and collapsed.
+use crate::Renderer;
+
+pub use iced_graphics::tree_view::StyleSheet;
+
+/// A node of a [`TreeView`].
+///
+/// This is an alias of an `iced_native` tree view node with an
+/// `iced_glow::Renderer`.
+///
+/// [`TreeView`]: type.TreeView.html
+pub type Node<'a, K, Message> =
+    iced_native::tree_view::Node<'a, K, Message, Renderer>;
+
+/// A hierarchical list of nodes, like a file browser or a scene hierarchy.
+///
+/// This is an alias of an `iced_native` tree view with an
+/// `iced_glow::Renderer`.
+pub type TreeView<'a, K, Message> =
+    iced_native::TreeView<'a, K, Message, Renderer>;
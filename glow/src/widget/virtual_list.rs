@@ -0,0 +1,13 @@
+//! Display a scrollable list with a very large amount of rows, building only
+//! the visible ones.
+use crate::Renderer;
+
+pub use iced_graphics::virtual_list::{RowHeight, State, StyleSheet};
+
+/// A scrollable list that builds, lays out and draws only the rows
+/// intersecting its viewport.
+///
+/// This is an alias of an `iced_native` virtual list with an
+/// `iced_glow::Renderer`.
+pub type VirtualList<'a, Message> =
+    iced_native::VirtualList<'a, Message, Renderer>;
@@ -6,6 +6,8 @@ use iced_winit::application;
 use iced_winit::conversion;
 use iced_winit::{Clipboard, Debug, Proxy, Settings};
 
+use std::time::Instant;
+
 pub use iced_winit::Application;
 pub use iced_winit::{program, Program};
 
@@ -40,7 +42,6 @@ pub fn run<A, E, C>(
 
     let flags = settings.flags;
     let (application, init_command) = runtime.enter(|| A::new(flags));
-    runtime.spawn(init_command);
 
     let subscription = application.subscription();
     runtime.track(subscription);
@@ -95,11 +96,26 @@ pub fn run<A, E, C>(
         &mut renderer,
         &mut debug,
     );
+
+    let init_command = state.perform(
+        init_command,
+        viewport.logical_size(),
+        conversion::cursor_position(cursor_position, viewport.scale_factor()),
+        clipboard.as_ref().map(|c| c as _),
+        &mut renderer,
+        &mut debug,
+    );
+    runtime.spawn(init_command);
     debug.startup_finished();
 
     event_loop.run(move |event, _, control_flow| match event {
         event::Event::MainEventsCleared => {
-            if state.is_queue_empty() {
+            let is_redraw_due = state
+                .redraw_request()
+                .map(|at| at <= Instant::now())
+                .unwrap_or(false);
+
+            if state.is_queue_empty() && !is_redraw_due {
                 return;
             }
 
@@ -221,9 +237,6 @@ pub fn run<A, E, C>(
 
                 mouse_interaction = new_mouse_interaction;
             }
-
-            // TODO: Handle animations!
-            // Maybe we can use `ControlFlow::WaitUntil` for this.
         }
         event::Event::WindowEvent {
             event: window_event,
@@ -251,7 +264,10 @@ pub fn run<A, E, C>(
             }
         }
         _ => {
-            *control_flow = ControlFlow::Wait;
+            *control_flow = match state.redraw_request() {
+                Some(at) => ControlFlow::WaitUntil(at),
+                None => ControlFlow::Wait,
+            };
         }
     })
 }
@@ -10,7 +10,11 @@
 pub mod button;
 pub mod checkbox;
 pub mod container;
+pub mod context_menu;
+pub mod grid;
 pub mod image;
+pub mod menu_bar;
+pub mod modal;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
@@ -18,12 +22,20 @@ pub mod radio;
 pub mod scrollable;
 pub mod slider;
 pub mod svg;
+pub mod table;
+pub mod tabs;
+pub mod text_editor;
 pub mod text_input;
+pub mod tooltip;
+pub mod tree_view;
+pub mod virtual_list;
 
 mod column;
 mod row;
 mod space;
+mod stack;
 mod text;
+mod wrap;
 
 #[doc(no_inline)]
 pub use button::Button;
@@ -44,14 +56,18 @@ pub use scrollable::Scrollable;
 #[doc(no_inline)]
 pub use slider::Slider;
 #[doc(no_inline)]
+pub use text_editor::TextEditor;
+#[doc(no_inline)]
 pub use text_input::TextInput;
 
 pub use column::Column;
 pub use image::Image;
 pub use row::Row;
 pub use space::Space;
+pub use stack::Stack;
 pub use svg::Svg;
 pub use text::Text;
+pub use wrap::Wrap;
 
 #[cfg(feature = "canvas")]
 #[cfg_attr(docsrs, doc(cfg(feature = "canvas")))]
@@ -60,3 +76,21 @@ pub mod canvas;
 #[cfg(feature = "canvas")]
 #[doc(no_inline)]
 pub use canvas::Canvas;
+#[doc(no_inline)]
+pub use context_menu::ContextMenu;
+#[doc(no_inline)]
+pub use grid::Grid;
+#[doc(no_inline)]
+pub use menu_bar::MenuBar;
+#[doc(no_inline)]
+pub use modal::Modal;
+#[doc(no_inline)]
+pub use table::Table;
+#[doc(no_inline)]
+pub use tabs::Tabs;
+#[doc(no_inline)]
+pub use tooltip::Tooltip;
+#[doc(no_inline)]
+pub use tree_view::TreeView;
+#[doc(no_inline)]
+pub use virtual_list::VirtualList;
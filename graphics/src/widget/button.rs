@@ -6,12 +6,13 @@
 //! [`State`]: struct.State.html
 use crate::defaults::{self, Defaults};
 use crate::{Backend, Primitive, Renderer};
+use iced_native::animation::Frame;
 use iced_native::mouse;
 use iced_native::{
-    Background, Color, Element, Layout, Point, Rectangle, Vector,
+    Background, Color, Element, Layout, Padding, Point, Rectangle, Vector,
 };
 
-pub use iced_native::button::State;
+pub use iced_native::button::{State, Status};
 pub use iced_style::button::{Style, StyleSheet};
 
 /// A widget that produces a message when clicked.
@@ -24,7 +25,7 @@ impl<B> iced_native::button::Renderer for Renderer<B>
 where
     B: Backend,
 {
-    const DEFAULT_PADDING: u16 = 5;
+    const DEFAULT_PADDING: Padding = Padding::new(5);
 
     type Style = Box<dyn StyleSheet>;
 
@@ -33,25 +34,21 @@ where
         _defaults: &Defaults,
         bounds: Rectangle,
         cursor_position: Point,
-        is_disabled: bool,
-        is_pressed: bool,
+        status: Frame<Status>,
         style: &Box<dyn StyleSheet>,
         content: &Element<'_, Message, Self>,
         content_layout: Layout<'_>,
     ) -> Self::Output {
         let is_mouse_over = bounds.contains(cursor_position);
+        let is_disabled = status.target == Status::Disabled;
 
-        let styling = if is_disabled {
-            style.disabled()
-        } else if is_mouse_over {
-            if is_pressed {
-                style.pressed()
-            } else {
-                style.hovered()
-            }
-        } else {
-            style.active()
-        };
+        let styling = status.interpolate(|status| match status {
+            Status::Active => style.active(),
+            Status::Hovered => style.hovered(),
+            Status::Pressed => style.pressed(),
+            Status::Focused => style.focused(),
+            Status::Disabled => style.disabled(),
+        });
 
         let (content, _) = content.draw(
             self,
@@ -13,6 +13,7 @@ use iced_native::{
 };
 use std::hash::Hash;
 use std::marker::PhantomData;
+use std::time::Instant;
 
 pub mod path;
 
@@ -223,6 +224,10 @@ where
         self.width.hash(state);
         self.height.hash(state);
     }
+
+    fn redraw_request(&self) -> Option<Instant> {
+        self.program.redraw_request()
+    }
 }
 
 impl<'a, Message, P, B> From<Canvas<Message, P>>
@@ -1,6 +1,8 @@
 use crate::canvas::{Cursor, Event, Geometry};
 use iced_native::{mouse, Rectangle};
 
+use std::time::Instant;
+
 /// The state and logic of a [`Canvas`].
 ///
 /// A [`Program`] can mutate internal state and produce messages for an
@@ -56,6 +58,20 @@ pub trait Program<Message> {
     ) -> mouse::Interaction {
         mouse::Interaction::default()
     }
+
+    /// Returns the [`Instant`] when the [`Program`] needs to be drawn again,
+    /// if any.
+    ///
+    /// Animated programs can use this to keep drawing new frames, even if no
+    /// events happen.
+    ///
+    /// By default, this method returns `None`.
+    ///
+    /// [`Instant`]: https://doc.rust-lang.org/std/time/struct.Instant.html
+    /// [`Program`]: trait.Program.html
+    fn redraw_request(&self) -> Option<Instant> {
+        None
+    }
 }
 
 impl<T, Message> Program<Message> for &mut T
@@ -82,4 +98,8 @@ where
     ) -> mouse::Interaction {
         T::mouse_interaction(self, bounds, cursor)
     }
+
+    fn redraw_request(&self) -> Option<Instant> {
+        T::redraw_request(self)
+    }
 }
@@ -1,17 +1,19 @@
 //! Show toggle controls using checkboxes.
 use crate::backend::{self, Backend};
 use crate::{Primitive, Renderer};
+use iced_native::animation::Frame;
 use iced_native::checkbox;
 use iced_native::mouse;
 use iced_native::{HorizontalAlignment, Rectangle, VerticalAlignment};
 
+pub use iced_native::checkbox::{State, Status};
 pub use iced_style::checkbox::{Style, StyleSheet};
 
 /// A box that can be checked.
 ///
 /// This is an alias of an `iced_native` checkbox with an `iced_wgpu::Renderer`.
-pub type Checkbox<Message, Backend> =
-    iced_native::Checkbox<Message, Renderer<Backend>>;
+pub type Checkbox<'a, Message, Backend> =
+    iced_native::Checkbox<'a, Message, Renderer<Backend>>;
 
 impl<B> checkbox::Renderer for Renderer<B>
 where
@@ -27,14 +29,15 @@ where
         bounds: Rectangle,
         is_checked: bool,
         is_mouse_over: bool,
+        status: Frame<Status>,
         (label, _): Self::Output,
         style_sheet: &Self::Style,
     ) -> Self::Output {
-        let style = if is_mouse_over {
-            style_sheet.hovered(is_checked)
-        } else {
-            style_sheet.active(is_checked)
-        };
+        let style = status.interpolate(|status| match status {
+            Status::Active => style_sheet.active(is_checked),
+            Status::Hovered => style_sheet.hovered(is_checked),
+            Status::Focused => style_sheet.focused(is_checked),
+        });
 
         let checkbox = Primitive::Quad {
             bounds,
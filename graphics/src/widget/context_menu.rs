@@ -0,0 +1,269 @@
+//! Open a menu of actions when right-clicking other widgets.
+//!
+//! A [`ContextMenu`] has some local [`State`].
+//!
+//! [`ContextMenu`]: type.ContextMenu.html
+//! [`State`]: struct.State.html
+use crate::backend::{self, Backend};
+use crate::{Primitive, Renderer};
+use iced_native::{
+    mouse, Color, Font, HorizontalAlignment, Layout, Padding, Point, Rectangle,
+    Size, VerticalAlignment,
+};
+
+pub use iced_native::context_menu::{Accelerator, Item, Label, State, Toggle};
+pub use iced_style::menu::Style;
+
+/// An element that opens a menu at the cursor position when right-clicked.
+///
+/// This is an alias of an `iced_native` context menu with an
+/// `iced_graphics::Renderer`.
+pub type ContextMenu<'a, Message, Backend> =
+    iced_native::ContextMenu<'a, Message, Renderer<Backend>>;
+
+impl<B> iced_native::context_menu::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    const DEFAULT_PADDING: Padding = Padding::new(5);
+
+    type Style = Style;
+
+    fn draw<Message>(
+        &mut self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        menus: &[(&[Item<Message>], Option<usize>)],
+        padding: Padding,
+        text_size: u16,
+        font: Font,
+        style: &Style,
+    ) -> Self::Output {
+        let mut primitives = Vec::new();
+        let mut mouse_interaction = mouse::Interaction::default();
+
+        let size = f32::from(text_size);
+
+        let disabled_text_color = Color {
+            a: style.text_color.a * 0.5,
+            ..style.text_color
+        };
+
+        for (menu_layout, (items, hovered)) in layout.children().zip(menus) {
+            primitives.push(Primitive::Quad {
+                bounds: menu_layout.bounds(),
+                background: style.background,
+                border_color: style.border_color,
+                border_width: style.border_width,
+                border_radius: 0,
+            });
+
+            let has_toggles = items.iter().any(|item| match item {
+                Item::Entry { toggle, .. } => toggle.is_some(),
+                _ => false,
+            });
+
+            let label_x =
+                f32::from(padding.left) + if has_toggles { size } else { 0.0 };
+
+            for (index, (item, item_layout)) in
+                items.iter().zip(menu_layout.children()).enumerate()
+            {
+                let bounds = item_layout.bounds();
+                let is_hovered = *hovered == Some(index);
+
+                if item.is_enabled() && bounds.contains(cursor_position) {
+                    mouse_interaction = mouse::Interaction::Pointer;
+                }
+
+                if is_hovered {
+                    primitives.push(Primitive::Quad {
+                        bounds,
+                        background: style.selected_background,
+                        border_color: Color::TRANSPARENT,
+                        border_width: 0,
+                        border_radius: 0,
+                    });
+                }
+
+                let color = if is_hovered {
+                    style.selected_text_color
+                } else if item.is_enabled() {
+                    style.text_color
+                } else {
+                    disabled_text_color
+                };
+
+                let text = |content: String| Primitive::Text {
+                    content,
+                    bounds: Rectangle {
+                        x: bounds.x + bounds.width - f32::from(padding.right),
+                        y: bounds.center_y(),
+                        ..bounds
+                    },
+                    size,
+                    font,
+                    color,
+                    horizontal_alignment: HorizontalAlignment::Right,
+                    vertical_alignment: VerticalAlignment::Center,
+                };
+
+                match item {
+                    Item::Entry {
+                        label,
+                        toggle,
+                        accelerator,
+                        ..
+                    } => {
+                        if let Some(toggle) = toggle {
+                            primitives.push(self::toggle(
+                                *toggle,
+                                Point::new(
+                                    bounds.x
+                                        + f32::from(padding.left)
+                                        + size / 2.0,
+                                    bounds.center_y(),
+                                ),
+                                size,
+                                color,
+                            ));
+                        }
+
+                        primitives.push(self::label(
+                            self,
+                            label,
+                            Point::new(bounds.x + label_x, bounds.center_y()),
+                            text_size,
+                            font,
+                            color,
+                            true,
+                        ));
+
+                        if let Some(accelerator) = accelerator {
+                            primitives.push(text(accelerator.to_string()));
+                        }
+                    }
+                    Item::Submenu { label, .. } => {
+                        primitives.push(self::label(
+                            self,
+                            label,
+                            Point::new(bounds.x + label_x, bounds.center_y()),
+                            text_size,
+                            font,
+                            color,
+                            true,
+                        ));
+
+                        primitives.push(text(String::from("›")));
+                    }
+                    Item::Separator => {
+                        primitives.push(Primitive::Quad {
+                            bounds: Rectangle {
+                                x: bounds.x + f32::from(padding.left),
+                                y: bounds.center_y().floor(),
+                                width: bounds.width
+                                    - f32::from(padding.horizontal()),
+                                height: 1.0,
+                            },
+                            background: style.border_color.into(),
+                            border_color: Color::TRANSPARENT,
+                            border_width: 0,
+                            border_radius: 0,
+                        });
+                    }
+                }
+            }
+        }
+
+        (Primitive::Group { primitives }, mouse_interaction)
+    }
+}
+
+/// Draws a menu [`Label`] starting at the given position, vertically
+/// centered, and underlines its mnemonic if requested.
+///
+/// [`Label`]: struct.Label.html
+pub(crate) fn label<B>(
+    renderer: &Renderer<B>,
+    label: &Label,
+    position: Point,
+    text_size: u16,
+    font: Font,
+    color: Color,
+    show_mnemonic: bool,
+) -> Primitive
+where
+    B: Backend + backend::Text,
+{
+    use iced_native::text::Renderer as _;
+
+    let text = Primitive::Text {
+        content: label.text().to_string(),
+        bounds: Rectangle {
+            x: position.x,
+            y: position.y,
+            width: f32::INFINITY,
+            height: f32::from(text_size),
+        },
+        size: f32::from(text_size),
+        font,
+        color,
+        horizontal_alignment: HorizontalAlignment::Left,
+        vertical_alignment: VerticalAlignment::Center,
+    };
+
+    let mnemonic = match (label.mnemonic_index(), label.mnemonic()) {
+        (Some(index), Some(mnemonic)) if show_mnemonic => (index, mnemonic),
+        _ => return text,
+    };
+
+    let measure = |content: &str| {
+        let (width, _) =
+            renderer.measure(content, text_size, font, Size::INFINITY);
+
+        width
+    };
+
+    let underline = Primitive::Quad {
+        bounds: Rectangle {
+            x: position.x + measure(&label.text()[..mnemonic.0]),
+            y: (position.y + f32::from(text_size) / 2.0).floor(),
+            width: measure(&mnemonic.1.to_string()),
+            height: 1.0,
+        },
+        background: color.into(),
+        border_color: Color::TRANSPARENT,
+        border_width: 0,
+        border_radius: 0,
+    };
+
+    Primitive::Group {
+        primitives: vec![text, underline],
+    }
+}
+
+fn toggle(toggle: Toggle, center: Point, size: f32, color: Color) -> Primitive {
+    let (is_active, border_radius) = match toggle {
+        Toggle::Checkbox(is_checked) => (is_checked, 2.0),
+        Toggle::Radio(is_selected) => (is_selected, size / 4.0),
+    };
+
+    if !is_active {
+        return Primitive::None;
+    }
+
+    let mark = size / 2.0;
+
+    Primitive::Quad {
+        bounds: Rectangle {
+            x: center.x - mark / 2.0,
+            y: center.y - mark / 2.0,
+            width: mark,
+            height: mark,
+        },
+        background: color.into(),
+        border_color: Color::TRANSPARENT,
+        border_width: 0,
+        border_radius: border_radius as u16,
+    }
+}
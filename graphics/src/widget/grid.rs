@@ -0,0 +1,48 @@
+//! Distribute content in the cells of a grid of rows and columns.
+use crate::{Backend, Primitive, Renderer};
+use iced_native::mouse;
+use iced_native::{Element, Layout, Point};
+
+pub use iced_native::grid::Area;
+
+/// A container that distributes its contents in the cells of a grid of
+/// column and row tracks.
+///
+/// This is an alias of an `iced_native` grid with an `iced_graphics::Renderer`.
+pub type Grid<'a, Message, Backend> =
+    iced_native::Grid<'a, Message, Renderer<Backend>>;
+
+impl<B> iced_native::grid::Renderer for Renderer<B>
+where
+    B: Backend,
+{
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        content: &[Element<'_, Message, Self>],
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Self::Output {
+        let mut mouse_interaction = mouse::Interaction::default();
+
+        (
+            Primitive::Group {
+                primitives: content
+                    .iter()
+                    .zip(layout.children())
+                    .map(|(child, layout)| {
+                        let (primitive, new_mouse_interaction) =
+                            child.draw(self, defaults, layout, cursor_position);
+
+                        if new_mouse_interaction > mouse_interaction {
+                            mouse_interaction = new_mouse_interaction;
+                        }
+
+                        primitive
+                    })
+                    .collect(),
+            },
+            mouse_interaction,
+        )
+    }
+}
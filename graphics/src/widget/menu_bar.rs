@@ -0,0 +1,99 @@
+//! Show an application menu bar.
+//!
+//! A [`MenuBar`] has some local [`State`].
+//!
+//! [`MenuBar`]: type.MenuBar.html
+//! [`State`]: struct.State.html
+use crate::backend::{self, Backend};
+use crate::widget::context_menu;
+use crate::{Primitive, Renderer};
+use iced_native::{mouse, Color, Font, Layout, Padding, Point};
+
+pub use iced_native::menu_bar::{Menu, State};
+pub use iced_style::menu_bar::{Style, StyleSheet};
+
+/// A horizontal bar of menus, like the `File`, `Edit` and `View` menus of a
+/// desktop application.
+///
+/// This is an alias of an `iced_native` menu bar with an
+/// `iced_graphics::Renderer`.
+pub type MenuBar<'a, Message, Backend> =
+    iced_native::MenuBar<'a, Message, Renderer<Backend>>;
+
+impl<B> iced_native::menu_bar::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    const DEFAULT_PADDING: Padding = Padding::new(5);
+
+    type Style = Box<dyn StyleSheet>;
+
+    fn menu_style(style_sheet: &Box<dyn StyleSheet>) -> context_menu::Style {
+        style_sheet.menu()
+    }
+
+    fn draw<Message>(
+        &mut self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        menus: &[Menu<Message>],
+        open_menu: Option<usize>,
+        show_mnemonics: bool,
+        padding: Padding,
+        text_size: u16,
+        font: Font,
+        style_sheet: &Box<dyn StyleSheet>,
+    ) -> Self::Output {
+        let style = style_sheet.active();
+
+        let mut primitives = vec![Primitive::Quad {
+            bounds: layout.bounds(),
+            background: style.background,
+            border_color: style.border_color,
+            border_width: style.border_width,
+            border_radius: 0,
+        }];
+
+        let mut mouse_interaction = mouse::Interaction::default();
+
+        for (index, (menu, title_layout)) in
+            menus.iter().zip(layout.children()).enumerate()
+        {
+            let bounds = title_layout.bounds();
+            let is_open = open_menu == Some(index);
+
+            if bounds.contains(cursor_position) {
+                mouse_interaction = mouse::Interaction::Pointer;
+            }
+
+            if is_open {
+                primitives.push(Primitive::Quad {
+                    bounds,
+                    background: style.selected_background,
+                    border_color: Color::TRANSPARENT,
+                    border_width: 0,
+                    border_radius: 0,
+                });
+            }
+
+            primitives.push(context_menu::label(
+                self,
+                &menu.label,
+                Point::new(
+                    bounds.x + f32::from(padding.left),
+                    bounds.center_y(),
+                ),
+                text_size,
+                font,
+                if is_open {
+                    style.selected_text_color
+                } else {
+                    style.text_color
+                },
+                show_mnemonics,
+            ));
+        }
+
+        (Primitive::Group { primitives }, mouse_interaction)
+    }
+}
@@ -0,0 +1,51 @@
+//! Display a dialog on top of other widgets, blocking any interaction with
+//! them.
+use crate::{Backend, Primitive, Renderer};
+use iced_native::{Color, Element, Layout, Point, Rectangle};
+
+pub use iced_style::modal::{Style, StyleSheet};
+
+/// An element that displays some content centered on top of an underlay,
+/// dimming it and blocking any interaction with it.
+///
+/// This is an alias of an `iced_native` modal with an
+/// `iced_graphics::Renderer`.
+pub type Modal<'a, Message, Backend> =
+    iced_native::Modal<'a, Message, Renderer<Backend>>;
+
+impl<B> iced_native::modal::Renderer for Renderer<B>
+where
+    B: Backend,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        bounds: Rectangle,
+        cursor_position: Point,
+        style_sheet: &Self::Style,
+        content: &Element<'_, Message, Self>,
+        content_layout: Layout<'_>,
+    ) -> Self::Output {
+        let style = style_sheet.style();
+
+        let backdrop = Primitive::Quad {
+            bounds,
+            background: style.backdrop,
+            border_radius: 0,
+            border_width: 0,
+            border_color: Color::TRANSPARENT,
+        };
+
+        let (content, mouse_interaction) =
+            content.draw(self, defaults, content_layout, cursor_position);
+
+        (
+            Primitive::Group {
+                primitives: vec![backdrop, content],
+            },
+            mouse_interaction,
+        )
+    }
+}
@@ -1,17 +1,19 @@
 //! Create choices using radio buttons.
 use crate::{Backend, Primitive, Renderer};
+use iced_native::animation::Frame;
 use iced_native::mouse;
 use iced_native::radio;
 use iced_native::{Background, Color, Rectangle};
 
+pub use iced_native::radio::{State, Status};
 pub use iced_style::radio::{Style, StyleSheet};
 
 /// A circular button representing a choice.
 ///
 /// This is an alias of an `iced_native` radio button with an
 /// `iced_wgpu::Renderer`.
-pub type Radio<Message, Backend> =
-    iced_native::Radio<Message, Renderer<Backend>>;
+pub type Radio<'a, Message, Backend> =
+    iced_native::Radio<'a, Message, Renderer<Backend>>;
 
 const SIZE: f32 = 28.0;
 const DOT_SIZE: f32 = SIZE / 2.0;
@@ -30,14 +32,15 @@ where
         bounds: Rectangle,
         is_selected: bool,
         is_mouse_over: bool,
+        status: Frame<Status>,
         (label, _): Self::Output,
         style_sheet: &Self::Style,
     ) -> Self::Output {
-        let style = if is_mouse_over {
-            style_sheet.hovered()
-        } else {
-            style_sheet.active()
-        };
+        let style = status.interpolate(|status| match status {
+            Status::Active => style_sheet.active(),
+            Status::Hovered => style_sheet.hovered(),
+            Status::Focused => style_sheet.focused(),
+        });
 
         let radio = Primitive::Quad {
             bounds,
@@ -2,13 +2,15 @@
 use crate::{Backend, Primitive, Renderer};
 use iced_native::mouse;
 use iced_native::scrollable;
-use iced_native::{Background, Color, Rectangle, Vector};
+use iced_native::{Background, Color, Point, Rectangle, Vector};
 
-pub use iced_native::scrollable::State;
+pub use iced_native::scrollable::{
+    scroll_to_child, snap_to, snap_to_end, snap_x_to, Direction, Offset, State,
+    Viewport,
+};
 pub use iced_style::scrollable::{Scrollbar, Scroller, StyleSheet};
 
-/// A widget that can vertically display an infinite amount of content
-/// with a scrollbar.
+/// A widget that can display an infinite amount of content with scrollbars.
 ///
 /// This is an alias of an `iced_native` scrollable with a default
 /// `Renderer`.
@@ -17,6 +19,7 @@ pub type Scrollable<'a, Message, Backend> =
 
 const SCROLLBAR_WIDTH: u16 = 10;
 const SCROLLBAR_MARGIN: u16 = 2;
+const MIN_SCROLLER_LENGTH: f32 = 20.0;
 
 impl<B> scrollable::Renderer for Renderer<B>
 where
@@ -24,40 +27,89 @@ where
 {
     type Style = Box<dyn iced_style::scrollable::StyleSheet>;
 
-    fn scrollbar(
+    fn scrollbars(
         &self,
         bounds: Rectangle,
         content_bounds: Rectangle,
-        offset: u32,
-    ) -> Option<scrollable::Scrollbar> {
-        if content_bounds.height > bounds.height {
+        offset: Vector<u32>,
+    ) -> scrollable::Scrollbars {
+        let thickness = f32::from(SCROLLBAR_WIDTH + 2 * SCROLLBAR_MARGIN);
+        let margin = f32::from(SCROLLBAR_MARGIN);
+
+        let is_vertical = content_bounds.height > bounds.height;
+        let is_horizontal = content_bounds.width > bounds.width;
+
+        // Leave the corner empty when both scrollbars are shown
+        let vertical = if is_vertical {
             let scrollbar_bounds = Rectangle {
-                x: bounds.x + bounds.width
-                    - f32::from(SCROLLBAR_WIDTH + 2 * SCROLLBAR_MARGIN),
+                x: bounds.x + bounds.width - thickness,
                 y: bounds.y,
-                width: f32::from(SCROLLBAR_WIDTH + 2 * SCROLLBAR_MARGIN),
-                height: bounds.height,
+                width: thickness,
+                height: bounds.height
+                    - if is_horizontal { thickness } else { 0.0 },
             };
 
-            let ratio = bounds.height / content_bounds.height;
-            let scrollbar_height = bounds.height * ratio;
-            let y_offset = offset as f32 * ratio;
+            let scroller_height = scroller_length(
+                scrollbar_bounds.height,
+                bounds.height,
+                content_bounds.height,
+            );
+
+            let y_offset = offset.y as f32
+                / (content_bounds.height - bounds.height)
+                * (scrollbar_bounds.height - scroller_height);
+
+            Some(scrollable::Scrollbar {
+                bounds: scrollbar_bounds,
+                scroller: scrollable::Scroller {
+                    bounds: Rectangle {
+                        x: scrollbar_bounds.x + margin,
+                        y: scrollbar_bounds.y + y_offset,
+                        width: scrollbar_bounds.width - 2.0 * margin,
+                        height: scroller_height,
+                    },
+                },
+            })
+        } else {
+            None
+        };
 
-            let scroller_bounds = Rectangle {
-                x: scrollbar_bounds.x + f32::from(SCROLLBAR_MARGIN),
-                y: scrollbar_bounds.y + y_offset,
-                width: scrollbar_bounds.width - f32::from(2 * SCROLLBAR_MARGIN),
-                height: scrollbar_height,
+        let horizontal = if is_horizontal {
+            let scrollbar_bounds = Rectangle {
+                x: bounds.x,
+                y: bounds.y + bounds.height - thickness,
+                width: bounds.width - if is_vertical { thickness } else { 0.0 },
+                height: thickness,
             };
 
+            let scroller_width = scroller_length(
+                scrollbar_bounds.width,
+                bounds.width,
+                content_bounds.width,
+            );
+
+            let x_offset = offset.x as f32
+                / (content_bounds.width - bounds.width)
+                * (scrollbar_bounds.width - scroller_width);
+
             Some(scrollable::Scrollbar {
                 bounds: scrollbar_bounds,
                 scroller: scrollable::Scroller {
-                    bounds: scroller_bounds,
+                    bounds: Rectangle {
+                        x: scrollbar_bounds.x + x_offset,
+                        y: scrollbar_bounds.y + margin,
+                        width: scroller_width,
+                        height: scrollbar_bounds.height - 2.0 * margin,
+                    },
                 },
             })
         } else {
             None
+        };
+
+        scrollable::Scrollbars {
+            vertical,
+            horizontal,
         }
     }
 
@@ -67,67 +119,76 @@ where
         bounds: Rectangle,
         _content_bounds: Rectangle,
         is_mouse_over: bool,
-        is_mouse_over_scrollbar: bool,
-        scrollbar: Option<scrollable::Scrollbar>,
-        offset: u32,
+        cursor_position: Point,
+        scrollbars: scrollable::Scrollbars,
+        offset: Vector<u32>,
         style_sheet: &Self::Style,
         (content, mouse_interaction): Self::Output,
     ) -> Self::Output {
+        let is_mouse_over_scrollbar = scrollbars.is_mouse_over(cursor_position);
+
+        let scrollable::Scrollbars {
+            vertical,
+            horizontal,
+        } = scrollbars;
+
         (
-            if let Some(scrollbar) = scrollbar {
+            if vertical.is_some() || horizontal.is_some() {
                 let clip = Primitive::Clip {
                     bounds,
-                    offset: Vector::new(0, offset),
+                    offset,
                     content: Box::new(content),
                 };
 
-                let style = if state.is_scroller_grabbed() {
-                    style_sheet.dragging()
-                } else if is_mouse_over_scrollbar {
-                    style_sheet.hovered()
-                } else {
-                    style_sheet.active()
-                };
-
-                let is_scrollbar_visible =
-                    style.background.is_some() || style.border_width > 0;
-
-                let scroller = if is_mouse_over
-                    || state.is_scroller_grabbed()
-                    || is_scrollbar_visible
-                {
-                    Primitive::Quad {
-                        bounds: scrollbar.scroller.bounds,
-                        background: Background::Color(style.scroller.color),
-                        border_radius: style.scroller.border_radius,
-                        border_width: style.scroller.border_width,
-                        border_color: style.scroller.border_color,
-                    }
-                } else {
-                    Primitive::None
-                };
+                let vertical = vertical.map(|scrollbar| {
+                    let style = if state.is_y_scroller_grabbed() {
+                        style_sheet.dragging()
+                    } else if scrollbar.is_mouse_over(cursor_position) {
+                        style_sheet.hovered()
+                    } else {
+                        style_sheet.active()
+                    };
 
-                let scrollbar = if is_scrollbar_visible {
-                    Primitive::Quad {
-                        bounds: Rectangle {
+                    scrollbar_primitive(
+                        &scrollbar,
+                        style,
+                        is_mouse_over || state.is_y_scroller_grabbed(),
+                        Rectangle {
                             x: scrollbar.bounds.x + f32::from(SCROLLBAR_MARGIN),
                             width: scrollbar.bounds.width
                                 - f32::from(2 * SCROLLBAR_MARGIN),
                             ..scrollbar.bounds
                         },
-                        background: style
-                            .background
-                            .unwrap_or(Background::Color(Color::TRANSPARENT)),
-                        border_radius: style.border_radius,
-                        border_width: style.border_width,
-                        border_color: style.border_color,
-                    }
-                } else {
-                    Primitive::None
-                };
+                    )
+                });
+
+                let horizontal = horizontal.map(|scrollbar| {
+                    let style = if state.is_x_scroller_grabbed() {
+                        style_sheet.horizontal_dragging()
+                    } else if scrollbar.is_mouse_over(cursor_position) {
+                        style_sheet.horizontal_hovered()
+                    } else {
+                        style_sheet.horizontal_active()
+                    };
+
+                    scrollbar_primitive(
+                        &scrollbar,
+                        style,
+                        is_mouse_over || state.is_x_scroller_grabbed(),
+                        Rectangle {
+                            y: scrollbar.bounds.y + f32::from(SCROLLBAR_MARGIN),
+                            height: scrollbar.bounds.height
+                                - f32::from(2 * SCROLLBAR_MARGIN),
+                            ..scrollbar.bounds
+                        },
+                    )
+                });
 
                 Primitive::Group {
-                    primitives: vec![clip, scrollbar, scroller],
+                    primitives: std::iter::once(clip)
+                        .chain(vertical)
+                        .chain(horizontal)
+                        .collect(),
                 }
             } else {
                 content
@@ -140,3 +201,51 @@ where
         )
     }
 }
+
+/// Returns the length of a scroller, keeping it grabbable when the content is
+/// very large.
+fn scroller_length(track: f32, viewport: f32, content: f32) -> f32 {
+    (track * viewport / content)
+        .max(MIN_SCROLLER_LENGTH)
+        .min(track)
+}
+
+fn scrollbar_primitive(
+    scrollbar: &scrollable::Scrollbar,
+    style: Scrollbar,
+    is_scroller_shown: bool,
+    track_bounds: Rectangle,
+) -> Primitive {
+    let is_scrollbar_visible =
+        style.background.is_some() || style.border_width > 0;
+
+    let scroller = if is_scroller_shown || is_scrollbar_visible {
+        Primitive::Quad {
+            bounds: scrollbar.scroller.bounds,
+            background: Background::Color(style.scroller.color),
+            border_radius: style.scroller.border_radius,
+            border_width: style.scroller.border_width,
+            border_color: style.scroller.border_color,
+        }
+    } else {
+        Primitive::None
+    };
+
+    let track = if is_scrollbar_visible {
+        Primitive::Quad {
+            bounds: track_bounds,
+            background: style
+                .background
+                .unwrap_or(Background::Color(Color::TRANSPARENT)),
+            border_radius: style.border_radius,
+            border_width: style.border_width,
+            border_color: style.border_color,
+        }
+    } else {
+        Primitive::None
+    };
+
+    Primitive::Group {
+        primitives: vec![track, scroller],
+    }
+}
@@ -5,11 +5,12 @@
 //! [`Slider`]: struct.Slider.html
 //! [`State`]: struct.State.html
 use crate::{Backend, Primitive, Renderer};
+use iced_native::animation::Frame;
 use iced_native::mouse;
 use iced_native::slider;
 use iced_native::{Background, Color, Point, Rectangle};
 
-pub use iced_native::slider::State;
+pub use iced_native::slider::{State, Status};
 pub use iced_style::slider::{Handle, HandleShape, Style, StyleSheet};
 
 /// An horizontal bar and a handle that selects a single value from a range of
@@ -33,18 +34,18 @@ where
         cursor_position: Point,
         range: std::ops::RangeInclusive<f32>,
         value: f32,
-        is_dragging: bool,
+        status: Frame<Status>,
         style_sheet: &Self::Style,
     ) -> Self::Output {
         let is_mouse_over = bounds.contains(cursor_position);
+        let is_dragging = status.target == Status::Dragging;
 
-        let style = if is_dragging {
-            style_sheet.dragging()
-        } else if is_mouse_over {
-            style_sheet.hovered()
-        } else {
-            style_sheet.active()
-        };
+        let style = status.interpolate(|status| match status {
+            Status::Active => style_sheet.active(),
+            Status::Hovered => style_sheet.hovered(),
+            Status::Dragging => style_sheet.dragging(),
+            Status::Focused => style_sheet.focused(),
+        });
 
         let rail_y = bounds.y + (bounds.height / 2.0).round();
 
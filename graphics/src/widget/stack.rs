@@ -0,0 +1,61 @@
+use crate::{Backend, Primitive, Renderer};
+use iced_native::mouse;
+use iced_native::stack;
+use iced_native::{Element, Layout, Point, Vector};
+
+/// A container that lays out its contents on top of each other.
+pub type Stack<'a, Message, Backend> =
+    iced_native::Stack<'a, Message, Renderer<Backend>>;
+
+impl<B> stack::Renderer for Renderer<B>
+where
+    B: Backend,
+{
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        content: &[Element<'_, Message, Self>],
+        layout: Layout<'_>,
+        cursor_positions: &[Point],
+    ) -> Self::Output {
+        let bounds = layout.bounds();
+        let mut mouse_interaction = mouse::Interaction::default();
+
+        (
+            Primitive::Group {
+                primitives: content
+                    .iter()
+                    .zip(layout.children())
+                    .zip(cursor_positions)
+                    .enumerate()
+                    .map(|(i, ((child, layout), cursor_position))| {
+                        let (primitive, new_mouse_interaction) = child.draw(
+                            self,
+                            defaults,
+                            layout,
+                            *cursor_position,
+                        );
+
+                        // The elements on top decide the interaction
+                        if new_mouse_interaction != mouse::Interaction::Idle {
+                            mouse_interaction = new_mouse_interaction;
+                        }
+
+                        if i == 0 {
+                            primitive
+                        } else {
+                            // A clip starts a new layer, drawn on top of the
+                            // previous elements
+                            Primitive::Clip {
+                                bounds,
+                                offset: Vector::new(0, 0),
+                                content: Box::new(primitive),
+                            }
+                        }
+                    })
+                    .collect(),
+            },
+            mouse_interaction,
+        )
+    }
+}
@@ -0,0 +1,276 @@
+//! Display tabular data with sortable and resizable columns.
+//!
+//! A [`Table`] has some local [`State`].
+//!
+//! [`Table`]: type.Table.html
+//! [`State`]: struct.State.html
+use crate::backend::{self, Backend};
+use crate::defaults::{self, Defaults};
+use crate::triangle;
+use crate::{Primitive, Renderer};
+use iced_native::table::{Header, VisibleRow};
+use iced_native::{
+    mouse, Background, Color, Element, Font, HorizontalAlignment, Layout,
+    Padding, Point, Rectangle, Size, Vector, VerticalAlignment,
+};
+
+pub use iced_native::table::{Column, Order, SelectionMode, State};
+pub use iced_style::table::{Style, StyleSheet};
+
+/// A table displaying a header row and a very large amount of rows, aligned
+/// in columns.
+///
+/// This is an alias of an `iced_native` table with an
+/// `iced_graphics::Renderer`.
+pub type Table<'a, Message, Backend> =
+    iced_native::Table<'a, Message, Renderer<Backend>>;
+
+impl<B> iced_native::table::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    const DEFAULT_PADDING: Padding = Padding::new(5);
+    const DEFAULT_ROW_HEIGHT: u16 = 30;
+
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw_rows<Message>(
+        &mut self,
+        _defaults: &Defaults,
+        rows: &[VisibleRow],
+        cells: &[Element<'_, Message, Self>],
+        layout: Layout<'_>,
+        cursor_position: Point,
+        style_sheet: &Box<dyn StyleSheet>,
+    ) -> Self::Output {
+        let style = style_sheet.active();
+        let columns = cells.len() / rows.len().max(1);
+
+        let mut mouse_interaction = mouse::Interaction::default();
+        let mut primitives = Vec::with_capacity(rows.len() + cells.len());
+        let mut layouts = layout.children();
+
+        for (row, cells) in rows.iter().zip(cells.chunks(columns.max(1))) {
+            let background = if row.is_selected {
+                Some(style.selected_row_background)
+            } else if row.is_hovered {
+                style.hovered_row_background
+            } else if row.index % 2 == 1 {
+                style.alternate_row_background
+            } else {
+                style.row_background
+            };
+
+            if let Some(background) = background {
+                primitives.push(Primitive::Quad {
+                    bounds: row.bounds,
+                    background,
+                    border_radius: 0,
+                    border_width: 0,
+                    border_color: Color::TRANSPARENT,
+                });
+            }
+
+            let defaults = Defaults {
+                text: defaults::Text {
+                    color: if row.is_selected {
+                        style.selected_text_color
+                    } else {
+                        style.text_color
+                    },
+                },
+            };
+
+            for (cell, layout) in cells.iter().zip(&mut layouts) {
+                let (primitive, new_mouse_interaction) =
+                    cell.draw(self, &defaults, layout, cursor_position);
+
+                if new_mouse_interaction > mouse_interaction {
+                    mouse_interaction = new_mouse_interaction;
+                }
+
+                primitives.push(primitive);
+            }
+        }
+
+        (Primitive::Group { primitives }, mouse_interaction)
+    }
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        headers: &[Header<'_>],
+        (body, body_mouse_interaction): Self::Output,
+        is_resizing: bool,
+        padding: Padding,
+        text_size: u16,
+        font: Font,
+        style_sheet: &Box<dyn StyleSheet>,
+    ) -> Self::Output {
+        let style = style_sheet.active();
+        let text_size = f32::from(text_size);
+
+        let mut primitives = vec![
+            Primitive::Quad {
+                bounds,
+                background: style.background,
+                border_radius: 0,
+                border_width: 0,
+                border_color: Color::TRANSPARENT,
+            },
+            body,
+        ];
+
+        for header in headers {
+            let bounds = header.bounds;
+
+            primitives.push(Primitive::Quad {
+                bounds,
+                background: if header.is_hovered && !is_resizing {
+                    style.hovered_header_background
+                } else {
+                    style.header_background
+                },
+                border_radius: 0,
+                border_width: 0,
+                border_color: Color::TRANSPARENT,
+            });
+
+            let indicator_size = if header.sort.is_some() {
+                text_size * 0.5
+            } else {
+                0.0
+            };
+
+            let label_bounds = Rectangle {
+                x: bounds.x + f32::from(padding.left),
+                width: (bounds.width
+                    - f32::from(padding.horizontal())
+                    - indicator_size)
+                    .max(0.0),
+                ..bounds
+            };
+
+            primitives.push(Primitive::Clip {
+                bounds: label_bounds,
+                offset: Vector::new(0, 0),
+                content: Box::new(Primitive::Text {
+                    content: header.label.to_string(),
+                    bounds: Rectangle {
+                        y: label_bounds.center_y(),
+                        ..label_bounds
+                    },
+                    size: text_size,
+                    color: style.header_text_color,
+                    font,
+                    horizontal_alignment: HorizontalAlignment::Left,
+                    vertical_alignment: VerticalAlignment::Center,
+                }),
+            });
+
+            if let Some(order) = header.sort {
+                primitives.push(sort_indicator(
+                    Point::new(
+                        bounds.x + bounds.width
+                            - f32::from(padding.right)
+                            - indicator_size,
+                        bounds.center_y() - indicator_size / 2.0,
+                    ),
+                    indicator_size,
+                    order,
+                    style.header_text_color,
+                ));
+            }
+
+            primitives.push(Primitive::Quad {
+                bounds: Rectangle {
+                    x: (bounds.x + bounds.width - 1.0).max(bounds.x),
+                    width: 1.0,
+                    ..bounds
+                },
+                background: Background::Color(style.divider_color),
+                border_radius: 0,
+                border_width: 0,
+                border_color: Color::TRANSPARENT,
+            });
+        }
+
+        if let Some(header) = headers.first() {
+            primitives.push(Primitive::Quad {
+                bounds: Rectangle {
+                    y: header.bounds.y + header.bounds.height - 1.0,
+                    height: 1.0,
+                    ..bounds
+                },
+                background: Background::Color(style.divider_color),
+                border_radius: 0,
+                border_width: 0,
+                border_color: Color::TRANSPARENT,
+            });
+        }
+
+        if style.border_width > 0 {
+            primitives.push(Primitive::Quad {
+                bounds,
+                background: Background::Color(Color::TRANSPARENT),
+                border_radius: 0,
+                border_width: style.border_width,
+                border_color: style.border_color,
+            });
+        }
+
+        (
+            Primitive::Clip {
+                bounds,
+                offset: Vector::new(0, 0),
+                content: Box::new(Primitive::Group { primitives }),
+            },
+            if is_resizing {
+                mouse::Interaction::ResizingHorizontally
+            } else if headers.iter().any(|header| header.is_hovered) {
+                mouse::Interaction::Pointer
+            } else {
+                body_mouse_interaction
+            },
+        )
+    }
+}
+
+/// Produces a triangle pointing up for [`Order::Ascending`] and down for
+/// [`Order::Descending`], fitting a square of the given size.
+///
+/// [`Order::Ascending`]: ../../../iced_native/table/enum.Order.html#variant.Ascending
+/// [`Order::Descending`]: ../../../iced_native/table/enum.Order.html#variant.Descending
+fn sort_indicator(
+    position: Point,
+    size: f32,
+    order: Order,
+    color: Color,
+) -> Primitive {
+    let color = color.into_linear();
+
+    let (top, bottom) = match order {
+        Order::Ascending => (size * 0.25, size * 0.75),
+        Order::Descending => (size * 0.75, size * 0.25),
+    };
+
+    let vertex = |x, y| triangle::Vertex2D {
+        position: [x, y],
+        color,
+    };
+
+    Primitive::Translate {
+        translation: Vector::new(position.x, position.y),
+        content: Box::new(Primitive::Mesh2D {
+            buffers: triangle::Mesh2D {
+                vertices: vec![
+                    vertex(size / 2.0, top),
+                    vertex(0.0, bottom),
+                    vertex(size, bottom),
+                ],
+                indices: vec![0, 1, 2],
+            },
+            size: Size::new(size, size),
+        }),
+    }
+}
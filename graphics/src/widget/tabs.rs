@@ -0,0 +1,226 @@
+//! Show one of many contents, switching between them with a tab bar.
+//!
+//! A [`Tabs`] widget has some local [`State`].
+//!
+//! [`Tabs`]: type.Tabs.html
+//! [`State`]: struct.State.html
+use crate::backend::{self, Backend};
+use crate::{Primitive, Renderer};
+use iced_native::tabs::Header;
+use iced_native::{
+    mouse, Background, Color, Font, HorizontalAlignment, Padding, Rectangle,
+    Size, Vector, VerticalAlignment,
+};
+
+pub use iced_native::tabs::{Label, State, Tab};
+pub use iced_style::tabs::{Style, StyleSheet};
+
+/// A tab bar on top of the content of its active tab.
+///
+/// This is an alias of an `iced_native` tabs widget with an
+/// `iced_graphics::Renderer`.
+pub type Tabs<'a, Message, Backend> =
+    iced_native::Tabs<'a, Message, Renderer<Backend>>;
+
+/// The height of the line under the active tab.
+const INDICATOR_HEIGHT: f32 = 2.0;
+
+impl<B> iced_native::tabs::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    const DEFAULT_PADDING: Padding = Padding::new(8);
+
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        bar_bounds: Rectangle,
+        headers: &[Header<'_>],
+        (content, content_mouse_interaction): Self::Output,
+        padding: Padding,
+        text_size: u16,
+        font: Font,
+        icon_font: Font,
+        style_sheet: &Box<dyn StyleSheet>,
+    ) -> Self::Output {
+        let style = style_sheet.active();
+        let size = f32::from(text_size);
+
+        let mut mouse_interaction = content_mouse_interaction;
+        let mut tabs = Vec::with_capacity(headers.len());
+        let mut dragged = None;
+
+        for header in headers {
+            let bounds = header.bounds;
+
+            let background = if header.is_active {
+                Some(style.active_tab_background)
+            } else if header.is_hovered {
+                style.hovered_tab_background
+            } else {
+                style.tab_background
+            };
+
+            let text_color = if header.is_active {
+                style.active_text_color
+            } else {
+                style.text_color
+            };
+
+            let mut primitives = Vec::new();
+
+            if let Some(background) = background {
+                primitives.push(Primitive::Quad {
+                    bounds,
+                    background,
+                    border_radius: 0,
+                    border_width: 0,
+                    border_color: Color::TRANSPARENT,
+                });
+            }
+
+            if header.is_active {
+                primitives.push(Primitive::Quad {
+                    bounds: Rectangle {
+                        y: bounds.y + bounds.height - INDICATOR_HEIGHT,
+                        height: INDICATOR_HEIGHT,
+                        ..bounds
+                    },
+                    background: Background::Color(style.active_indicator_color),
+                    border_radius: 0,
+                    border_width: 0,
+                    border_color: Color::TRANSPARENT,
+                });
+            }
+
+            let label = |content: String, x: f32, font: Font| Primitive::Text {
+                content,
+                bounds: Rectangle {
+                    x,
+                    y: bounds.center_y(),
+                    ..bounds
+                },
+                size,
+                color: text_color,
+                font,
+                horizontal_alignment: HorizontalAlignment::Left,
+                vertical_alignment: VerticalAlignment::Center,
+            };
+
+            let x = bounds.x + f32::from(padding.left);
+
+            match header.label {
+                Label::Text(text) => {
+                    primitives.push(label(text.clone(), x, font));
+                }
+                Label::Icon(icon) => {
+                    primitives.push(label(icon.to_string(), x, icon_font));
+                }
+                Label::IconText(icon, text) => {
+                    let icon = icon.to_string();
+
+                    let (icon_width, _) = self.backend().measure(
+                        &icon,
+                        size,
+                        icon_font,
+                        Size::INFINITY,
+                    );
+
+                    primitives.push(label(icon, x, icon_font));
+                    primitives.push(label(
+                        text.clone(),
+                        x + icon_width + f32::from(padding.left),
+                        font,
+                    ));
+                }
+            }
+
+            if let Some(close_bounds) = header.close_bounds {
+                if header.is_close_hovered {
+                    primitives.push(Primitive::Quad {
+                        bounds: close_bounds,
+                        background: style.hovered_close_background,
+                        border_radius: (close_bounds.width / 2.0) as u16,
+                        border_width: 0,
+                        border_color: Color::TRANSPARENT,
+                    });
+                }
+
+                primitives.push(Primitive::Text {
+                    content: String::from("×"),
+                    bounds: Rectangle {
+                        x: close_bounds.center_x(),
+                        y: close_bounds.center_y(),
+                        ..close_bounds
+                    },
+                    size,
+                    color: style.close_color,
+                    font,
+                    horizontal_alignment: HorizontalAlignment::Center,
+                    vertical_alignment: VerticalAlignment::Center,
+                });
+            }
+
+            if header.is_dragged {
+                mouse_interaction = mouse::Interaction::Grabbing;
+            } else if header.is_hovered
+                && mouse_interaction != mouse::Interaction::Grabbing
+            {
+                mouse_interaction = mouse::Interaction::Pointer;
+            }
+
+            let tab = Primitive::Group { primitives };
+
+            // The dragged tab is drawn on top of the others
+            if header.is_dragged {
+                dragged = Some(tab);
+            } else {
+                tabs.push(tab);
+            }
+        }
+
+        tabs.extend(dragged);
+
+        let bar = Primitive::Group {
+            primitives: vec![
+                Primitive::Quad {
+                    bounds: bar_bounds,
+                    background: style.bar_background,
+                    border_radius: 0,
+                    border_width: 0,
+                    border_color: Color::TRANSPARENT,
+                },
+                Primitive::Quad {
+                    bounds: Rectangle {
+                        y: bar_bounds.y + bar_bounds.height
+                            - f32::from(style.border_width),
+                        height: f32::from(style.border_width),
+                        ..bar_bounds
+                    },
+                    background: Background::Color(style.border_color),
+                    border_radius: 0,
+                    border_width: 0,
+                    border_color: Color::TRANSPARENT,
+                },
+                Primitive::Clip {
+                    bounds: bar_bounds,
+                    offset: Vector::new(0, 0),
+                    content: Box::new(Primitive::Group { primitives: tabs }),
+                },
+            ],
+        };
+
+        (
+            Primitive::Clip {
+                bounds,
+                offset: Vector::new(0, 0),
+                content: Box::new(Primitive::Group {
+                    primitives: vec![bar, content],
+                }),
+            },
+            mouse_interaction,
+        )
+    }
+}
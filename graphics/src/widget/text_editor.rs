@@ -0,0 +1,220 @@
+//! Edit multi-line text.
+//!
+//! A [`TextEditor`] has some local [`State`].
+//!
+//! [`TextEditor`]: struct.TextEditor.html
+//! [`State`]: struct.State.html
+use crate::backend::{self, Backend};
+use crate::{Primitive, Renderer};
+use iced_native::mouse;
+use iced_native::text_editor::{self, line, Line};
+use iced_native::text_input::{cursor, Value};
+use iced_native::{
+    Background, Color, Font, HorizontalAlignment, Point, Rectangle, Size,
+    Vector, VerticalAlignment,
+};
+
+pub use iced_native::text_editor::State;
+pub use iced_style::text_editor::{Style, StyleSheet};
+
+/// A field that can be filled with multiple lines of text.
+///
+/// This is an alias of an `iced_native` text editor with an
+/// `iced_graphics::Renderer`.
+pub type TextEditor<'a, Message, Backend> =
+    iced_native::TextEditor<'a, Message, Renderer<Backend>>;
+
+impl<B> text_editor::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    fn measure_value(&self, value: &str, size: u16, font: Font) -> f32 {
+        let backend = self.backend();
+
+        let (width, _) =
+            backend.measure(value, f32::from(size), font, Size::INFINITY);
+
+        width
+    }
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        text_bounds: Rectangle,
+        cursor_position: Point,
+        font: Font,
+        size: u16,
+        placeholder: &str,
+        value: &Value,
+        lines: &[Line],
+        state: &text_editor::State,
+        style_sheet: &Self::Style,
+    ) -> Self::Output {
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let style = if state.is_focused() {
+            style_sheet.focused()
+        } else if is_mouse_over {
+            style_sheet.hovered()
+        } else {
+            style_sheet.active()
+        };
+
+        let editor = Primitive::Quad {
+            bounds,
+            background: style.background,
+            border_radius: style.border_radius,
+            border_width: style.border_width,
+            border_color: style.border_color,
+        };
+
+        let line_height = f32::from(size);
+        let content_height = lines.len() as f32 * line_height;
+        let offset = state
+            .offset()
+            .min(content_height - text_bounds.height)
+            .max(0.0);
+
+        let first = (offset / line_height) as usize;
+        let last = (((offset + text_bounds.height) / line_height).ceil()
+            as usize)
+            .min(lines.len());
+
+        let line_bounds = |index: usize| Rectangle {
+            x: text_bounds.x,
+            y: text_bounds.y + index as f32 * line_height,
+            width: f32::INFINITY,
+            height: line_height,
+        };
+
+        let text =
+            |content: String, color: Color, index: usize| Primitive::Text {
+                content,
+                color,
+                font,
+                bounds: line_bounds(index),
+                size: f32::from(size),
+                horizontal_alignment: HorizontalAlignment::Left,
+                vertical_alignment: VerticalAlignment::Top,
+            };
+
+        let mut primitives = Vec::new();
+
+        if value.len() == 0 {
+            primitives.push(text(
+                placeholder.to_string(),
+                style_sheet.placeholder_color(),
+                0,
+            ));
+        } else {
+            let cursor = state.cursor();
+
+            if let cursor::State::Selection { start, end } = cursor.state(value)
+            {
+                let left = start.min(end);
+                let right = start.max(end);
+
+                for (index, line) in
+                    lines.iter().enumerate().take(last).skip(first)
+                {
+                    if right < line.start || left > line.end {
+                        continue;
+                    }
+
+                    let from = left.max(line.start);
+                    let to = right.min(line.end);
+
+                    let x = self.measure_value(
+                        &value.select(line.start, from).to_string(),
+                        size,
+                        font,
+                    );
+
+                    let mut width = self.measure_value(
+                        &value.select(from, to).to_string(),
+                        size,
+                        font,
+                    );
+
+                    // Highlight the selected line breaks
+                    let is_line_break = lines
+                        .get(index + 1)
+                        .map(|next| next.start > line.end)
+                        .unwrap_or(false);
+
+                    if right > line.end && is_line_break {
+                        width += self.measure_value(" ", size, font);
+                    }
+
+                    primitives.push(Primitive::Quad {
+                        bounds: Rectangle {
+                            x: text_bounds.x + x,
+                            width,
+                            ..line_bounds(index)
+                        },
+                        background: Background::Color(
+                            style_sheet.selection_color(),
+                        ),
+                        border_radius: 0,
+                        border_width: 0,
+                        border_color: Color::TRANSPARENT,
+                    });
+                }
+            }
+
+            for (index, line) in lines.iter().enumerate().take(last).skip(first)
+            {
+                primitives.push(text(
+                    value.select(line.start, line.end).to_string(),
+                    style_sheet.value_color(),
+                    index,
+                ));
+            }
+        }
+
+        if state.is_focused() {
+            if let cursor::State::Index(position) = state.cursor().state(value)
+            {
+                let index = line::find(lines, position);
+                let start = lines[index].start;
+
+                let x = self.measure_value(
+                    &value.select(start, position).to_string(),
+                    size,
+                    font,
+                );
+
+                primitives.push(Primitive::Quad {
+                    bounds: Rectangle {
+                        x: text_bounds.x + x,
+                        width: 1.0,
+                        ..line_bounds(index)
+                    },
+                    background: Background::Color(style_sheet.value_color()),
+                    border_radius: 0,
+                    border_width: 0,
+                    border_color: Color::TRANSPARENT,
+                });
+            }
+        }
+
+        let contents = Primitive::Clip {
+            bounds: text_bounds,
+            offset: Vector::new(0, offset as u32),
+            content: Box::new(Primitive::Group { primitives }),
+        };
+
+        (
+            Primitive::Group {
+                primitives: vec![editor, contents],
+            },
+            if is_mouse_over {
+                mouse::Interaction::Text
+            } else {
+                mouse::Interaction::default()
+            },
+        )
+    }
+}
@@ -6,6 +6,7 @@
 //! [`State`]: struct.State.html
 use crate::backend::{self, Backend};
 use crate::{Primitive, Renderer};
+use iced_native::animation::Frame;
 use iced_native::mouse;
 use iced_native::text_input::{self, cursor};
 use iced_native::{
@@ -13,8 +14,9 @@ use iced_native::{
     Vector, VerticalAlignment,
 };
 use std::f32;
+use std::time::Instant;
 
-pub use iced_native::text_input::State;
+pub use iced_native::text_input::{State, Status};
 pub use iced_style::text_input::{Style, StyleSheet};
 
 /// A field that can be filled with text.
@@ -79,17 +81,16 @@ where
         placeholder: &str,
         value: &text_input::Value,
         state: &text_input::State,
+        status: Frame<Status>,
         style_sheet: &Self::Style,
     ) -> Self::Output {
         let is_mouse_over = bounds.contains(cursor_position);
 
-        let style = if state.is_focused() {
-            style_sheet.focused()
-        } else if is_mouse_over {
-            style_sheet.hovered()
-        } else {
-            style_sheet.active()
-        };
+        let style = status.interpolate(|status| match status {
+            Status::Active => style_sheet.active(),
+            Status::Hovered => style_sheet.hovered(),
+            Status::Focused => style_sheet.focused(),
+        });
 
         let input = Primitive::Quad {
             bounds,
@@ -139,19 +140,23 @@ where
                         );
 
                     (
-                        Primitive::Quad {
-                            bounds: Rectangle {
-                                x: text_bounds.x + text_value_width,
-                                y: text_bounds.y,
-                                width: 1.0,
-                                height: text_bounds.height,
-                            },
-                            background: Background::Color(
-                                style_sheet.value_color(),
-                            ),
-                            border_radius: 0,
-                            border_width: 0,
-                            border_color: Color::TRANSPARENT,
+                        if state.is_cursor_visible(Instant::now()) {
+                            Primitive::Quad {
+                                bounds: Rectangle {
+                                    x: text_bounds.x + text_value_width,
+                                    y: text_bounds.y,
+                                    width: 1.0,
+                                    height: text_bounds.height,
+                                },
+                                background: Background::Color(
+                                    style_sheet.value_color(),
+                                ),
+                                border_radius: 0,
+                                border_width: 0,
+                                border_color: Color::TRANSPARENT,
+                            }
+                        } else {
+                            Primitive::None
                         },
                         offset,
                     )
@@ -0,0 +1,196 @@
+//! Display a hierarchy of nodes that can be expanded and collapsed.
+use crate::backend::{self, Backend};
+use crate::defaults::{self, Defaults};
+use crate::triangle;
+use crate::{Primitive, Renderer};
+use iced_native::tree_view::Row;
+use iced_native::{
+    mouse, Background, Color, Element, HorizontalAlignment, Layout, Point,
+    Rectangle, Size, Vector, VerticalAlignment,
+};
+
+pub use iced_native::tree_view::Node;
+pub use iced_style::tree_view::{Style, StyleSheet};
+
+/// A hierarchical list of nodes, like a file browser or a scene hierarchy.
+///
+/// This is an alias of an `iced_native` tree view with an
+/// `iced_graphics::Renderer`.
+pub type TreeView<'a, K, Message, Backend> =
+    iced_native::TreeView<'a, K, Message, Renderer<Backend>>;
+
+impl<B> iced_native::tree_view::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    const DEFAULT_INDENT: u16 = 20;
+
+    type Style = Box<dyn StyleSheet>;
+
+    fn draw<Message>(
+        &mut self,
+        _defaults: &Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        rows: &[Row],
+        contents: &[Element<'_, Message, Self>],
+        indent: u16,
+        is_focused: bool,
+        style_sheet: &Box<dyn StyleSheet>,
+    ) -> Self::Output {
+        let style = if is_focused {
+            style_sheet.focused()
+        } else {
+            style_sheet.active()
+        };
+
+        let bounds = layout.bounds();
+        let indent = f32::from(indent);
+
+        let mut mouse_interaction = mouse::Interaction::default();
+        let mut primitives = Vec::new();
+
+        if let Some(background) = style.background {
+            primitives.push(Primitive::Quad {
+                bounds,
+                background,
+                border_radius: 0,
+                border_width: 0,
+                border_color: Color::TRANSPARENT,
+            });
+        }
+
+        for ((row, content), row_layout) in
+            rows.iter().zip(contents).zip(layout.children())
+        {
+            let row_bounds = row_layout.bounds();
+            let is_hovered = row_bounds.contains(cursor_position);
+
+            let background = if row.is_selected {
+                Some(style.selected_background)
+            } else if is_hovered {
+                style.hovered_background
+            } else {
+                None
+            };
+
+            if let Some(background) = background {
+                primitives.push(Primitive::Quad {
+                    bounds: row_bounds,
+                    background,
+                    border_radius: 0,
+                    border_width: 0,
+                    border_color: Color::TRANSPARENT,
+                });
+            }
+
+            for level in 0..row.depth {
+                primitives.push(Primitive::Quad {
+                    bounds: Rectangle {
+                        x: (row_bounds.x + indent * (level as f32 + 0.5))
+                            .floor(),
+                        y: row_bounds.y,
+                        width: 1.0,
+                        height: row_bounds.height,
+                    },
+                    background: Background::Color(style.guide_color),
+                    border_radius: 0,
+                    border_width: 0,
+                    border_color: Color::TRANSPARENT,
+                });
+            }
+
+            if row.is_expandable {
+                let arrow_bounds = Rectangle {
+                    x: row_bounds.x + indent * row.depth as f32,
+                    y: row_bounds.y,
+                    width: indent,
+                    height: row_bounds.height,
+                };
+
+                let color = if row.is_selected {
+                    style.selected_text_color
+                } else {
+                    style.arrow_color
+                };
+
+                primitives.push(if row.is_expanded {
+                    Primitive::Text {
+                        content: B::ARROW_DOWN_ICON.to_string(),
+                        font: B::ICON_FONT,
+                        size: indent * 0.7,
+                        bounds: Rectangle {
+                            x: arrow_bounds.center_x(),
+                            y: arrow_bounds.center_y(),
+                            ..arrow_bounds
+                        },
+                        color,
+                        horizontal_alignment: HorizontalAlignment::Center,
+                        vertical_alignment: VerticalAlignment::Center,
+                    }
+                } else {
+                    // The built-in icon font has no ▶ icon
+                    arrow_right(arrow_bounds, indent * 0.4, color)
+                });
+
+                if arrow_bounds.contains(cursor_position) {
+                    mouse_interaction = mouse::Interaction::Pointer;
+                }
+            }
+
+            let defaults = Defaults {
+                text: defaults::Text {
+                    color: if row.is_selected {
+                        style.selected_text_color
+                    } else {
+                        style.text_color
+                    },
+                },
+            };
+
+            let (primitive, new_mouse_interaction) = content.draw(
+                self,
+                &defaults,
+                row_layout.children().next().unwrap(),
+                cursor_position,
+            );
+
+            if new_mouse_interaction > mouse_interaction {
+                mouse_interaction = new_mouse_interaction;
+            }
+
+            primitives.push(primitive);
+        }
+
+        (Primitive::Group { primitives }, mouse_interaction)
+    }
+}
+
+/// Produces a triangle pointing right of the given size, centered in the
+/// given bounds.
+fn arrow_right(bounds: Rectangle, size: f32, color: Color) -> Primitive {
+    let color = color.into_linear();
+
+    let vertex = |x, y| triangle::Vertex2D {
+        position: [x, y],
+        color,
+    };
+
+    Primitive::Translate {
+        translation: Vector::new(
+            bounds.center_x() - size / 2.0,
+            bounds.center_y() - size / 2.0,
+        ),
+        content: Box::new(Primitive::Mesh2D {
+            buffers: triangle::Mesh2D {
+                vertices: vec![
+                    vertex(size * 0.2, 0.0),
+                    vertex(size * 0.2, size),
+                    vertex(size * 0.9, size / 2.0),
+                ],
+                indices: vec![0, 1, 2],
+            },
+            size: Size::new(size, size),
+        }),
+    }
+}
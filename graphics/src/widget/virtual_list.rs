@@ -0,0 +1,14 @@
+//! Display a scrollable list with a very large amount of rows, building only
+//! the visible ones.
+use crate::Renderer;
+
+pub use iced_native::virtual_list::{RowHeight, State};
+pub use iced_style::scrollable::StyleSheet;
+
+/// A scrollable list that builds, lays out and draws only the rows
+/// intersecting its viewport.
+///
+/// This is an alias of an `iced_native` virtual list with an
+/// `iced_graphics::Renderer`.
+pub type VirtualList<'a, Message, Backend> =
+    iced_native::VirtualList<'a, Message, Renderer<Backend>>;
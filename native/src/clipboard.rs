@@ -1,3 +1,18 @@
+//! Access the clipboard.
+//!
+//! Widgets can read and write the [`Clipboard`] while processing events.
+//!
+//! Your update logic can do the same with the [`Command`] produced by
+//! [`read`] and [`write`].
+//!
+//! [`Clipboard`]: trait.Clipboard.html
+//! [`Command`]: ../struct.Command.html
+//! [`read`]: fn.read.html
+//! [`write`]: fn.write.html
+use crate::command::{self, Command};
+
+use std::cell::RefCell;
+
 /// A buffer for short-term storage and transfer within and between
 /// applications.
 pub trait Clipboard {
@@ -5,4 +20,123 @@ pub trait Clipboard {
     ///
     /// [`Clipboard`]: trait.Clipboard.html
     fn content(&self) -> Option<String>;
+
+    /// Writes the given text contents to the [`Clipboard`].
+    ///
+    /// [`Clipboard`]: trait.Clipboard.html
+    fn write(&self, contents: String);
+}
+
+/// Produces a [`Command`] that reads the contents of the [`Clipboard`] and
+/// turns them into a message.
+///
+/// The contents will be `None` if the [`Clipboard`] is empty, unavailable, or
+/// does not contain text.
+///
+/// [`Command`]: ../struct.Command.html
+/// [`Clipboard`]: trait.Clipboard.html
+pub fn read<T>(f: impl Fn(Option<String>) -> T + 'static + Send) -> Command<T> {
+    Command::single(command::Action::Clipboard(Action::Read(Box::new(f))))
+}
+
+/// Produces a [`Command`] that writes the given contents to the
+/// [`Clipboard`].
+///
+/// [`Command`]: ../struct.Command.html
+/// [`Clipboard`]: trait.Clipboard.html
+pub fn write<T>(contents: String) -> Command<T> {
+    Command::single(command::Action::Clipboard(Action::Write(contents)))
+}
+
+/// A clipboard operation performed by a shell.
+pub enum Action<T> {
+    /// Read the contents of the [`Clipboard`].
+    ///
+    /// [`Clipboard`]: trait.Clipboard.html
+    Read(Box<dyn Fn(Option<String>) -> T + Send>),
+
+    /// Write the given contents to the [`Clipboard`].
+    ///
+    /// [`Clipboard`]: trait.Clipboard.html
+    Write(String),
+}
+
+impl<T> Action<T> {
+    /// Applies a transformation to the result of an [`Action`].
+    ///
+    /// [`Action`]: enum.Action.html
+    pub fn map<A>(self, f: impl Fn(T) -> A + 'static + Send + Sync) -> Action<A>
+    where
+        T: 'static,
+    {
+        match self {
+            Action::Read(g) => {
+                Action::Read(Box::new(move |contents| f(g(contents))))
+            }
+            Action::Write(contents) => Action::Write(contents),
+        }
+    }
+
+    /// Performs the [`Action`] on the given [`Clipboard`], producing the
+    /// resulting message, if any.
+    ///
+    /// Reading an unavailable [`Clipboard`] produces `None` contents.
+    ///
+    /// [`Action`]: enum.Action.html
+    /// [`Clipboard`]: trait.Clipboard.html
+    pub fn perform(self, clipboard: Option<&dyn Clipboard>) -> Option<T> {
+        match self {
+            Action::Read(f) => {
+                Some(f(clipboard.and_then(|clipboard| clipboard.content())))
+            }
+            Action::Write(contents) => {
+                if let Some(clipboard) = clipboard {
+                    clipboard.write(contents);
+                }
+
+                None
+            }
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for Action<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Action::Read(_) => write!(f, "Action::Read"),
+            Action::Write(contents) => {
+                write!(f, "Action::Write({:?})", contents)
+            }
+        }
+    }
+}
+
+/// A [`Clipboard`] that keeps its contents in memory.
+///
+/// It does not interact with the system clipboard, which makes it useful
+/// if you are writing tests!
+///
+/// [`Clipboard`]: trait.Clipboard.html
+#[derive(Debug, Default)]
+pub struct Memory {
+    contents: RefCell<Option<String>>,
+}
+
+impl Memory {
+    /// Creates a new, empty [`Memory`] clipboard.
+    ///
+    /// [`Memory`]: struct.Memory.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Clipboard for Memory {
+    fn content(&self) -> Option<String> {
+        self.contents.borrow().clone()
+    }
+
+    fn write(&self, contents: String) {
+        *self.contents.borrow_mut() = Some(contents);
+    }
 }
@@ -0,0 +1,182 @@
+//! Run asynchronous actions and operate on widgets.
+use crate::{clipboard, focus, scrollable};
+use iced_futures::futures::future::{Future, FutureExt};
+use iced_futures::BoxFuture;
+
+/// A collection of actions to be performed by a shell.
+///
+/// You should be able to turn a future easily into a [`Command`], either by
+/// using the `From` trait or [`Command::perform`].
+///
+/// [`Command`]: struct.Command.html
+/// [`Command::perform`]: #method.perform
+pub struct Command<T> {
+    actions: Vec<Action<T>>,
+}
+
+/// An action that a [`Command`] can perform.
+///
+/// [`Command`]: struct.Command.html
+pub enum Action<T> {
+    /// Run a future and produce its result as a message.
+    Future(BoxFuture<T>),
+
+    /// Change or query the keyboard focus of the widgets.
+    Focus(focus::Action<T>),
+
+    /// Read or write the clipboard.
+    Clipboard(clipboard::Action<T>),
+
+    /// Change the scrolling position of a [`Scrollable`].
+    ///
+    /// [`Scrollable`]: ../widget/scrollable/struct.Scrollable.html
+    Scroll(scrollable::Action),
+}
+
+impl<T> Command<T> {
+    /// Creates an empty [`Command`].
+    ///
+    /// In other words, a [`Command`] that does nothing.
+    ///
+    /// [`Command`]: struct.Command.html
+    pub fn none() -> Self {
+        Self {
+            actions: Vec::new(),
+        }
+    }
+
+    /// Creates a [`Command`] that performs a single [`Action`].
+    ///
+    /// [`Command`]: struct.Command.html
+    /// [`Action`]: enum.Action.html
+    pub fn single(action: Action<T>) -> Self {
+        Self {
+            actions: vec![action],
+        }
+    }
+
+    /// Creates a [`Command`] that performs the action of the given future.
+    ///
+    /// [`Command`]: struct.Command.html
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn perform<A>(
+        future: impl Future<Output = T> + 'static + Send,
+        f: impl Fn(T) -> A + 'static + Send,
+    ) -> Command<A> {
+        Command::single(Action::Future(Box::pin(future.map(f))))
+    }
+
+    /// Creates a [`Command`] that performs the action of the given future.
+    ///
+    /// [`Command`]: struct.Command.html
+    #[cfg(target_arch = "wasm32")]
+    pub fn perform<A>(
+        future: impl Future<Output = T> + 'static,
+        f: impl Fn(T) -> A + 'static + Send,
+    ) -> Command<A> {
+        Command::single(Action::Future(Box::pin(future.map(f))))
+    }
+
+    /// Applies a transformation to the result of a [`Command`].
+    ///
+    /// [`Command`]: struct.Command.html
+    pub fn map<A>(
+        self,
+        f: impl Fn(T) -> A + 'static + Send + Sync,
+    ) -> Command<A>
+    where
+        T: 'static,
+    {
+        let f = std::sync::Arc::new(f);
+
+        Command {
+            actions: self
+                .actions
+                .into_iter()
+                .map(|action| {
+                    let f = f.clone();
+
+                    action.map(move |result| f(result))
+                })
+                .collect(),
+        }
+    }
+
+    /// Creates a [`Command`] that performs the actions of all the given
+    /// commands.
+    ///
+    /// Once this command is run, all the commands will be executed at once.
+    ///
+    /// [`Command`]: struct.Command.html
+    pub fn batch(commands: impl IntoIterator<Item = Command<T>>) -> Self {
+        Self {
+            actions: commands
+                .into_iter()
+                .flat_map(|command| command.actions)
+                .collect(),
+        }
+    }
+
+    /// Converts a [`Command`] into its underlying list of actions.
+    ///
+    /// [`Command`]: struct.Command.html
+    pub fn actions(self) -> Vec<Action<T>> {
+        self.actions
+    }
+}
+
+impl<T> Action<T> {
+    /// Applies a transformation to the result of an [`Action`].
+    ///
+    /// [`Action`]: enum.Action.html
+    pub fn map<A>(self, f: impl Fn(T) -> A + 'static + Send + Sync) -> Action<A>
+    where
+        T: 'static,
+    {
+        match self {
+            Action::Future(future) => Action::Future(Box::pin(future.map(f))),
+            Action::Focus(action) => Action::Focus(action.map(f)),
+            Action::Clipboard(action) => Action::Clipboard(action.map(f)),
+            Action::Scroll(action) => Action::Scroll(action),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<T, A> From<A> for Command<T>
+where
+    A: Future<Output = T> + 'static + Send,
+{
+    fn from(future: A) -> Self {
+        Command::single(Action::Future(future.boxed()))
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl<T, A> From<A> for Command<T>
+where
+    A: Future<Output = T> + 'static,
+{
+    fn from(future: A) -> Self {
+        Command::single(Action::Future(future.boxed_local()))
+    }
+}
+
+impl<T> std::fmt::Debug for Command<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Command").finish()
+    }
+}
+
+impl<T> std::fmt::Debug for Action<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Action::Future(_) => write!(f, "Action::Future"),
+            Action::Focus(action) => write!(f, "Action::Focus({:?})", action),
+            Action::Clipboard(action) => {
+                write!(f, "Action::Clipboard({:?})", action)
+            }
+            Action::Scroll(action) => write!(f, "Action::Scroll({:?})", action),
+        }
+    }
+}
@@ -1,8 +1,11 @@
 use crate::{
-    layout, overlay, Clipboard, Color, Event, EventInteraction, Hasher, Layout,
-    Length, Point, Widget,
+    focus, keyboard, layout, overlay, Clipboard, Color, Event,
+    EventInteraction, Hasher, Id, Layout, Length, Point, Widget,
 };
 
+use std::any::Any;
+use std::time::Instant;
+
 /// A generic [`Widget`].
 ///
 /// It is useful to build composable user interfaces that do not leak
@@ -281,6 +284,38 @@ where
     ) -> Option<overlay::Element<'b, Message, Renderer>> {
         self.widget.overlay(layout)
     }
+
+    /// Collects the [`Focusable`] widgets of the [`Element`].
+    ///
+    /// [`Focusable`]: focus/trait.Focusable.html
+    /// [`Element`]: struct.Element.html
+    pub fn focusables<'b>(
+        &'b mut self,
+        focusables: &mut Vec<&'b mut dyn focus::Focusable>,
+    ) {
+        self.widget.focusables(focusables);
+    }
+
+    /// Collects the local state of the widgets of the [`Element`] that have an
+    /// [`Id`].
+    ///
+    /// [`Element`]: struct.Element.html
+    /// [`Id`]: widget/struct.Id.html
+    pub fn targets<'b>(
+        &'b mut self,
+        targets: &mut Vec<(&'b Id, &'b mut dyn Any)>,
+    ) {
+        self.widget.targets(targets);
+    }
+
+    /// Returns the [`Instant`] when the [`Element`] needs to be redrawn, if
+    /// any.
+    ///
+    /// [`Instant`]: https://doc.rust-lang.org/std/time/struct.Instant.html
+    /// [`Element`]: struct.Element.html
+    pub fn redraw_request(&self) -> Option<Instant> {
+        self.widget.redraw_request()
+    }
 }
 
 struct Map<'a, A, B, Renderer> {
@@ -377,6 +412,42 @@ where
             .overlay(layout)
             .map(move |overlay| overlay.map(mapper))
     }
+
+    fn on_shortcut(
+        &mut self,
+        key_code: keyboard::KeyCode,
+        modifiers: keyboard::ModifiersState,
+        messages: &mut Vec<B>,
+    ) -> EventInteraction {
+        let mut original_messages = Vec::new();
+
+        let interaction = self.widget.on_shortcut(
+            key_code,
+            modifiers,
+            &mut original_messages,
+        );
+
+        original_messages
+            .drain(..)
+            .for_each(|message| messages.push((self.mapper)(message)));
+
+        interaction
+    }
+
+    fn focusables<'b>(
+        &'b mut self,
+        focusables: &mut Vec<&'b mut dyn focus::Focusable>,
+    ) {
+        self.widget.focusables(focusables);
+    }
+
+    fn targets<'b>(&'b mut self, targets: &mut Vec<(&'b Id, &'b mut dyn Any)>) {
+        self.widget.targets(targets);
+    }
+
+    fn redraw_request(&self) -> Option<Instant> {
+        self.widget.redraw_request()
+    }
 }
 
 struct Explain<'a, Message, Renderer: crate::Renderer> {
@@ -459,4 +530,30 @@ where
     ) -> Option<overlay::Element<'_, Message, Renderer>> {
         self.element.overlay(layout)
     }
+
+    fn on_shortcut(
+        &mut self,
+        key_code: keyboard::KeyCode,
+        modifiers: keyboard::ModifiersState,
+        messages: &mut Vec<Message>,
+    ) -> EventInteraction {
+        self.element
+            .widget
+            .on_shortcut(key_code, modifiers, messages)
+    }
+
+    fn focusables<'b>(
+        &'b mut self,
+        focusables: &mut Vec<&'b mut dyn focus::Focusable>,
+    ) {
+        self.element.focusables(focusables);
+    }
+
+    fn targets<'b>(&'b mut self, targets: &mut Vec<(&'b Id, &'b mut dyn Any)>) {
+        self.element.targets(targets);
+    }
+
+    fn redraw_request(&self) -> Option<Instant> {
+        self.element.redraw_request()
+    }
 }
@@ -0,0 +1,211 @@
+//! Move the keyboard focus between widgets.
+//!
+//! A [`UserInterface`] collects the [`Focusable`] widgets of its tree in
+//! order and cycles through them when `Tab` or `Shift+Tab` are pressed and
+//! the focused widget does not use the key.
+//!
+//! The focus can also be changed or queried from your update logic with the
+//! [`Command`] produced by [`focus`], [`unfocus`], and [`focused`].
+//!
+//! [`UserInterface`]: ../struct.UserInterface.html
+//! [`Focusable`]: trait.Focusable.html
+//! [`Command`]: ../struct.Command.html
+//! [`focus`]: fn.focus.html
+//! [`unfocus`]: fn.unfocus.html
+//! [`focused`]: fn.focused.html
+use crate::command::{self, Command};
+use crate::pane_grid::Pane;
+use crate::Id;
+
+/// A widget that can receive keyboard focus.
+pub trait Focusable {
+    /// Returns whether the [`Focusable`] is currently focused.
+    ///
+    /// [`Focusable`]: trait.Focusable.html
+    fn is_focused(&self) -> bool;
+
+    /// Focuses the [`Focusable`].
+    ///
+    /// [`Focusable`]: trait.Focusable.html
+    fn focus(&mut self);
+
+    /// Unfocuses the [`Focusable`].
+    ///
+    /// [`Focusable`]: trait.Focusable.html
+    fn unfocus(&mut self);
+
+    /// Returns whether the [`Focusable`] remembers its focus in some local
+    /// state.
+    ///
+    /// Widgets without local state, like a [`Checkbox`], are rebuilt unfocused
+    /// every frame. The [`UserInterface`] keeps track of their focus instead.
+    ///
+    /// By default, it returns `true`.
+    ///
+    /// [`Focusable`]: trait.Focusable.html
+    /// [`Checkbox`]: ../widget/checkbox/struct.Checkbox.html
+    /// [`UserInterface`]: ../struct.UserInterface.html
+    fn is_stateful(&self) -> bool {
+        true
+    }
+
+    /// Returns the [`Id`] of the [`Focusable`], if it has one.
+    ///
+    /// The [`UserInterface`] uses it to keep track of the focused widget when
+    /// other widgets are added or removed around it.
+    ///
+    /// By default, it returns `None`.
+    ///
+    /// [`Id`]: ../widget/struct.Id.html
+    /// [`Focusable`]: trait.Focusable.html
+    /// [`UserInterface`]: ../struct.UserInterface.html
+    fn id(&self) -> Option<&Id> {
+        None
+    }
+}
+
+/// The direction of a focus traversal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// Towards the next [`Focusable`] in the chain.
+    ///
+    /// [`Focusable`]: trait.Focusable.html
+    Forward,
+
+    /// Towards the previous [`Focusable`] in the chain.
+    ///
+    /// [`Focusable`]: trait.Focusable.html
+    Backward,
+}
+
+/// Moves the focus of a chain of [`Focusable`] widgets in the given
+/// [`Direction`].
+///
+/// It returns the index of the newly focused widget, if any.
+///
+/// [`Focusable`]: trait.Focusable.html
+/// [`Direction`]: enum.Direction.html
+pub fn traverse(
+    focusables: &mut [&mut dyn Focusable],
+    direction: Direction,
+) -> Option<usize> {
+    let total = focusables.len();
+
+    if total == 0 {
+        return None;
+    }
+
+    let current = focusables
+        .iter()
+        .position(|focusable| focusable.is_focused());
+
+    for focusable in focusables.iter_mut() {
+        if focusable.is_focused() {
+            focusable.unfocus();
+        }
+    }
+
+    let next = match (current, direction) {
+        (Some(index), Direction::Forward) => (index + 1) % total,
+        (Some(index), Direction::Backward) => (index + total - 1) % total,
+        (None, Direction::Forward) => 0,
+        (None, Direction::Backward) => total - 1,
+    };
+
+    focusables[next].focus();
+
+    Some(next)
+}
+
+/// Produces a [`Command`] that focuses the widget with the given [`Id`].
+///
+/// [`Command`]: ../struct.Command.html
+/// [`Id`]: ../widget/struct.Id.html
+pub fn focus<T>(id: Id) -> Command<T> {
+    Command::single(command::Action::Focus(Action::Focus(id)))
+}
+
+/// Produces a [`Command`] that unfocuses the currently focused widget.
+///
+/// [`Command`]: ../struct.Command.html
+pub fn unfocus<T>() -> Command<T> {
+    Command::single(command::Action::Focus(Action::Unfocus))
+}
+
+/// Produces a [`Command`] that queries the [`Id`] of the currently focused
+/// widget and turns it into a message.
+///
+/// The [`Id`] will be `None` if no widget is focused or if the focused
+/// widget does not have an [`Id`].
+///
+/// [`Command`]: ../struct.Command.html
+/// [`Id`]: ../widget/struct.Id.html
+pub fn focused<T>(f: impl Fn(Option<Id>) -> T + 'static + Send) -> Command<T> {
+    Command::single(command::Action::Focus(Action::Focused(Box::new(f))))
+}
+
+/// A focus operation performed by a [`UserInterface`].
+///
+/// [`UserInterface`]: ../struct.UserInterface.html
+pub enum Action<T> {
+    /// Focus the widget with the given [`Id`].
+    ///
+    /// [`Id`]: ../widget/struct.Id.html
+    Focus(Id),
+
+    /// Unfocus the currently focused widget.
+    Unfocus,
+
+    /// Query the [`Id`] of the currently focused widget.
+    ///
+    /// [`Id`]: ../widget/struct.Id.html
+    Focused(Box<dyn Fn(Option<Id>) -> T + Send>),
+
+    /// Focus a [`Pane`] of the [`PaneGrid`] with the given [`Id`].
+    ///
+    /// [`Pane`]: ../widget/pane_grid/struct.Pane.html
+    /// [`PaneGrid`]: ../widget/pane_grid/struct.PaneGrid.html
+    /// [`Id`]: ../widget/struct.Id.html
+    FocusPane(Id, Pane),
+
+    /// Unfocus the focused [`Pane`] of the [`PaneGrid`] with the given [`Id`].
+    ///
+    /// [`Pane`]: ../widget/pane_grid/struct.Pane.html
+    /// [`PaneGrid`]: ../widget/pane_grid/struct.PaneGrid.html
+    /// [`Id`]: ../widget/struct.Id.html
+    UnfocusPane(Id),
+}
+
+impl<T> Action<T> {
+    /// Applies a transformation to the result of an [`Action`].
+    ///
+    /// [`Action`]: enum.Action.html
+    pub fn map<A>(self, f: impl Fn(T) -> A + 'static + Send + Sync) -> Action<A>
+    where
+        T: 'static,
+    {
+        match self {
+            Action::Focus(id) => Action::Focus(id),
+            Action::Unfocus => Action::Unfocus,
+            Action::Focused(g) => Action::Focused(Box::new(move |id| f(g(id)))),
+            Action::FocusPane(id, pane) => Action::FocusPane(id, pane),
+            Action::UnfocusPane(id) => Action::UnfocusPane(id),
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for Action<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Action::Focus(id) => write!(f, "Action::Focus({:?})", id),
+            Action::Unfocus => write!(f, "Action::Unfocus"),
+            Action::Focused(_) => write!(f, "Action::Focused"),
+            Action::FocusPane(id, pane) => {
+                write!(f, "Action::FocusPane({:?}, {:?})", id, pane)
+            }
+            Action::UnfocusPane(id) => {
+                write!(f, "Action::UnfocusPane({:?})", id)
+            }
+        }
+    }
+}
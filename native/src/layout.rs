@@ -4,6 +4,8 @@ mod limits;
 mod node;
 
 pub mod flex;
+pub mod grid;
+pub mod wrap;
 
 pub use debugger::Debugger;
 pub use limits::Limits;
@@ -18,7 +18,7 @@
 // limitations under the License.
 use crate::{
     layout::{Limits, Node},
-    Align, Element, Point, Size,
+    Align, AlignItems, Element, Justify, Length, Padding, Point, Size,
 };
 
 /// The main axis of a flex layout.
@@ -32,21 +32,21 @@ pub enum Axis {
 }
 
 impl Axis {
-    fn main(&self, size: Size) -> f32 {
+    pub(crate) fn main(&self, size: Size) -> f32 {
         match self {
             Axis::Horizontal => size.width,
             Axis::Vertical => size.height,
         }
     }
 
-    fn cross(&self, size: Size) -> f32 {
+    pub(crate) fn cross(&self, size: Size) -> f32 {
         match self {
             Axis::Horizontal => size.height,
             Axis::Vertical => size.width,
         }
     }
 
-    fn pack(&self, main: f32, cross: f32) -> (f32, f32) {
+    pub(crate) fn pack(&self, main: f32, cross: f32) -> (f32, f32) {
         match self {
             Axis::Horizontal => (main, cross),
             Axis::Vertical => (cross, main),
@@ -55,7 +55,7 @@ impl Axis {
 }
 
 /// Computes the flex layout with the given axis and limits, applying spacing,
-/// padding and alignment to the items as needed.
+/// padding, justification and alignment to the items as needed.
 ///
 /// It returns a new layout [`Node`].
 ///
@@ -64,9 +64,10 @@ pub fn resolve<Message, Renderer>(
     axis: Axis,
     renderer: &Renderer,
     limits: &Limits,
-    padding: f32,
+    padding: Padding,
     spacing: f32,
-    align_items: Align,
+    justify_content: Justify,
+    align_items: AlignItems,
     items: &[Element<'_, Message, Renderer>],
 ) -> Node
 where
@@ -143,23 +144,72 @@ where
         }
     }
 
-    let mut main = padding;
+    if align_items == AlignItems::Stretch {
+        for (child, node) in items.iter().zip(nodes.iter_mut()) {
+            let cross_length = match axis {
+                Axis::Horizontal => child.height(),
+                Axis::Vertical => child.width(),
+            };
+
+            // Elements with a fixed size are not stretched
+            if let Length::Units(_) = cross_length {
+                continue;
+            }
+
+            let (width, height) = axis.pack(axis.main(node.size()), cross);
+            let size = Size::new(width, height);
+
+            *node = child.layout(renderer, &Limits::new(size, size));
+        }
+    }
+
+    let content_main =
+        nodes.iter().map(|node| axis.main(node.size())).sum::<f32>()
+            + total_spacing;
+
+    let (width, height) = axis.pack(content_main, cross);
+    let size = limits.resolve(Size::new(width, height));
+
+    let free = axis.main(size) - content_main;
+    let free = if free.is_finite() { free.max(0.0) } else { 0.0 };
+    let count = nodes.len() as f32;
+
+    let (leading, gap) = match justify_content {
+        Justify::Start => (0.0, 0.0),
+        Justify::Center => (free / 2.0, 0.0),
+        Justify::End => (free, 0.0),
+        Justify::SpaceBetween if nodes.len() > 1 => (0.0, free / (count - 1.0)),
+        Justify::SpaceBetween => (0.0, 0.0),
+        Justify::SpaceAround => (free / count / 2.0, free / count),
+        Justify::SpaceEvenly => (free / (count + 1.0), free / (count + 1.0)),
+    };
+
+    let (main_padding, cross_padding) =
+        axis.pack(f32::from(padding.left), f32::from(padding.top));
+
+    let align = match align_items {
+        AlignItems::Start | AlignItems::Stretch => Align::Start,
+        AlignItems::Center => Align::Center,
+        AlignItems::End => Align::End,
+    };
+
+    let mut main = main_padding + leading;
 
     for (i, node) in nodes.iter_mut().enumerate() {
         if i > 0 {
-            main += spacing;
+            main += spacing + gap;
         }
 
-        let (x, y) = axis.pack(main, padding);
+        let (x, y) = axis.pack(main, cross_padding);
 
         node.move_to(Point::new(x, y));
 
         match axis {
             Axis::Horizontal => {
-                node.align(Align::Start, align_items, Size::new(0.0, cross));
+                node.align(Align::Start, align, Size::new(0.0, cross));
             }
             Axis::Vertical => {
-                node.align(align_items, Align::Start, Size::new(cross, 0.0));
+                node.align(align, Align::Start, Size::new(cross, 0.0));
             }
         }
 
@@ -168,8 +218,50 @@ where
         main += axis.main(size);
     }
 
-    let (width, height) = axis.pack(main - padding, cross);
-    let size = limits.resolve(Size::new(width, height));
-
     Node::with_children(size.pad(padding), nodes)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{renderer::Null, Column};
+
+    fn item(width: u16, height: Length) -> Element<'static, (), Null> {
+        Column::new()
+            .width(Length::Units(width))
+            .height(height)
+            .into()
+    }
+
+    #[test]
+    fn justifies_and_stretches_items() {
+        let node = resolve(
+            Axis::Horizontal,
+            &Null,
+            &Limits::new(Size::ZERO, Size::new(100.0, 100.0))
+                .width(Length::Fill)
+                .height(Length::Shrink),
+            Padding::ZERO,
+            0.0,
+            Justify::SpaceBetween,
+            AlignItems::Stretch,
+            &[
+                item(10, Length::Units(30)),
+                item(10, Length::Shrink),
+                item(10, Length::Units(10)),
+            ],
+        );
+
+        let bounds: Vec<_> = node
+            .children()
+            .iter()
+            .map(|child| {
+                let bounds = child.bounds();
+
+                (bounds.x, bounds.height)
+            })
+            .collect();
+
+        assert_eq!(bounds, vec![(0.0, 30.0), (45.0, 30.0), (90.0, 10.0)]);
+    }
+}
@@ -0,0 +1,362 @@
+//! Distribute elements in the cells of a grid of rows and columns.
+use crate::{
+    layout::{Limits, Node},
+    Align, Element, Length, Padding, Point, Size,
+};
+
+/// The cells of a grid occupied by an element and its alignment in them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Area {
+    /// The index of the first column of the [`Area`].
+    ///
+    /// [`Area`]: struct.Area.html
+    pub column: usize,
+
+    /// The index of the first row of the [`Area`].
+    ///
+    /// [`Area`]: struct.Area.html
+    pub row: usize,
+
+    /// The amount of columns spanned by the [`Area`].
+    ///
+    /// [`Area`]: struct.Area.html
+    pub column_span: usize,
+
+    /// The amount of rows spanned by the [`Area`].
+    ///
+    /// [`Area`]: struct.Area.html
+    pub row_span: usize,
+
+    /// The horizontal alignment of the element in the [`Area`].
+    ///
+    /// [`Area`]: struct.Area.html
+    pub horizontal_alignment: Align,
+
+    /// The vertical alignment of the element in the [`Area`].
+    ///
+    /// [`Area`]: struct.Area.html
+    pub vertical_alignment: Align,
+}
+
+/// The column and row tracks of a grid and the spacing between them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tracks<'a> {
+    /// The sizes of the columns of the grid.
+    pub columns: &'a [Length],
+
+    /// The sizes of the rows of the grid.
+    pub rows: &'a [Length],
+
+    /// The horizontal spacing between columns.
+    pub column_spacing: f32,
+
+    /// The vertical spacing between rows.
+    pub row_spacing: f32,
+}
+
+impl Area {
+    /// Creates a new [`Area`] of a single cell, aligning its element at the
+    /// start of both axes.
+    ///
+    /// [`Area`]: struct.Area.html
+    pub fn new(column: usize, row: usize) -> Self {
+        Area {
+            column,
+            row,
+            column_span: 1,
+            row_span: 1,
+            horizontal_alignment: Align::Start,
+            vertical_alignment: Align::Start,
+        }
+    }
+
+    fn columns(&self) -> std::ops::Range<usize> {
+        self.column..self.column + self.column_span.max(1)
+    }
+
+    fn rows(&self) -> std::ops::Range<usize> {
+        self.row..self.row + self.row_span.max(1)
+    }
+}
+
+/// Computes the grid layout with the given [`Tracks`], placing every item in
+/// its [`Area`].
+///
+/// Tracks can be sized with any [`Length`]:
+///   * [`Length::Units`] tracks have a fixed size.
+///   * [`Length::Shrink`] tracks fit the items placed only in them.
+///   * [`Length::Fill`] and [`Length::FillPortion`] tracks share the
+///     remaining space.
+///
+/// Items placed outside of the given tracks create new [`Length::Shrink`]
+/// tracks. Items spanning many tracks without any [`Length::Fill`] one grow
+/// the last [`Length::Shrink`] track they span, if needed.
+///
+/// It returns a new layout [`Node`], with a child for every item.
+///
+/// [`Tracks`]: struct.Tracks.html
+/// [`Area`]: struct.Area.html
+/// [`Length`]: ../../enum.Length.html
+/// [`Length::Units`]: ../../enum.Length.html#variant.Units
+/// [`Length::Shrink`]: ../../enum.Length.html#variant.Shrink
+/// [`Length::Fill`]: ../../enum.Length.html#variant.Fill
+/// [`Length::FillPortion`]: ../../enum.Length.html#variant.FillPortion
+/// [`Node`]: ../struct.Node.html
+pub fn resolve<Message, Renderer>(
+    renderer: &Renderer,
+    limits: &Limits,
+    padding: Padding,
+    tracks: Tracks<'_>,
+    areas: &[Area],
+    items: &[Element<'_, Message, Renderer>],
+) -> Node
+where
+    Renderer: crate::Renderer,
+{
+    let Tracks {
+        columns,
+        rows,
+        column_spacing,
+        row_spacing,
+    } = tracks;
+
+    let limits = limits.pad(padding);
+    let max = limits.max();
+
+    let columns = self::tracks(columns, areas.iter().map(Area::columns));
+    let rows = self::tracks(rows, areas.iter().map(Area::rows));
+
+    let available_width =
+        max.width - column_spacing * columns.len().saturating_sub(1) as f32;
+
+    let available_height =
+        max.height - row_spacing * rows.len().saturating_sub(1) as f32;
+
+    let loose = Limits::new(Size::ZERO, Size::new(available_width, max.height));
+
+    let widths = sizes(
+        &columns,
+        available_width,
+        column_spacing,
+        areas.iter().zip(items).map(|(area, item)| {
+            let width = if item.width().fill_factor() == 0 {
+                item.layout(renderer, &loose).size().width
+            } else {
+                0.0
+            };
+
+            (area.columns(), width)
+        }),
+    );
+
+    let heights = sizes(
+        &rows,
+        available_height,
+        row_spacing,
+        areas.iter().zip(items).map(|(area, item)| {
+            let height = if item.height().fill_factor() == 0 {
+                let width = span(&widths, area.columns(), column_spacing);
+                let limits =
+                    Limits::new(Size::ZERO, Size::new(width, available_height));
+
+                item.layout(renderer, &limits).size().height
+            } else {
+                0.0
+            };
+
+            (area.rows(), height)
+        }),
+    );
+
+    let nodes = areas
+        .iter()
+        .zip(items)
+        .map(|(area, item)| {
+            let x = f32::from(padding.left)
+                + offset(&widths, area.column, column_spacing);
+            let y = f32::from(padding.top)
+                + offset(&heights, area.row, row_spacing);
+
+            let cell = Size::new(
+                span(&widths, area.columns(), column_spacing),
+                span(&heights, area.rows(), row_spacing),
+            );
+
+            let mut node =
+                item.layout(renderer, &Limits::new(Size::ZERO, cell));
+
+            node.move_to(Point::new(x, y));
+            node.align(
+                area.horizontal_alignment,
+                area.vertical_alignment,
+                cell,
+            );
+
+            node
+        })
+        .collect();
+
+    let intrinsic_size = Size::new(
+        span(&widths, 0..widths.len(), column_spacing),
+        span(&heights, 0..heights.len(), row_spacing),
+    );
+
+    let size = limits.resolve(intrinsic_size);
+
+    Node::with_children(size.pad(padding), nodes)
+}
+
+/// Returns the given tracks, adding [`Length::Shrink`] tracks until every
+/// span fits.
+///
+/// [`Length::Shrink`]: ../../enum.Length.html#variant.Shrink
+fn tracks(
+    tracks: &[Length],
+    spans: impl Iterator<Item = std::ops::Range<usize>>,
+) -> Vec<Length> {
+    let count = spans.map(|span| span.end).fold(tracks.len(), usize::max);
+
+    let mut tracks = tracks.to_vec();
+    tracks.resize(count, Length::Shrink);
+
+    tracks
+}
+
+/// Computes the size of every track, given the available space and the
+/// intrinsic size of the items spanning them.
+fn sizes(
+    tracks: &[Length],
+    available: f32,
+    spacing: f32,
+    items: impl Iterator<Item = (std::ops::Range<usize>, f32)>,
+) -> Vec<f32> {
+    let items: Vec<_> = items.collect();
+
+    let mut sizes: Vec<f32> = tracks
+        .iter()
+        .map(|track| match track {
+            Length::Units(units) => f32::from(*units),
+            _ => 0.0,
+        })
+        .collect();
+
+    // Without a bound, fill tracks fit their contents too
+    let fits_content = |track: &Length| {
+        *track == Length::Shrink
+            || (track.fill_factor() > 0 && !available.is_finite())
+    };
+
+    // Single tracks first, so spanning items only grow what is needed
+    for (span, size) in items.iter().filter(|(span, _)| span.len() == 1) {
+        if fits_content(&tracks[span.start]) {
+            sizes[span.start] = sizes[span.start].max(*size);
+        }
+    }
+
+    // Items spanning fill tracks will get their share of the remaining space
+    for (span, size) in items.iter().filter(|(span, _)| {
+        span.len() > 1
+            && (!available.is_finite()
+                || tracks[span.clone()]
+                    .iter()
+                    .all(|track| track.fill_factor() == 0))
+    }) {
+        let current = self::span(&sizes, span.clone(), spacing);
+
+        if let Some(last_shrink) =
+            span.clone().rev().find(|i| fits_content(&tracks[*i]))
+        {
+            if *size > current {
+                sizes[last_shrink] += size - current;
+            }
+        }
+    }
+
+    let fill_factors: u16 = tracks.iter().map(Length::fill_factor).sum();
+
+    if fill_factors > 0 {
+        let remaining = (available - sizes.iter().sum::<f32>()).max(0.0);
+
+        for (track, size) in tracks.iter().zip(sizes.iter_mut()) {
+            let fill_factor = track.fill_factor();
+
+            if fill_factor > 0 && remaining.is_finite() {
+                *size = remaining * f32::from(fill_factor)
+                    / f32::from(fill_factors);
+            }
+        }
+    }
+
+    sizes
+}
+
+/// Returns the offset of the track with the given index.
+fn offset(sizes: &[f32], index: usize, spacing: f32) -> f32 {
+    sizes[..index].iter().map(|size| size + spacing).sum()
+}
+
+/// Returns the total size of the given range of tracks, including the spacing
+/// between them.
+fn span(sizes: &[f32], range: std::ops::Range<usize>, spacing: f32) -> f32 {
+    let count = range.len();
+
+    sizes[range].iter().sum::<f32>() + spacing * count.saturating_sub(1) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{renderer::Null, Column};
+
+    fn item(width: u16, height: u16) -> Element<'static, (), Null> {
+        Column::new()
+            .width(Length::Units(width))
+            .height(Length::Units(height))
+            .into()
+    }
+
+    #[test]
+    fn sizes_tracks_and_spans() {
+        let areas = [
+            Area::new(0, 0),
+            Area::new(1, 0),
+            Area {
+                column_span: 2,
+                horizontal_alignment: Align::Center,
+                ..Area::new(0, 1)
+            },
+        ];
+
+        let node = resolve(
+            &Null,
+            &Limits::new(Size::ZERO, Size::new(200.0, 200.0))
+                .width(Length::Fill)
+                .height(Length::Shrink),
+            Padding::ZERO,
+            Tracks {
+                columns: &[Length::Shrink, Length::Fill],
+                rows: &[Length::Units(30)],
+                column_spacing: 10.0,
+                row_spacing: 5.0,
+            },
+            &areas,
+            &[item(40, 10), item(20, 10), item(50, 10)],
+        );
+
+        let bounds: Vec<_> = node
+            .children()
+            .iter()
+            .map(|child| {
+                let bounds = child.bounds();
+
+                (bounds.x, bounds.y, bounds.width)
+            })
+            .collect();
+
+        assert_eq!(
+            bounds,
+            vec![(0.0, 0.0, 40.0), (50.0, 0.0, 20.0), (75.0, 35.0, 50.0)]
+        );
+        assert_eq!(node.size(), Size::new(200.0, 45.0));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Length, Size};
+use crate::{Length, Padding, Size};
 
 /// A set of size constraints for layouting.
 #[derive(Debug, Clone, Copy)]
@@ -140,11 +140,15 @@ impl Limits {
         self
     }
 
-    /// Shrinks the current [`Limits`] to account for the given padding.
+    /// Shrinks the current [`Limits`] to account for the given [`Padding`].
     ///
     /// [`Limits`]: struct.Limits.html
-    pub fn pad(&self, padding: f32) -> Limits {
-        self.shrink(Size::new(padding * 2.0, padding * 2.0))
+    /// [`Padding`]: ../struct.Padding.html
+    pub fn pad(&self, padding: Padding) -> Limits {
+        self.shrink(Size::new(
+            f32::from(padding.horizontal()),
+            f32::from(padding.vertical()),
+        ))
     }
 
     /// Shrinks the current [`Limits`] by the given [`Size`].
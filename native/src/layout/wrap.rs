@@ -0,0 +1,167 @@
+//! Distribute elements in lines, breaking them when they run out of space.
+use crate::{
+    layout::{flex::Axis, Limits, Node},
+    Align, Element, Padding, Point, Size,
+};
+
+/// Computes a wrapping layout with the given axis and limits.
+///
+/// Items are placed one after another along the main axis, starting a new
+/// line when the next item does not fit. Lines are stacked along the cross
+/// axis.
+///
+/// `spacing` separates the items of a line, while `line_spacing` separates
+/// the lines. Every line is positioned along the main axis following
+/// `align_lines`, and its items are positioned along the cross axis
+/// following `align_items`.
+///
+/// It returns a new layout [`Node`].
+///
+/// [`Node`]: ../struct.Node.html
+pub fn resolve<Message, Renderer>(
+    axis: Axis,
+    renderer: &Renderer,
+    limits: &Limits,
+    padding: Padding,
+    spacing: f32,
+    line_spacing: f32,
+    align_items: Align,
+    align_lines: Align,
+    items: &[Element<'_, Message, Renderer>],
+) -> Node
+where
+    Renderer: crate::Renderer,
+{
+    let limits = limits.pad(padding);
+    let max_main = axis.main(limits.max());
+
+    let child_limits = Limits::new(Size::ZERO, limits.max());
+
+    let mut nodes: Vec<Node> = Vec::with_capacity(items.len());
+
+    // Every line is the range of its items, its main length and its cross
+    // length
+    let mut lines: Vec<(std::ops::Range<usize>, f32, f32)> = Vec::new();
+    let mut line = (0..0, 0.0, 0.0);
+
+    for (i, child) in items.iter().enumerate() {
+        let node = child.layout(renderer, &child_limits);
+        let size = node.size();
+
+        let main = if line.0.is_empty() {
+            axis.main(size)
+        } else {
+            line.1 + spacing + axis.main(size)
+        };
+
+        if main > max_main && !line.0.is_empty() {
+            lines.push(line);
+            line = (i..i, axis.main(size), 0.0);
+        } else {
+            line.1 = main;
+        }
+
+        line.0.end = i + 1;
+        line.2 = f32::max(line.2, axis.cross(size));
+
+        nodes.push(node);
+    }
+
+    if !line.0.is_empty() {
+        lines.push(line);
+    }
+
+    let intrinsic_main =
+        lines.iter().map(|(_, main, _)| *main).fold(0.0, f32::max);
+
+    let intrinsic_cross = lines.iter().map(|(_, _, cross)| *cross).sum::<f32>()
+        + line_spacing * lines.len().saturating_sub(1) as f32;
+
+    let (width, height) = axis.pack(intrinsic_main, intrinsic_cross);
+    let size = limits.resolve(Size::new(width, height));
+    let available = axis.main(size);
+
+    let (main_padding, cross_padding) =
+        axis.pack(f32::from(padding.left), f32::from(padding.top));
+
+    let mut cross = cross_padding;
+
+    for (range, line_main, line_cross) in lines {
+        let mut main = main_padding
+            + match align_lines {
+                Align::Start => 0.0,
+                Align::Center => (available - line_main).max(0.0) / 2.0,
+                Align::End => (available - line_main).max(0.0),
+            };
+
+        for node in &mut nodes[range] {
+            let (x, y) = axis.pack(main, cross);
+
+            node.move_to(Point::new(x, y));
+
+            match axis {
+                Axis::Horizontal => {
+                    node.align(
+                        Align::Start,
+                        align_items,
+                        Size::new(0.0, line_cross),
+                    );
+                }
+                Axis::Vertical => {
+                    node.align(
+                        align_items,
+                        Align::Start,
+                        Size::new(line_cross, 0.0),
+                    );
+                }
+            }
+
+            main += axis.main(node.size()) + spacing;
+        }
+
+        cross += line_cross + line_spacing;
+    }
+
+    Node::with_children(size.pad(padding), nodes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{renderer::Null, Column, Length};
+
+    #[test]
+    fn breaks_lines_when_out_of_space() {
+        let items: Vec<Element<'_, (), Null>> = (0..3)
+            .map(|_| {
+                Column::new()
+                    .width(Length::Units(30))
+                    .height(Length::Units(10))
+                    .into()
+            })
+            .collect();
+
+        let node = resolve(
+            Axis::Horizontal,
+            &Null,
+            &Limits::new(Size::ZERO, Size::new(100.0, 100.0))
+                .width(Length::Shrink)
+                .height(Length::Shrink),
+            Padding::ZERO,
+            10.0,
+            5.0,
+            Align::Start,
+            Align::Center,
+            &items,
+        );
+
+        let positions: Vec<_> = node
+            .children()
+            .iter()
+            .map(|child| (child.bounds().x, child.bounds().y))
+            .collect();
+
+        assert_eq!(positions, vec![(0.0, 0.0), (40.0, 0.0), (20.0, 15.0)]);
+        assert_eq!(node.size(), Size::new(70.0, 25.0));
+    }
+}
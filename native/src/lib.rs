@@ -35,6 +35,9 @@
 #![deny(unused_results)]
 #![forbid(unsafe_code)]
 #![forbid(rust_2018_idioms)]
+pub mod clipboard;
+pub mod command;
+pub mod focus;
 pub mod keyboard;
 pub mod layout;
 pub mod mouse;
@@ -45,7 +48,6 @@ pub mod subscription;
 pub mod widget;
 pub mod window;
 
-mod clipboard;
 mod element;
 mod event;
 mod hasher;
@@ -62,15 +64,17 @@ mod debug;
 mod debug;
 
 pub use iced_core::{
-    Align, Background, Color, Font, HorizontalAlignment, Length, Point,
-    Rectangle, Size, Vector, VerticalAlignment,
+    animation, Align, AlignItems, Background, Color, Font, HorizontalAlignment,
+    Justify, Length, Padding, Point, Rectangle, Size, Vector,
+    VerticalAlignment,
 };
-pub use iced_futures::{executor, futures, Command};
+pub use iced_futures::{executor, futures};
 
 #[doc(no_inline)]
 pub use executor::Executor;
 
 pub use clipboard::Clipboard;
+pub use command::Command;
 pub use debug::Debug;
 pub use element::Element;
 pub use event::Event;
@@ -1,15 +1,19 @@
 //! Display interactive elements on top of other widgets.
 mod element;
+mod group;
 
 pub mod menu;
 
 pub use element::Element;
+pub use group::Group;
 pub use menu::Menu;
 
 use crate::{
     layout, Clipboard, Event, EventInteraction, Hasher, Layout, Point, Size,
 };
 
+use std::time::Instant;
+
 /// An interactive component that can be displayed on top of other widgets.
 pub trait Overlay<Message, Renderer>
 where
@@ -84,4 +88,51 @@ where
     ) -> EventInteraction {
         EventInteraction::default()
     }
+    /// Returns the nested overlay of the [`Overlay`], if there is any.
+    ///
+    /// A nested overlay is displayed on top of the [`Overlay`] that produced
+    /// it. It may produce overlays of its own, forming a stack.
+    ///
+    /// By default, it returns `None`.
+    ///
+    /// [`Overlay`]: trait.Overlay.html
+    fn overlay(
+        &mut self,
+        _layout: Layout<'_>,
+    ) -> Option<Element<'_, Message, Renderer>> {
+        None
+    }
+
+    /// Returns whether the [`Overlay`] captures the cursor at the given
+    /// position.
+    ///
+    /// The layers below an [`Overlay`] capturing the cursor do not see it.
+    /// Non-interactive overlays, like tooltips, should let it through.
+    ///
+    /// By default, it captures the cursor when it is inside its bounds.
+    ///
+    /// [`Overlay`]: trait.Overlay.html
+    fn is_over(&self, layout: Layout<'_>, cursor_position: Point) -> bool {
+        layout.bounds().contains(cursor_position)
+    }
+
+    /// Returns the [`Instant`] when the [`Overlay`] needs to be redrawn, if
+    /// any.
+    ///
+    /// Overlays displaying widgets should return the redraw requests of
+    /// their contents. Nested overlays are queried separately.
+    ///
+    /// By default, it returns `None`.
+    ///
+    /// [`Instant`]: https://doc.rust-lang.org/std/time/struct.Instant.html
+    /// [`Overlay`]: trait.Overlay.html
+    fn redraw_request(&self) -> Option<Instant> {
+        None
+    }
 }
+
+/// The cursor position seen by a layer when the cursor is captured by an
+/// overlay on top of it.
+///
+/// It lies outside of any window, so no widget is considered hovered.
+pub(crate) const UNAVAILABLE_CURSOR: Point = Point::new(f32::MIN, f32::MIN);
@@ -5,6 +5,8 @@ use crate::{
     Vector,
 };
 
+use std::time::Instant;
+
 /// A generic [`Overlay`].
 ///
 /// [`Overlay`]: trait.Overlay.html
@@ -102,6 +104,33 @@ where
     pub fn hash_layout(&self, state: &mut Hasher) {
         self.overlay.hash_layout(state, self.position);
     }
+
+    /// Returns the nested overlay of the [`Element`], if there is any.
+    ///
+    /// [`Element`]: struct.Element.html
+    pub fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<Element<'_, Message, Renderer>> {
+        self.overlay.overlay(layout)
+    }
+
+    /// Returns whether the [`Element`] captures the cursor at the given
+    /// position.
+    ///
+    /// [`Element`]: struct.Element.html
+    pub fn is_over(&self, layout: Layout<'_>, cursor_position: Point) -> bool {
+        self.overlay.is_over(layout, cursor_position)
+    }
+
+    /// Returns the [`Instant`] when the [`Element`] needs to be redrawn, if
+    /// any.
+    ///
+    /// [`Instant`]: https://doc.rust-lang.org/std/time/struct.Instant.html
+    /// [`Element`]: struct.Element.html
+    pub fn redraw_request(&self) -> Option<Instant> {
+        self.overlay.redraw_request()
+    }
 }
 
 struct Map<'a, A, B, Renderer> {
@@ -172,4 +201,24 @@ where
     fn hash_layout(&self, state: &mut Hasher, position: Point) {
         self.content.hash_layout(state, position);
     }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<Element<'_, B, Renderer>> {
+        let mapper = self.mapper;
+
+        self.content.overlay(layout).map(|overlay| Element {
+            position: overlay.position,
+            overlay: Box::new(Map::new(overlay.overlay, mapper)),
+        })
+    }
+
+    fn is_over(&self, layout: Layout<'_>, cursor_position: Point) -> bool {
+        self.content.is_over(layout, cursor_position)
+    }
+
+    fn redraw_request(&self) -> Option<Instant> {
+        self.content.redraw_request()
+    }
 }
@@ -0,0 +1,215 @@
+use crate::overlay::{self, Element, Overlay};
+use crate::{
+    layout, Clipboard, Event, EventInteraction, Hasher, Layout, Point, Size,
+    Vector,
+};
+
+use std::hash::Hash;
+use std::time::Instant;
+
+/// A collection of [`Overlay`] elements displayed at the same time.
+///
+/// The overlays are drawn in order, so every overlay is displayed on top of
+/// the previous ones. Events are processed from top to bottom, and an overlay
+/// under the cursor hides it from the overlays below.
+///
+/// [`Overlay`]: trait.Overlay.html
+#[allow(missing_debug_implementations)]
+pub struct Group<'a, Message, Renderer> {
+    children: Vec<Element<'a, Message, Renderer>>,
+}
+
+impl<'a, Message, Renderer> Group<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + crate::Renderer,
+{
+    /// Creates a [`Group`] with the given overlays, from bottom to top.
+    ///
+    /// [`Group`]: struct.Group.html
+    pub fn with_children(
+        children: Vec<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        Group { children }
+    }
+
+    /// Turns the [`Group`] into an overlay [`Element`], if it contains any
+    /// overlays.
+    ///
+    /// A [`Group`] with a single overlay produces the overlay itself.
+    ///
+    /// [`Group`]: struct.Group.html
+    /// [`Element`]: struct.Element.html
+    pub fn overlay(mut self) -> Option<Element<'a, Message, Renderer>> {
+        match self.children.len() {
+            0 => None,
+            1 => self.children.pop(),
+            _ => Some(Element::new(Point::ORIGIN, Box::new(self))),
+        }
+    }
+
+    /// Returns the cursor position seen by every overlay of the [`Group`].
+    ///
+    /// [`Group`]: struct.Group.html
+    fn cursor_positions(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Vec<Point> {
+        let layouts: Vec<_> = layout.children().collect();
+        let mut cursor_position = Some(cursor_position);
+
+        let mut cursor_positions: Vec<Point> = self
+            .children
+            .iter()
+            .zip(layouts)
+            .rev()
+            .map(|(child, layout)| {
+                let current =
+                    cursor_position.unwrap_or(overlay::UNAVAILABLE_CURSOR);
+
+                cursor_position = cursor_position
+                    .filter(|position| !child.is_over(layout, *position));
+
+                current
+            })
+            .collect();
+
+        cursor_positions.reverse();
+        cursor_positions
+    }
+}
+
+impl<'a, Message, Renderer> Overlay<Message, Renderer>
+    for Group<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + crate::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let translation = Vector::new(position.x, position.y);
+
+        layout::Node::with_children(
+            bounds,
+            self.children
+                .iter()
+                .map(|child| {
+                    let mut node = child.layout(renderer, bounds);
+                    node.move_to(node.bounds().position() + translation);
+
+                    node
+                })
+                .collect(),
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> EventInteraction {
+        let cursor_positions = self.cursor_positions(layout, cursor_position);
+        let layouts: Vec<_> = layout.children().collect();
+
+        self.children
+            .iter_mut()
+            .zip(layouts)
+            .zip(cursor_positions)
+            .rev()
+            .fold(
+                EventInteraction::default(),
+                |interaction, ((child, layout), cursor_position)| {
+                    child
+                        .on_event(
+                            event.clone(),
+                            layout,
+                            cursor_position,
+                            messages,
+                            renderer,
+                            clipboard,
+                        )
+                        .union(&interaction)
+                },
+            )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let cursor_positions = self.cursor_positions(layout, cursor_position);
+
+        let mut layers = self
+            .children
+            .iter()
+            .zip(layout.children())
+            .zip(cursor_positions)
+            .map(|((child, layout), cursor_position)| {
+                (
+                    child.draw(renderer, defaults, layout, cursor_position),
+                    layout.bounds(),
+                )
+            })
+            .collect::<Vec<_>>()
+            .into_iter();
+
+        // A group always contains overlays, see `Group::overlay`
+        let (base, _) = layers.next().expect("Draw empty overlay group");
+
+        layers.fold(base, |primitives, (overlay, bounds)| {
+            renderer.overlay(primitives, overlay, bounds)
+        })
+    }
+
+    fn hash_layout(&self, state: &mut Hasher, position: Point) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        (position.x as u32).hash(state);
+        (position.y as u32).hash(state);
+
+        for child in &self.children {
+            child.hash_layout(state);
+        }
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<Element<'_, Message, Renderer>> {
+        Group::with_children(
+            self.children
+                .iter_mut()
+                .zip(layout.children())
+                .filter_map(|(child, layout)| child.overlay(layout))
+                .collect(),
+        )
+        .overlay()
+    }
+
+    fn is_over(&self, layout: Layout<'_>, cursor_position: Point) -> bool {
+        self.children
+            .iter()
+            .zip(layout.children())
+            .any(|(child, layout)| child.is_over(layout, cursor_position))
+    }
+
+    fn redraw_request(&self) -> Option<Instant> {
+        self.children
+            .iter()
+            .filter_map(|child| child.redraw_request())
+            .min()
+    }
+}
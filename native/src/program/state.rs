@@ -1,8 +1,12 @@
 use crate::{
-    Cache, Clipboard, Command, Debug, Event, Point, Program, Renderer, Size,
-    UserInterface,
+    command, Cache, Clipboard, Command, Debug, Event, Point, Program, Renderer,
+    Size, UserInterface,
 };
 
+use iced_futures::futures::future;
+
+use std::time::Instant;
+
 /// The execution state of a [`Program`]. It leverages caching, event
 /// processing, and rendering primitive storage.
 ///
@@ -15,6 +19,7 @@ where
     program: P,
     cache: Option<Cache>,
     primitive: <P::Renderer as Renderer>::Output,
+    redraw_request: Option<Instant>,
     queued_events: Vec<Event>,
     queued_messages: Vec<P::Message>,
 }
@@ -47,12 +52,14 @@ where
         let primitive = user_interface.draw(renderer, cursor_position);
         debug.draw_finished();
 
+        let redraw_request = user_interface.redraw_request();
         let cache = Some(user_interface.into_cache());
 
         State {
             program,
             cache,
             primitive,
+            redraw_request,
             queued_events: Vec::new(),
             queued_messages: Vec::new(),
         }
@@ -73,6 +80,15 @@ where
         &self.primitive
     }
 
+    /// Returns the [`Instant`] when the [`State`] needs to be updated again to
+    /// redraw its widgets, even if its queue is empty.
+    ///
+    /// [`Instant`]: https://doc.rust-lang.org/std/time/struct.Instant.html
+    /// [`State`]: struct.State.html
+    pub fn redraw_request(&self) -> Option<Instant> {
+        self.redraw_request
+    }
+
     /// Queues an event in the [`State`] for processing during an [`update`].
     ///
     /// [`State`]: struct.State.html
@@ -96,13 +112,58 @@ where
         self.queued_events.is_empty() && self.queued_messages.is_empty()
     }
 
+    /// Performs the widget and clipboard actions of a [`Command`] in the
+    /// [`State`], redrawing the widgets of the linked [`Program`].
+    ///
+    /// Returns the remaining futures of the [`Command`], which should be run
+    /// by the shell.
+    ///
+    /// This is useful to run the [`Command`] produced when initializing a
+    /// [`Program`].
+    ///
+    /// [`Command`]: ../struct.Command.html
+    /// [`State`]: struct.State.html
+    /// [`Program`]: trait.Program.html
+    pub fn perform(
+        &mut self,
+        command: Command<P::Message>,
+        bounds: Size,
+        cursor_position: Point,
+        clipboard: Option<&dyn Clipboard>,
+        renderer: &mut P::Renderer,
+        debug: &mut Debug,
+    ) -> iced_futures::Command<P::Message> {
+        let mut user_interface = build_user_interface(
+            &mut self.program,
+            self.cache.take().unwrap(),
+            renderer,
+            bounds,
+            debug,
+        );
+
+        let futures = perform(&mut user_interface, command, clipboard);
+
+        debug.draw_started();
+        self.primitive = user_interface.draw(renderer, cursor_position);
+        debug.draw_finished();
+
+        self.redraw_request = user_interface.redraw_request();
+
+        self.cache = Some(user_interface.into_cache());
+
+        futures
+    }
+
     /// Processes all the queued events and messages, rebuilding and redrawing
     /// the widgets of the linked [`Program`] if necessary.
     ///
     /// Returns the [`Command`] obtained from [`Program`] after updating it,
-    /// only if an update was necessary.
+    /// only if an update was necessary. The widget and clipboard actions of
+    /// the [`Command`] are performed right away, so only its futures are
+    /// returned.
     ///
     /// [`Program`]: trait.Program.html
+    /// [`Command`]: ../struct.Command.html
     pub fn update_immediate(
         &mut self,
         bounds: Size,
@@ -111,7 +172,10 @@ where
         clipboard: Option<&dyn Clipboard>,
         renderer: &mut P::Renderer,
         debug: &mut Debug,
-    ) -> (Option<Command<P::Message>>, crate::EventInteraction) {
+    ) -> (
+        Option<iced_futures::Command<P::Message>>,
+        crate::EventInteraction,
+    ) {
         let mut user_interface = build_user_interface(
             &mut self.program,
             self.cache.take().unwrap(),
@@ -137,6 +201,8 @@ where
             self.primitive = user_interface.draw(renderer, cursor_position);
             debug.draw_finished();
 
+            self.redraw_request = user_interface.redraw_request();
+
             self.cache = Some(user_interface.into_cache());
 
             (None, interaction)
@@ -164,10 +230,14 @@ where
                 debug,
             );
 
+            let commands = perform(&mut user_interface, commands, clipboard);
+
             debug.draw_started();
             self.primitive = user_interface.draw(renderer, cursor_position);
             debug.draw_finished();
 
+            self.redraw_request = user_interface.redraw_request();
+
             self.cache = Some(user_interface.into_cache());
 
             (Some(commands), interaction)
@@ -177,9 +247,12 @@ where
     /// the widgets of the linked [`Program`] if necessary.
     ///
     /// Returns the [`Command`] obtained from [`Program`] after updating it,
-    /// only if an update was necessary.
+    /// only if an update was necessary. The widget and clipboard actions of
+    /// the [`Command`] are performed right away, so only its futures are
+    /// returned.
     ///
     /// [`Program`]: trait.Program.html
+    /// [`Command`]: ../struct.Command.html
     pub fn update(
         &mut self,
         bounds: Size,
@@ -187,7 +260,7 @@ where
         clipboard: Option<&dyn Clipboard>,
         renderer: &mut P::Renderer,
         debug: &mut Debug,
-    ) -> Option<Command<P::Message>> {
+    ) -> Option<iced_futures::Command<P::Message>> {
         let mut user_interface = build_user_interface(
             &mut self.program,
             self.cache.take().unwrap(),
@@ -213,6 +286,8 @@ where
             self.primitive = user_interface.draw(renderer, cursor_position);
             debug.draw_finished();
 
+            self.redraw_request = user_interface.redraw_request();
+
             self.cache = Some(user_interface.into_cache());
 
             None
@@ -240,10 +315,14 @@ where
                 debug,
             );
 
+            let commands = perform(&mut user_interface, commands, clipboard);
+
             debug.draw_started();
             self.primitive = user_interface.draw(renderer, cursor_position);
             debug.draw_finished();
 
+            self.redraw_request = user_interface.redraw_request();
+
             self.cache = Some(user_interface.into_cache());
 
             Some(commands)
@@ -268,3 +347,98 @@ fn build_user_interface<'a, P: Program>(
 
     user_interface
 }
+
+fn perform<Message, Renderer>(
+    user_interface: &mut UserInterface<'_, Message, Renderer>,
+    command: Command<Message>,
+    clipboard: Option<&dyn Clipboard>,
+) -> iced_futures::Command<Message>
+where
+    Message: 'static + Send,
+    Renderer: crate::Renderer,
+{
+    iced_futures::Command::batch(command.actions().into_iter().filter_map(
+        |action| {
+            match action {
+                command::Action::Future(future) => Some(future.into()),
+                command::Action::Focus(action) => user_interface
+                    .focus(action)
+                    .map(|message| future::ready(message).into()),
+                command::Action::Clipboard(action) => action
+                    .perform(clipboard)
+                    .map(|message| future::ready(message).into()),
+                command::Action::Scroll(action) => {
+                    user_interface.scroll(action);
+
+                    None
+                }
+            }
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{clipboard, renderer::Null, Element, Text};
+
+    use iced_futures::futures::FutureExt;
+
+    struct Viewer;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Pasted(Option<String>),
+    }
+
+    impl Program for Viewer {
+        type Renderer = Null;
+        type Message = Message;
+
+        fn update(&mut self, _message: Message) -> Command<Message> {
+            Command::none()
+        }
+
+        fn view(&mut self) -> Element<'_, Message, Null> {
+            Text::new("Viewer").into()
+        }
+    }
+
+    #[test]
+    fn performs_clipboard_commands() {
+        let clipboard = clipboard::Memory::new();
+        let bounds = Size::new(100.0, 100.0);
+        let mut debug = Debug::new();
+
+        let mut state =
+            State::new(Viewer, bounds, Point::ORIGIN, &mut Null, &mut debug);
+
+        let mut perform = |command| {
+            state
+                .perform(
+                    command,
+                    bounds,
+                    Point::ORIGIN,
+                    Some(&clipboard),
+                    &mut Null,
+                    &mut debug,
+                )
+                .futures()
+                .into_iter()
+                .filter_map(|future| future.now_or_never())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            perform(clipboard::read(Message::Pasted)),
+            vec![Message::Pasted(None)]
+        );
+
+        assert!(perform(clipboard::write(String::from("Hello"))).is_empty());
+
+        assert_eq!(
+            perform(clipboard::read(Message::Pasted)),
+            vec![Message::Pasted(Some(String::from("Hello")))]
+        );
+    }
+}
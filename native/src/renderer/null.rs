@@ -1,8 +1,10 @@
+use crate::animation::Frame;
 use crate::{
-    button, checkbox, column, container, pane_grid, progress_bar, radio, row,
-    scrollable, slider, text, text_input, Color, Element, Font,
-    HorizontalAlignment, Layout, Point, Rectangle, Renderer, Size,
-    VerticalAlignment,
+    button, checkbox, column, container, context_menu, grid, menu_bar, modal,
+    pane_grid, progress_bar, radio, row, scrollable, slider, stack, table,
+    tabs, text, text_editor, text_input, tooltip, tree_view, wrap, Color,
+    Element, Font, HorizontalAlignment, Layout, Padding, Point, Rectangle,
+    Renderer, Size, Vector, VerticalAlignment,
 };
 
 /// A renderer that does nothing.
@@ -50,6 +52,39 @@ impl row::Renderer for Null {
     }
 }
 
+impl grid::Renderer for Null {
+    fn draw<Message>(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _content: &[Element<'_, Message, Self>],
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+    ) {
+    }
+}
+
+impl stack::Renderer for Null {
+    fn draw<Message>(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _content: &[Element<'_, Message, Self>],
+        _layout: Layout<'_>,
+        _cursor_positions: &[Point],
+    ) {
+    }
+}
+
+impl wrap::Renderer for Null {
+    fn draw<Message>(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _content: &[Element<'_, Message, Self>],
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+    ) {
+    }
+}
+
 impl text::Renderer for Null {
     type Font = Font;
 
@@ -84,13 +119,13 @@ impl text::Renderer for Null {
 impl scrollable::Renderer for Null {
     type Style = ();
 
-    fn scrollbar(
+    fn scrollbars(
         &self,
         _bounds: Rectangle,
         _content_bounds: Rectangle,
-        _offset: u32,
-    ) -> Option<scrollable::Scrollbar> {
-        None
+        _offset: Vector<u32>,
+    ) -> scrollable::Scrollbars {
+        scrollable::Scrollbars::default()
     }
 
     fn draw(
@@ -99,9 +134,9 @@ impl scrollable::Renderer for Null {
         _bounds: Rectangle,
         _content_bounds: Rectangle,
         _is_mouse_over: bool,
-        _is_mouse_over_scrollbar: bool,
-        _scrollbar: Option<scrollable::Scrollbar>,
-        _offset: u32,
+        _cursor_position: Point,
+        _scrollbars: scrollable::Scrollbars,
+        _offset: Vector<u32>,
         _style: &Self::Style,
         _content: Self::Output,
     ) {
@@ -136,13 +171,37 @@ impl text_input::Renderer for Null {
         _placeholder: &str,
         _value: &text_input::Value,
         _state: &text_input::State,
+        _status: Frame<text_input::Status>,
+        _style: &Self::Style,
+    ) -> Self::Output {
+    }
+}
+
+impl text_editor::Renderer for Null {
+    type Style = ();
+
+    fn measure_value(&self, _value: &str, _size: u16, _font: Font) -> f32 {
+        0.0
+    }
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _text_bounds: Rectangle,
+        _cursor_position: Point,
+        _font: Font,
+        _size: u16,
+        _placeholder: &str,
+        _value: &text_input::Value,
+        _lines: &[text_editor::Line],
+        _state: &text_editor::State,
         _style: &Self::Style,
     ) -> Self::Output {
     }
 }
 
 impl button::Renderer for Null {
-    const DEFAULT_PADDING: u16 = 0;
+    const DEFAULT_PADDING: Padding = Padding::ZERO;
 
     type Style = ();
 
@@ -151,8 +210,7 @@ impl button::Renderer for Null {
         _defaults: &Self::Defaults,
         _bounds: Rectangle,
         _cursor_position: Point,
-        _is_disabled: bool,
-        _is_pressed: bool,
+        _status: Frame<button::Status>,
         _style: &Self::Style,
         _content: &Element<'_, Message, Self>,
         _content_layout: Layout<'_>,
@@ -171,6 +229,7 @@ impl radio::Renderer for Null {
         _bounds: Rectangle,
         _is_selected: bool,
         _is_mouse_over: bool,
+        _status: Frame<radio::Status>,
         _label: Self::Output,
         _style: &Self::Style,
     ) {
@@ -188,6 +247,7 @@ impl checkbox::Renderer for Null {
         _bounds: Rectangle,
         _is_checked: bool,
         _is_mouse_over: bool,
+        _status: Frame<checkbox::Status>,
         _label: Self::Output,
         _style: &Self::Style,
     ) {
@@ -205,7 +265,7 @@ impl slider::Renderer for Null {
         _cursor_position: Point,
         _range: std::ops::RangeInclusive<f32>,
         _value: f32,
-        _is_dragging: bool,
+        _status: Frame<slider::Status>,
         _style_sheet: &Self::Style,
     ) {
     }
@@ -281,3 +341,145 @@ impl pane_grid::Renderer for Null {
     ) {
     }
 }
+
+impl context_menu::Renderer for Null {
+    const DEFAULT_PADDING: Padding = Padding::ZERO;
+
+    type Style = ();
+
+    fn draw<Message>(
+        &mut self,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _menus: &[(&[context_menu::Item<Message>], Option<usize>)],
+        _padding: Padding,
+        _text_size: u16,
+        _font: Font,
+        _style: &Self::Style,
+    ) {
+    }
+}
+
+impl menu_bar::Renderer for Null {
+    const DEFAULT_PADDING: Padding = Padding::ZERO;
+
+    type Style = ();
+
+    fn menu_style(_style: &()) {}
+
+    fn draw<Message>(
+        &mut self,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _menus: &[menu_bar::Menu<Message>],
+        _open_menu: Option<usize>,
+        _show_mnemonics: bool,
+        _padding: Padding,
+        _text_size: u16,
+        _font: Font,
+        _style: &(),
+    ) {
+    }
+}
+
+impl table::Renderer for Null {
+    const DEFAULT_PADDING: Padding = Padding::ZERO;
+    const DEFAULT_ROW_HEIGHT: u16 = 20;
+
+    type Style = ();
+
+    fn draw_rows<Message>(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _rows: &[table::VisibleRow],
+        _cells: &[Element<'_, Message, Self>],
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _style: &(),
+    ) {
+    }
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _headers: &[table::Header<'_>],
+        _body: (),
+        _is_resizing: bool,
+        _padding: Padding,
+        _text_size: u16,
+        _font: Font,
+        _style: &(),
+    ) {
+    }
+}
+
+impl tabs::Renderer for Null {
+    const DEFAULT_PADDING: Padding = Padding::ZERO;
+
+    type Style = ();
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _bar_bounds: Rectangle,
+        _headers: &[tabs::Header<'_>],
+        _content: (),
+        _padding: Padding,
+        _text_size: u16,
+        _font: Font,
+        _icon_font: Font,
+        _style: &(),
+    ) {
+    }
+}
+
+impl tree_view::Renderer for Null {
+    const DEFAULT_INDENT: u16 = 20;
+
+    type Style = ();
+
+    fn draw<Message>(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _rows: &[tree_view::Row],
+        _contents: &[Element<'_, Message, Self>],
+        _indent: u16,
+        _is_focused: bool,
+        _style: &(),
+    ) {
+    }
+}
+
+impl modal::Renderer for Null {
+    type Style = ();
+
+    fn draw<Message>(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _style: &Self::Style,
+        _content: &Element<'_, Message, Self>,
+        _content_layout: Layout<'_>,
+    ) -> Self::Output {
+    }
+}
+
+impl tooltip::Renderer for Null {
+    const DEFAULT_PADDING: Padding = Padding::ZERO;
+
+    type Style = ();
+
+    fn draw<Message>(
+        &mut self,
+        _defaults: &Self::Defaults,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _style: &Self::Style,
+        _content: &Element<'_, Message, Self>,
+        _content_layout: Layout<'_>,
+    ) -> Self::Output {
+    }
+}
@@ -1,6 +1,10 @@
-use crate::{layout, overlay, Clipboard, Element, Event, Layout, Point, Size};
+use crate::{
+    focus, keyboard, layout, overlay, pane_grid, scrollable, Clipboard,
+    Element, Event, Id, Layout, Point, Rectangle, Size,
+};
 
 use std::hash::Hasher;
+use std::time::Instant;
 
 /// A set of interactive graphical elements with a specific [`Layout`].
 ///
@@ -21,8 +25,9 @@ use std::hash::Hasher;
 pub struct UserInterface<'a, Message, Renderer> {
     root: Element<'a, Message, Renderer>,
     base: Layer,
-    overlay: Option<Layer>,
+    overlays: Vec<Layer>,
     bounds: Size,
+    focused: Option<Focused>,
 }
 
 impl<'a, Message, Renderer> UserInterface<'a, Message, Renderer>
@@ -94,7 +99,7 @@ where
     ) -> Self {
         let root = root.into();
 
-        let (base, overlay) = {
+        let (base, overlays) = {
             let hash = {
                 let hasher = &mut crate::Hasher::default();
                 root.hash_layout(hasher);
@@ -105,27 +110,31 @@ where
             let layout_is_cached =
                 hash == cache.base.hash && bounds == cache.bounds;
 
-            let (layout, overlay) = if layout_is_cached {
-                (cache.base.layout, cache.overlay)
+            let (layout, overlays) = if layout_is_cached {
+                (cache.base.layout, cache.overlays)
             } else {
                 (
                     renderer.layout(
                         &root,
                         &layout::Limits::new(Size::ZERO, bounds),
                     ),
-                    None,
+                    Vec::new(),
                 )
             };
 
-            (Layer { layout, hash }, overlay)
+            (Layer { layout, hash }, overlays)
         };
 
-        UserInterface {
+        let mut user_interface = UserInterface {
             root,
             base,
-            overlay,
+            overlays,
             bounds,
-        }
+            focused: cache.focused,
+        };
+
+        user_interface.restore_focus();
+        user_interface
     }
 
     /// Updates the [`UserInterface`] by processing each provided [`Event`].
@@ -133,8 +142,18 @@ where
     /// It returns __messages__ that may have been produced as a result of user
     /// interactions. You should feed these to your __update logic__.
     ///
+    /// Pressing `Tab` or `Shift+Tab` moves the keyboard focus to the next or
+    /// previous [`Focusable`] widget, respectively, unless the focused widget
+    /// uses the key itself, like a [`TextEditor`] inserting a tab.
+    ///
+    /// Key presses that no widget consumes are then processed as keyboard
+    /// shortcuts by the widgets, like the accelerators of a [`MenuBar`].
+    ///
     /// [`UserInterface`]: struct.UserInterface.html
     /// [`Event`]: enum.Event.html
+    /// [`Focusable`]: focus/trait.Focusable.html
+    /// [`MenuBar`]: widget/menu_bar/struct.MenuBar.html
+    /// [`TextEditor`]: widget/text_editor/struct.TextEditor.html
     ///
     /// # Example
     /// Let's allow our [counter](index.html#usage) to change state by
@@ -203,58 +222,84 @@ where
     ) -> (Vec<Message>, crate::EventInteraction) {
         let mut messages = Vec::new();
 
-        let base_cursor = if let Some(mut overlay) =
-            self.root.overlay(Layout::new(&self.base.layout))
-        {
-            let layer = Self::overlay_layer(
-                self.overlay.take(),
-                self.bounds,
-                &mut overlay,
-                renderer,
-            );
+        let mut cache = std::mem::take(&mut self.overlays).into_iter();
+        let mut overlays = Vec::new();
 
-            for event in events {
-                let _ = overlay.on_event(
-                    event.clone(),
-                    Layout::new(&layer.layout),
-                    cursor_position,
+        let (base_cursor, overlay_interactions) =
+            match self.root.overlay(Layout::new(&self.base.layout)) {
+                Some(mut overlay) => Self::update_overlay(
+                    &mut overlay,
+                    &mut cache,
+                    &mut overlays,
+                    self.bounds,
+                    events,
+                    Some(cursor_position),
                     &mut messages,
                     renderer,
                     clipboard,
-                );
-            }
-
-            let base_cursor = if layer.layout.bounds().contains(cursor_position)
-            {
-                // TODO: Type-safe cursor availability
-                Point::new(-1.0, -1.0)
-            } else {
-                cursor_position
+                ),
+                None => (
+                    Some(cursor_position),
+                    events
+                        .iter()
+                        .map(|_| crate::EventInteraction::default())
+                        .collect(),
+                ),
             };
 
-            self.overlay = Some(layer);
-
-            base_cursor
-        } else {
-            cursor_position
-        };
+        self.overlays = overlays;
 
         let mut interaction = crate::EventInteraction::default();
-        for event in events {
-            interaction = self
-                .root
-                .widget
-                .on_event(
-                    event.clone(),
-                    Layout::new(&self.base.layout),
-                    base_cursor,
-                    &mut messages,
-                    renderer,
-                    clipboard,
-                )
+
+        for (event, overlay_interaction) in
+            events.iter().zip(overlay_interactions)
+        {
+            let mut event_interaction = self.root.widget.on_event(
+                event.clone(),
+                Layout::new(&self.base.layout),
+                base_cursor.unwrap_or(overlay::UNAVAILABLE_CURSOR),
+                &mut messages,
+                renderer,
+                clipboard,
+            );
+
+            // The focus only moves if no widget uses the key
+            if !(event_interaction.consumed || overlay_interaction.consumed)
+                && self.traverse_focus(event)
+            {
+                event_interaction.consumed = true;
+            }
+
+            // Shortcuts only get the keys that no widget uses
+            if let Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) = event
+            {
+                if !event_interaction.consumed {
+                    event_interaction = self.root.widget.on_shortcut(
+                        *key_code,
+                        *modifiers,
+                        &mut messages,
+                    );
+                }
+            }
+
+            interaction = event_interaction
+                .union(&overlay_interaction)
                 .union(&interaction);
         }
 
+        self.focused = {
+            let mut focusables = Vec::new();
+            self.root.focusables(&mut focusables);
+
+            focusables
+                .iter()
+                .position(|focusable| focusable.is_focused())
+                .map(|index| Focused::new(&*focusables[index], index))
+        };
+
         (messages, interaction)
     }
 
@@ -332,58 +377,145 @@ where
         renderer: &mut Renderer,
         cursor_position: Point,
     ) -> Renderer::Output {
-        let overlay = if let Some(mut overlay) =
-            self.root.overlay(Layout::new(&self.base.layout))
-        {
-            let layer = Self::overlay_layer(
-                self.overlay.take(),
-                self.bounds,
-                &mut overlay,
-                renderer,
-            );
+        let mut cache = std::mem::take(&mut self.overlays).into_iter();
+        let mut overlays = Vec::new();
 
-            let overlay_bounds = layer.layout.bounds();
+        let (base_cursor, layers) =
+            match self.root.overlay(Layout::new(&self.base.layout)) {
+                Some(mut overlay) => Self::draw_overlay(
+                    &mut overlay,
+                    &mut cache,
+                    &mut overlays,
+                    self.bounds,
+                    Some(cursor_position),
+                    renderer,
+                ),
+                None => (Some(cursor_position), Vec::new()),
+            };
 
-            let overlay_primitives = overlay.draw(
-                renderer,
-                &Renderer::Defaults::default(),
-                Layout::new(&layer.layout),
-                cursor_position,
-            );
+        self.overlays = overlays;
+
+        let base_primitives = self.root.widget.draw(
+            renderer,
+            &Renderer::Defaults::default(),
+            Layout::new(&self.base.layout),
+            base_cursor.unwrap_or(overlay::UNAVAILABLE_CURSOR),
+        );
 
-            self.overlay = Some(layer);
+        layers.into_iter().fold(
+            base_primitives,
+            |primitives, (overlay_primitives, overlay_bounds)| {
+                renderer.overlay(primitives, overlay_primitives, overlay_bounds)
+            },
+        )
+    }
 
-            Some((overlay_primitives, overlay_bounds))
-        } else {
-            None
+    /// Returns the [`Instant`] when the [`UserInterface`] needs to be redrawn,
+    /// if any.
+    ///
+    /// It is the earliest redraw request of its widgets and overlays. You
+    /// should [`draw`] the [`UserInterface`] again at that time, even if no
+    /// events happen, so its animations can progress.
+    ///
+    /// [`Instant`]: https://doc.rust-lang.org/std/time/struct.Instant.html
+    /// [`UserInterface`]: struct.UserInterface.html
+    /// [`draw`]: #method.draw
+    pub fn redraw_request(&mut self) -> Option<Instant> {
+        let overlays = match self.root.overlay(Layout::new(&self.base.layout)) {
+            Some(mut overlay) => {
+                Self::overlay_redraw_request(&mut overlay, &self.overlays)
+            }
+            None => None,
         };
 
-        if let Some((overlay_primitives, overlay_bounds)) = overlay {
-            let base_cursor = if overlay_bounds.contains(cursor_position) {
-                Point::new(-1.0, -1.0)
-            } else {
-                cursor_position
-            };
+        self.root.redraw_request().into_iter().chain(overlays).min()
+    }
 
-            let base_primitives = self.root.widget.draw(
-                renderer,
-                &Renderer::Defaults::default(),
-                Layout::new(&self.base.layout),
-                base_cursor,
-            );
+    /// Performs a focus [`Action`] in the [`UserInterface`].
+    ///
+    /// It returns the message produced by the [`Action`], if any.
+    ///
+    /// [`Action`]: focus/enum.Action.html
+    /// [`UserInterface`]: struct.UserInterface.html
+    pub fn focus(&mut self, action: focus::Action<Message>) -> Option<Message> {
+        match action {
+            focus::Action::Focus(id) => {
+                let mut focusables = Vec::new();
+                self.root.focusables(&mut focusables);
 
-            renderer.overlay(
-                base_primitives,
-                overlay_primitives,
-                overlay_bounds,
-            )
-        } else {
-            self.root.widget.draw(
-                renderer,
-                &Renderer::Defaults::default(),
-                Layout::new(&self.base.layout),
-                cursor_position,
-            )
+                if let Some(index) = focusables
+                    .iter()
+                    .position(|focusable| focusable.id() == Some(&id))
+                {
+                    for focusable in focusables.iter_mut() {
+                        if focusable.is_focused() {
+                            focusable.unfocus();
+                        }
+                    }
+
+                    focusables[index].focus();
+                    self.focused = Some(Focused::Id(id.clone()));
+                }
+
+                None
+            }
+            focus::Action::Unfocus => {
+                let mut focusables = Vec::new();
+                self.root.focusables(&mut focusables);
+
+                for focusable in focusables.iter_mut() {
+                    if focusable.is_focused() {
+                        focusable.unfocus();
+                    }
+                }
+
+                self.focused = None;
+
+                None
+            }
+            focus::Action::Focused(f) => {
+                let mut focusables = Vec::new();
+                self.root.focusables(&mut focusables);
+
+                let id = focusables
+                    .iter()
+                    .find(|focusable| focusable.is_focused())
+                    .and_then(|focusable| focusable.id().cloned());
+
+                Some(f(id))
+            }
+            focus::Action::FocusPane(id, pane) => {
+                if let Some(pane_grid) = self.pane_grid(&id) {
+                    pane_grid.focus(&pane);
+                }
+
+                None
+            }
+            focus::Action::UnfocusPane(id) => {
+                if let Some(pane_grid) = self.pane_grid(&id) {
+                    pane_grid.unfocus();
+                }
+
+                None
+            }
+        }
+    }
+
+    /// Performs a scrolling [`Action`] in the [`UserInterface`].
+    ///
+    /// [`Action`]: widget/scrollable/enum.Action.html
+    /// [`UserInterface`]: struct.UserInterface.html
+    pub fn scroll(&mut self, action: scrollable::Action) {
+        let mut targets = Vec::new();
+        self.root.targets(&mut targets);
+
+        let state = targets
+            .into_iter()
+            .filter(|(target, _)| *target == action.id())
+            .find_map(|(_, state)| state.downcast_mut::<scrollable::State>());
+
+        if let Some(state) = state {
+            action.perform(state);
         }
     }
 
@@ -395,11 +527,225 @@ where
     pub fn into_cache(self) -> Cache {
         Cache {
             base: self.base,
-            overlay: self.overlay,
+            overlays: self.overlays,
             bounds: self.bounds,
+            focused: self.focused,
+        }
+    }
+
+    fn restore_focus(&mut self) {
+        let mut focusables = Vec::new();
+        self.root.focusables(&mut focusables);
+
+        match focusables
+            .iter()
+            .position(|focusable| focusable.is_focused())
+        {
+            Some(index) => {
+                self.focused = Some(Focused::new(&*focusables[index], index));
+            }
+            None => {
+                // Widgets without local state forget they are focused
+                // between frames, so we focus them again.
+                match self
+                    .focused
+                    .as_ref()
+                    .and_then(|focused| focused.position(&focusables))
+                {
+                    Some(index) if !focusables[index].is_stateful() => {
+                        focusables[index].focus();
+                    }
+                    _ => {
+                        self.focused = None;
+                    }
+                }
+            }
         }
     }
 
+    fn pane_grid(&mut self, id: &Id) -> Option<&mut pane_grid::Internal> {
+        let mut targets = Vec::new();
+        self.root.targets(&mut targets);
+
+        targets
+            .into_iter()
+            .filter(|(target, _)| *target == id)
+            .find_map(|(_, state)| state.downcast_mut::<pane_grid::Internal>())
+    }
+
+    fn traverse_focus(&mut self, event: &Event) -> bool {
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Tab,
+                modifiers,
+            }) if !(modifiers.control || modifiers.alt || modifiers.logo) => {
+                let direction = if modifiers.shift {
+                    focus::Direction::Backward
+                } else {
+                    focus::Direction::Forward
+                };
+
+                let mut focusables = Vec::new();
+                self.root.focusables(&mut focusables);
+
+                match focus::traverse(&mut focusables, direction) {
+                    Some(index) => {
+                        self.focused =
+                            Some(Focused::new(&*focusables[index], index));
+
+                        true
+                    }
+                    None => false,
+                }
+            }
+            _ => false,
+        }
+    }
+
+    /// Processes the events with the given overlay and the overlays stacked
+    /// on top of it, starting from the topmost one.
+    ///
+    /// It returns the cursor position seen by the layers below, if the cursor
+    /// is not captured, and the resulting interaction of every event.
+    #[allow(clippy::too_many_arguments)]
+    fn update_overlay(
+        overlay: &mut overlay::Element<'_, Message, Renderer>,
+        cache: &mut impl Iterator<Item = Layer>,
+        layers: &mut Vec<Layer>,
+        bounds: Size,
+        events: &[Event],
+        cursor_position: Option<Point>,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> (Option<Point>, Vec<crate::EventInteraction>) {
+        let index = layers.len();
+
+        layers.push(Self::overlay_layer(
+            cache.next(),
+            bounds,
+            overlay,
+            renderer,
+        ));
+
+        let (cursor_position, mut interactions) =
+            match overlay.overlay(Layout::new(&layers[index].layout)) {
+                Some(mut nested) => Self::update_overlay(
+                    &mut nested,
+                    cache,
+                    layers,
+                    bounds,
+                    events,
+                    cursor_position,
+                    messages,
+                    renderer,
+                    clipboard,
+                ),
+                None => (
+                    cursor_position,
+                    events
+                        .iter()
+                        .map(|_| crate::EventInteraction::default())
+                        .collect(),
+                ),
+            };
+
+        let layout = Layout::new(&layers[index].layout);
+
+        for (event, interaction) in events.iter().zip(&mut interactions) {
+            *interaction = overlay
+                .on_event(
+                    event.clone(),
+                    layout,
+                    cursor_position.unwrap_or(overlay::UNAVAILABLE_CURSOR),
+                    messages,
+                    renderer,
+                    clipboard,
+                )
+                .union(interaction);
+        }
+
+        (
+            cursor_position.filter(|cursor_position| {
+                !overlay.is_over(layout, *cursor_position)
+            }),
+            interactions,
+        )
+    }
+
+    /// Returns the earliest redraw request of the given overlay and the
+    /// overlays stacked on top of it, laid out in the given layers.
+    fn overlay_redraw_request(
+        overlay: &mut overlay::Element<'_, Message, Renderer>,
+        layers: &[Layer],
+    ) -> Option<Instant> {
+        let redraw_request = overlay.redraw_request();
+
+        let nested = match layers.split_first() {
+            Some((layer, layers)) => overlay
+                .overlay(Layout::new(&layer.layout))
+                .and_then(|mut nested| {
+                    Self::overlay_redraw_request(&mut nested, layers)
+                }),
+            None => None,
+        };
+
+        redraw_request.into_iter().chain(nested).min()
+    }
+
+    /// Draws the given overlay and the overlays stacked on top of it.
+    ///
+    /// It returns the cursor position seen by the layers below, if the cursor
+    /// is not captured, and the primitives of every overlay in z-order.
+    fn draw_overlay(
+        overlay: &mut overlay::Element<'_, Message, Renderer>,
+        cache: &mut impl Iterator<Item = Layer>,
+        layers: &mut Vec<Layer>,
+        bounds: Size,
+        cursor_position: Option<Point>,
+        renderer: &mut Renderer,
+    ) -> (Option<Point>, Vec<(Renderer::Output, Rectangle)>) {
+        let index = layers.len();
+
+        layers.push(Self::overlay_layer(
+            cache.next(),
+            bounds,
+            overlay,
+            renderer,
+        ));
+
+        let (cursor_position, mut above) =
+            match overlay.overlay(Layout::new(&layers[index].layout)) {
+                Some(mut nested) => Self::draw_overlay(
+                    &mut nested,
+                    cache,
+                    layers,
+                    bounds,
+                    cursor_position,
+                    renderer,
+                ),
+                None => (cursor_position, Vec::new()),
+            };
+
+        let layout = Layout::new(&layers[index].layout);
+
+        let primitives = overlay.draw(
+            renderer,
+            &Renderer::Defaults::default(),
+            layout,
+            cursor_position.unwrap_or(overlay::UNAVAILABLE_CURSOR),
+        );
+
+        above.insert(0, (primitives, layout.bounds()));
+
+        (
+            cursor_position.filter(|cursor_position| {
+                !overlay.is_over(layout, *cursor_position)
+            }),
+            above,
+        )
+    }
+
     fn overlay_layer(
         cache: Option<Layer>,
         bounds: Size,
@@ -431,14 +777,57 @@ struct Layer {
     hash: u64,
 }
 
+/// The focused widget of a [`UserInterface`], remembered between frames.
+///
+/// [`UserInterface`]: struct.UserInterface.html
+#[derive(Debug, Clone, PartialEq)]
+enum Focused {
+    /// A widget with an [`Id`], found again wherever it moves in the tree.
+    ///
+    /// [`Id`]: widget/struct.Id.html
+    Id(Id),
+
+    /// A widget without an [`Id`], found again by its position among the
+    /// focusable widgets.
+    ///
+    /// [`Id`]: widget/struct.Id.html
+    Position(usize),
+}
+
+impl Focused {
+    fn new(focusable: &dyn focus::Focusable, index: usize) -> Self {
+        match focusable.id() {
+            Some(id) => Focused::Id(id.clone()),
+            None => Focused::Position(index),
+        }
+    }
+
+    /// Returns the index of the focused widget in the given focusables, if
+    /// it is still there.
+    fn position(
+        &self,
+        focusables: &[&mut dyn focus::Focusable],
+    ) -> Option<usize> {
+        match self {
+            Focused::Id(id) => focusables
+                .iter()
+                .position(|focusable| focusable.id() == Some(id)),
+            Focused::Position(index) => {
+                Some(*index).filter(|index| *index < focusables.len())
+            }
+        }
+    }
+}
+
 /// Reusable data of a specific [`UserInterface`].
 ///
 /// [`UserInterface`]: struct.UserInterface.html
 #[derive(Debug, Clone)]
 pub struct Cache {
     base: Layer,
-    overlay: Option<Layer>,
+    overlays: Vec<Layer>,
     bounds: Size,
+    focused: Option<Focused>,
 }
 
 impl Cache {
@@ -455,8 +844,9 @@ impl Cache {
                 layout: layout::Node::new(Size::new(0.0, 0.0)),
                 hash: 0,
             },
-            overlay: None,
+            overlays: Vec::new(),
             bounds: Size::ZERO,
+            focused: None,
         }
     }
 }
@@ -466,3 +856,384 @@ impl Default for Cache {
         Cache::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context_menu::{Accelerator, Item};
+    use crate::keyboard::{KeyCode, ModifiersState};
+    use crate::{
+        menu_bar, mouse, renderer::Null, text_editor, text_input, Checkbox,
+        Column, EventInteraction, Hasher, Length, MenuBar, Modal, Text,
+        TextEditor, TextInput, Widget,
+    };
+
+    use std::cell::RefCell;
+
+    type Log = RefCell<Vec<(&'static str, Point)>>;
+
+    /// A widget producing an overlay with the given bounds, which logs the
+    /// cursor position seen by both the widget and its overlay.
+    struct Anchor<'a> {
+        name: &'static str,
+        bounds: Rectangle,
+        log: &'a Log,
+    }
+
+    impl<'a> Widget<(), Null> for Anchor<'a> {
+        fn width(&self) -> Length {
+            Length::Units(10)
+        }
+
+        fn height(&self) -> Length {
+            Length::Units(10)
+        }
+
+        fn layout(
+            &self,
+            _renderer: &Null,
+            _limits: &layout::Limits,
+        ) -> layout::Node {
+            layout::Node::new(Size::new(10.0, 10.0))
+        }
+
+        fn draw(
+            &self,
+            _renderer: &mut Null,
+            _defaults: &(),
+            _layout: Layout<'_>,
+            _cursor_position: Point,
+        ) {
+        }
+
+        fn hash_layout(&self, _state: &mut Hasher) {}
+
+        fn on_event(
+            &mut self,
+            _event: Event,
+            _layout: Layout<'_>,
+            cursor_position: Point,
+            _messages: &mut Vec<()>,
+            _renderer: &Null,
+            _clipboard: Option<&dyn Clipboard>,
+        ) -> EventInteraction {
+            self.log.borrow_mut().push(("base", cursor_position));
+
+            EventInteraction::default()
+        }
+
+        fn overlay(
+            &mut self,
+            _layout: Layout<'_>,
+        ) -> Option<overlay::Element<'_, (), Null>> {
+            Some(overlay::Element::new(
+                self.bounds.position(),
+                Box::new(Popup {
+                    name: self.name,
+                    size: self.bounds.size(),
+                    log: self.log,
+                }),
+            ))
+        }
+    }
+
+    struct Popup<'a> {
+        name: &'static str,
+        size: Size,
+        log: &'a Log,
+    }
+
+    impl<'a> overlay::Overlay<(), Null> for Popup<'a> {
+        fn layout(
+            &self,
+            _renderer: &Null,
+            _bounds: Size,
+            position: Point,
+        ) -> layout::Node {
+            let mut node = layout::Node::new(self.size);
+            node.move_to(position);
+
+            node
+        }
+
+        fn draw(
+            &self,
+            _renderer: &mut Null,
+            _defaults: &(),
+            _layout: Layout<'_>,
+            _cursor_position: Point,
+        ) {
+        }
+
+        fn hash_layout(&self, _state: &mut Hasher, _position: Point) {}
+
+        fn on_event(
+            &mut self,
+            _event: Event,
+            _layout: Layout<'_>,
+            cursor_position: Point,
+            _messages: &mut Vec<()>,
+            _renderer: &Null,
+            _clipboard: Option<&dyn Clipboard>,
+        ) -> EventInteraction {
+            self.log.borrow_mut().push((self.name, cursor_position));
+
+            EventInteraction::default()
+        }
+    }
+
+    fn update(log: &Log, cursor_position: Point) -> Vec<(&'static str, Point)> {
+        let mut renderer = Null;
+
+        let column: Column<'_, (), Null> = Column::new()
+            .push(Element::new(Anchor {
+                name: "bottom",
+                bounds: Rectangle {
+                    x: 0.0,
+                    y: 0.0,
+                    width: 100.0,
+                    height: 100.0,
+                },
+                log,
+            }))
+            .push(Element::new(Anchor {
+                name: "top",
+                bounds: Rectangle {
+                    x: 50.0,
+                    y: 50.0,
+                    width: 100.0,
+                    height: 100.0,
+                },
+                log,
+            }));
+
+        let mut user_interface = UserInterface::build(
+            column,
+            Size::new(500.0, 500.0),
+            Cache::new(),
+            &mut renderer,
+        );
+
+        let _ = user_interface.update(
+            &[Event::Mouse(mouse::Event::CursorMoved {
+                x: cursor_position.x,
+                y: cursor_position.y,
+            })],
+            cursor_position,
+            None,
+            &renderer,
+        );
+
+        log.replace(Vec::new())
+    }
+
+    #[test]
+    fn sibling_overlays_are_stacked() {
+        let log = Log::default();
+
+        // Both overlays are shown, the last one on top of the first one
+        let cursor_position = Point::new(75.0, 75.0);
+
+        assert_eq!(
+            update(&log, cursor_position),
+            vec![
+                ("top", cursor_position),
+                ("bottom", overlay::UNAVAILABLE_CURSOR),
+                ("base", overlay::UNAVAILABLE_CURSOR),
+                ("base", overlay::UNAVAILABLE_CURSOR),
+            ]
+        );
+
+        // The bottom overlay sees the cursor outside of the top one
+        let cursor_position = Point::new(25.0, 25.0);
+
+        assert_eq!(
+            update(&log, cursor_position),
+            vec![
+                ("top", cursor_position),
+                ("bottom", cursor_position),
+                ("base", overlay::UNAVAILABLE_CURSOR),
+                ("base", overlay::UNAVAILABLE_CURSOR),
+            ]
+        );
+
+        // The widgets see the cursor outside of every overlay
+        let cursor_position = Point::new(200.0, 200.0);
+
+        assert_eq!(
+            update(&log, cursor_position),
+            vec![
+                ("top", cursor_position),
+                ("bottom", cursor_position),
+                ("base", cursor_position),
+                ("base", cursor_position),
+            ]
+        );
+    }
+
+    #[test]
+    fn focused_widgets_use_keys_before_shortcuts() {
+        let mut renderer = Null;
+        let mut menu_bar = menu_bar::State::new();
+        let mut text_input = text_input::State::focused();
+
+        let ctrl = ModifiersState {
+            control: true,
+            ..ModifiersState::default()
+        };
+
+        let mut press = |key_code| {
+            let column: Column<'_, &'static str, Null> = Column::new()
+                .push(MenuBar::new(
+                    &mut menu_bar,
+                    vec![menu_bar::Menu::new(
+                        "&Edit",
+                        vec![
+                            Item::new("Select &all", "select_all").accelerator(
+                                Accelerator::new(ctrl, KeyCode::A),
+                            ),
+                            Item::new("&Save", "save").accelerator(
+                                Accelerator::new(ctrl, KeyCode::S),
+                            ),
+                        ],
+                    )],
+                ))
+                .push(TextInput::new(&mut text_input, "", "Text", |_| "edit"));
+
+            let mut user_interface = UserInterface::build(
+                column,
+                Size::new(500.0, 500.0),
+                Cache::new(),
+                &mut renderer,
+            );
+
+            let (messages, _) = user_interface.update(
+                &[Event::Keyboard(keyboard::Event::KeyPressed {
+                    key_code,
+                    modifiers: ctrl,
+                })],
+                Point::ORIGIN,
+                None,
+                &renderer,
+            );
+
+            messages
+        };
+
+        assert!(press(KeyCode::A).is_empty());
+        assert_eq!(press(KeyCode::S), vec!["save"]);
+    }
+
+    #[test]
+    fn overlays_request_redraws() {
+        let mut renderer = Null;
+        let mut state = text_input::State::focused();
+
+        // The focused input of the dialog needs to blink its cursor
+        let modal: Modal<'_, (), Null> = Modal::new(
+            true,
+            Text::new("Content"),
+            TextInput::new(&mut state, "", "", |_| ()),
+        );
+
+        let mut user_interface = UserInterface::build(
+            modal,
+            Size::new(500.0, 500.0),
+            Cache::new(),
+            &mut renderer,
+        );
+
+        assert!(user_interface.redraw_request().is_some());
+    }
+
+    #[test]
+    fn focused_widgets_use_tab_before_traversal() {
+        fn press(
+            text_editor: &mut text_editor::State,
+            text_input: &mut text_input::State,
+            modifiers: ModifiersState,
+        ) -> Vec<String> {
+            let mut renderer = Null;
+
+            let column: Column<'_, String, Null> = Column::new()
+                .push(TextEditor::new(text_editor, "", "", |value| value))
+                .push(TextInput::new(text_input, "", "", |value| value));
+
+            let mut user_interface = UserInterface::build(
+                column,
+                Size::new(500.0, 500.0),
+                Cache::new(),
+                &mut renderer,
+            );
+
+            let (messages, _) = user_interface.update(
+                &[Event::Keyboard(keyboard::Event::KeyPressed {
+                    key_code: KeyCode::Tab,
+                    modifiers,
+                })],
+                Point::ORIGIN,
+                None,
+                &renderer,
+            );
+
+            messages
+        }
+
+        let mut text_editor = text_editor::State::focused();
+        let mut text_input = text_input::State::new();
+
+        assert_eq!(
+            press(&mut text_editor, &mut text_input, ModifiersState::default()),
+            vec![String::from("\t")]
+        );
+        assert!(text_editor.is_focused());
+
+        let shift = ModifiersState {
+            shift: true,
+            ..ModifiersState::default()
+        };
+
+        // The focus wraps around to the last widget
+        assert!(press(&mut text_editor, &mut text_input, shift).is_empty());
+        assert!(!text_editor.is_focused());
+        assert!(text_input.is_focused());
+    }
+
+    #[test]
+    fn focus_follows_ids_when_widgets_change() {
+        fn view(
+            checkboxes: &[&'static str],
+        ) -> Column<'static, Option<Id>, Null> {
+            checkboxes.iter().fold(Column::new(), |column, name| {
+                column.push(
+                    Checkbox::new(false, *name, |_| None).id(Id::new(*name)),
+                )
+            })
+        }
+
+        let mut renderer = Null;
+        let bounds = Size::new(500.0, 500.0);
+
+        let mut user_interface = UserInterface::build(
+            view(&["b"]),
+            bounds,
+            Cache::new(),
+            &mut renderer,
+        );
+        let _ = user_interface.focus(focus::Action::Focus(Id::new("b")));
+
+        // A new checkbox appears before the focused one
+        let cache = user_interface.into_cache();
+        let mut user_interface = UserInterface::build(
+            view(&["a", "b"]),
+            bounds,
+            cache,
+            &mut renderer,
+        );
+
+        assert_eq!(
+            user_interface.focus(focus::Action::Focused(Box::new(|id| id))),
+            Some(Some(Id::new("b")))
+        );
+    }
+}
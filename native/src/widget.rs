@@ -24,7 +24,11 @@ pub mod button;
 pub mod checkbox;
 pub mod column;
 pub mod container;
+pub mod context_menu;
+pub mod grid;
 pub mod image;
+pub mod menu_bar;
+pub mod modal;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
@@ -33,9 +37,19 @@ pub mod row;
 pub mod scrollable;
 pub mod slider;
 pub mod space;
+pub mod stack;
 pub mod svg;
+pub mod table;
+pub mod tabs;
 pub mod text;
+pub mod text_editor;
 pub mod text_input;
+pub mod tooltip;
+pub mod tree_view;
+pub mod virtual_list;
+pub mod wrap;
+
+mod id;
 
 #[doc(no_inline)]
 pub use button::Button;
@@ -46,8 +60,16 @@ pub use column::Column;
 #[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use context_menu::ContextMenu;
+#[doc(no_inline)]
+pub use grid::Grid;
+#[doc(no_inline)]
 pub use image::Image;
 #[doc(no_inline)]
+pub use menu_bar::MenuBar;
+#[doc(no_inline)]
+pub use modal::Modal;
+#[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
 pub use pick_list::PickList;
@@ -64,13 +86,37 @@ pub use slider::Slider;
 #[doc(no_inline)]
 pub use space::Space;
 #[doc(no_inline)]
+pub use stack::Stack;
+#[doc(no_inline)]
 pub use svg::Svg;
 #[doc(no_inline)]
+pub use table::Table;
+#[doc(no_inline)]
+pub use tabs::Tabs;
+#[doc(no_inline)]
 pub use text::Text;
 #[doc(no_inline)]
+pub use text_editor::TextEditor;
+#[doc(no_inline)]
 pub use text_input::TextInput;
+#[doc(no_inline)]
+pub use tooltip::Tooltip;
+#[doc(no_inline)]
+pub use tree_view::TreeView;
+#[doc(no_inline)]
+pub use virtual_list::VirtualList;
+#[doc(no_inline)]
+pub use wrap::Wrap;
+
+pub use id::Id;
+
+use crate::{
+    focus, keyboard, layout, overlay, Clipboard, Event, Hasher, Layout, Length,
+    Point,
+};
 
-use crate::{layout, overlay, Clipboard, Event, Hasher, Layout, Length, Point};
+use std::any::Any;
+use std::time::Instant;
 
 /// A component that displays information and allows interaction.
 ///
@@ -189,6 +235,74 @@ where
     ) -> Option<overlay::Element<'_, Message, Renderer>> {
         None
     }
+
+    /// Processes a key press that no widget consumed, like a keyboard
+    /// shortcut.
+    ///
+    /// The [`UserInterface`] calls it after the key press has gone through
+    /// [`on_event`], so the focused widget gets to use the key first. Widgets
+    /// with children should forward the call to them.
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`UserInterface`]: ../struct.UserInterface.html
+    /// [`on_event`]: #method.on_event
+    fn on_shortcut(
+        &mut self,
+        _key_code: keyboard::KeyCode,
+        _modifiers: keyboard::ModifiersState,
+        _messages: &mut Vec<Message>,
+    ) -> EventInteraction {
+        EventInteraction { consumed: false }
+    }
+
+    /// Collects the [`Focusable`] widgets of the [`Widget`] in traversal order.
+    ///
+    /// Widgets that can be focused should push themselves, while widgets with
+    /// children should forward the call to them.
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`Focusable`]: ../focus/trait.Focusable.html
+    /// [`Widget`]: trait.Widget.html
+    fn focusables<'b>(
+        &'b mut self,
+        _focusables: &mut Vec<&'b mut dyn focus::Focusable>,
+    ) {
+    }
+
+    /// Collects the local state of the widgets of the [`Widget`] that have an
+    /// [`Id`], so a [`Command`] can target them.
+    ///
+    /// Widgets with an [`Id`] should push themselves, while widgets with
+    /// children should forward the call to them.
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`Widget`]: trait.Widget.html
+    /// [`Id`]: struct.Id.html
+    /// [`Command`]: ../struct.Command.html
+    fn targets<'b>(
+        &'b mut self,
+        _targets: &mut Vec<(&'b Id, &'b mut dyn Any)>,
+    ) {
+    }
+
+    /// Returns the [`Instant`] when the [`Widget`] needs to be redrawn, if
+    /// any.
+    ///
+    /// Animated widgets should return when their next frame is due, while
+    /// widgets with children should return the earliest request of them.
+    /// The runtime will redraw the user interface at that time, even if no
+    /// events happen.
+    ///
+    /// By default, it returns `None`.
+    ///
+    /// [`Instant`]: https://doc.rust-lang.org/std/time/struct.Instant.html
+    /// [`Widget`]: trait.Widget.html
+    fn redraw_request(&self) -> Option<Instant> {
+        None
+    }
 }
 
 /// Metainfo associated with the [`on_event`] method.
@@ -204,7 +318,7 @@ impl EventInteraction {
     /// Produces an event interaction with the unified positive fields of
     /// `self` and `other`
     pub fn union(mut self, other: &Self) -> Self {
-        self.consumed &= other.consumed;
+        self.consumed |= other.consumed;
         self
     }
 }
@@ -4,11 +4,14 @@
 //!
 //! [`Button`]: struct.Button.html
 //! [`State`]: struct.State.html
+use crate::animation::{Animated, Frame, Transition};
 use crate::{
-    layout, mouse, Clipboard, Element, Event, EventInteraction, Hasher, Layout,
-    Length, Point, Rectangle, Widget,
+    focus, keyboard, layout, mouse, Clipboard, Element, Event,
+    EventInteraction, Hasher, Id, Layout, Length, Padding, Point, Rectangle,
+    Widget,
 };
 use std::hash::Hash;
+use std::time::Instant;
 
 /// A generic widget that produces a message when pressed.
 ///
@@ -28,6 +31,7 @@ use std::hash::Hash;
 /// ```
 #[allow(missing_debug_implementations)]
 pub struct Button<'a, Message, Renderer: self::Renderer> {
+    id: Option<Id>,
     state: &'a mut State,
     content: Element<'a, Message, Renderer>,
     on_press: Option<Message>,
@@ -35,7 +39,8 @@ pub struct Button<'a, Message, Renderer: self::Renderer> {
     height: Length,
     min_width: u32,
     min_height: u32,
-    padding: u16,
+    padding: Padding,
+    transition: Transition,
     style: Renderer::Style,
 }
 
@@ -53,6 +58,7 @@ where
         E: Into<Element<'a, Message, Renderer>>,
     {
         Button {
+            id: None,
             state,
             content: content.into(),
             on_press: None,
@@ -61,10 +67,25 @@ where
             min_width: 0,
             min_height: 0,
             padding: Renderer::DEFAULT_PADDING,
+            transition: Transition::default(),
             style: Renderer::Style::default(),
         }
     }
 
+    /// Sets the [`Id`] of the [`Button`].
+    ///
+    /// It allows you to focus the [`Button`] with a [`Command`]. See
+    /// [`focus::focus`].
+    ///
+    /// [`Id`]: ../struct.Id.html
+    /// [`Button`]: struct.Button.html
+    /// [`Command`]: ../../struct.Command.html
+    /// [`focus::focus`]: ../../focus/fn.focus.html
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Sets the width of the [`Button`].
     ///
     /// [`Button`]: struct.Button.html
@@ -100,8 +121,8 @@ where
     /// Sets the padding of the [`Button`].
     ///
     /// [`Button`]: struct.Button.html
-    pub fn padding(mut self, padding: u16) -> Self {
-        self.padding = padding;
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
         self
     }
 
@@ -120,6 +141,35 @@ where
         self.style = style.into();
         self
     }
+
+    /// Sets the [`Transition`] used to blend the styles of the [`Button`]
+    /// when its [`Status`] changes.
+    ///
+    /// By default, styles change instantly.
+    ///
+    /// [`Transition`]: ../../animation/struct.Transition.html
+    /// [`Button`]: struct.Button.html
+    /// [`Status`]: enum.Status.html
+    pub fn transition(mut self, transition: Transition) -> Self {
+        self.transition = transition;
+        self
+    }
+
+    fn status(&self, bounds: Rectangle, cursor_position: Point) -> Status {
+        if self.on_press.is_none() {
+            Status::Disabled
+        } else if bounds.contains(cursor_position) {
+            if self.state.is_pressed {
+                Status::Pressed
+            } else {
+                Status::Hovered
+            }
+        } else if self.state.is_focused {
+            Status::Focused
+        } else {
+            Status::Active
+        }
+    }
 }
 
 /// The local state of a [`Button`].
@@ -128,6 +178,8 @@ where
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct State {
     is_pressed: bool,
+    is_focused: bool,
+    status: Animated<Status>,
 }
 
 impl State {
@@ -137,6 +189,45 @@ impl State {
     pub fn new() -> State {
         State::default()
     }
+
+    /// Returns whether the [`Button`] is currently focused or not.
+    ///
+    /// [`Button`]: struct.Button.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+}
+
+/// The possible statuses of a [`Button`].
+///
+/// [`Button`]: struct.Button.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Status {
+    /// The [`Button`] can be pressed.
+    ///
+    /// [`Button`]: struct.Button.html
+    #[default]
+    Active,
+
+    /// The [`Button`] can be pressed and it is being hovered.
+    ///
+    /// [`Button`]: struct.Button.html
+    Hovered,
+
+    /// The [`Button`] is being pressed.
+    ///
+    /// [`Button`]: struct.Button.html
+    Pressed,
+
+    /// The [`Button`] is focused and it is not being hovered.
+    ///
+    /// [`Button`]: struct.Button.html
+    Focused,
+
+    /// The [`Button`] cannot be pressed.
+    ///
+    /// [`Button`]: struct.Button.html
+    Disabled,
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -158,7 +249,7 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        let padding = f32::from(self.padding);
+        let padding = self.padding;
         let limits = limits
             .min_width(self.min_width)
             .min_height(self.min_height)
@@ -167,7 +258,10 @@ where
             .pad(padding);
 
         let mut content = self.content.layout(renderer, &limits);
-        content.move_to(Point::new(padding, padding));
+        content.move_to(Point::new(
+            f32::from(padding.left),
+            f32::from(padding.top),
+        ));
 
         let size = limits.resolve(content.size()).pad(padding);
 
@@ -183,12 +277,13 @@ where
         _renderer: &Renderer,
         _clipboard: Option<&dyn Clipboard>,
     ) -> EventInteraction {
-        match event {
+        let interaction = match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
                 let consumed = layout.bounds().contains(cursor_position);
                 if self.on_press.is_some() {
                     self.state.is_pressed = consumed;
                 }
+                self.state.is_focused = false;
                 EventInteraction { consumed }
             }
             Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
@@ -208,8 +303,31 @@ where
 
                 EventInteraction { consumed }
             }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Space,
+                ..
+            })
+            | Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Enter,
+                ..
+            }) if self.state.is_focused => {
+                let mut consumed = false;
+                if let Some(on_press) = self.on_press.clone() {
+                    messages.push(on_press);
+                    consumed = true;
+                }
+                EventInteraction { consumed }
+            }
             _ => EventInteraction { consumed: false },
-        }
+        };
+
+        let status = self.status(layout.bounds(), cursor_position);
+
+        self.state
+            .status
+            .transition(status, self.transition, Instant::now());
+
+        interaction
     }
 
     fn draw(
@@ -219,12 +337,21 @@ where
         layout: Layout<'_>,
         cursor_position: Point,
     ) -> Renderer::Output {
+        let bounds = layout.bounds();
+        let status = self.status(bounds, cursor_position);
+
+        // The status may have changed without any events, like when focusing
+        let status = if *self.state.status.target() == status {
+            self.state.status.at(Instant::now())
+        } else {
+            Frame::settled(status)
+        };
+
         renderer.draw(
             defaults,
-            layout.bounds(),
+            bounds,
             cursor_position,
-            self.on_press.is_none(),
-            self.state.is_pressed,
+            status,
             &self.style,
             &self.content,
             layout.children().next().unwrap(),
@@ -238,6 +365,46 @@ where
         self.width.hash(state);
         self.content.hash_layout(state);
     }
+
+    fn focusables<'b>(
+        &'b mut self,
+        focusables: &mut Vec<&'b mut dyn focus::Focusable>,
+    ) {
+        if self.on_press.is_some() {
+            focusables.push(self);
+        }
+    }
+
+    fn redraw_request(&self) -> Option<Instant> {
+        let status = self.state.status.redraw_request(Instant::now());
+
+        status
+            .into_iter()
+            .chain(self.content.redraw_request())
+            .min()
+    }
+}
+
+impl<'a, Message, Renderer> focus::Focusable for Button<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn is_focused(&self) -> bool {
+        self.state.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.state.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.state.is_focused = false;
+        self.state.is_pressed = false;
+    }
+
+    fn id(&self) -> Option<&Id> {
+        self.id.as_ref()
+    }
 }
 
 /// The renderer of a [`Button`].
@@ -251,21 +418,25 @@ pub trait Renderer: crate::Renderer + Sized {
     /// The default padding of a [`Button`].
     ///
     /// [`Button`]: struct.Button.html
-    const DEFAULT_PADDING: u16;
+    const DEFAULT_PADDING: Padding;
 
     /// The style supported by this renderer.
     type Style: Default;
 
     /// Draws a [`Button`].
     ///
+    /// It receives the current [`Frame`] of the [`Status`] of the [`Button`],
+    /// which should be used to blend the styles of each [`Status`].
+    ///
     /// [`Button`]: struct.Button.html
+    /// [`Frame`]: ../../animation/struct.Frame.html
+    /// [`Status`]: enum.Status.html
     fn draw<Message>(
         &mut self,
         defaults: &Self::Defaults,
         bounds: Rectangle,
         cursor_position: Point,
-        is_disabled: bool,
-        is_pressed: bool,
+        status: Frame<Status>,
         style: &Self::Style,
         content: &Element<'_, Message, Self>,
         content_layout: Layout<'_>,
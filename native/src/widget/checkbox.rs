@@ -1,18 +1,22 @@
 //! Show toggle controls using checkboxes.
 use std::hash::Hash;
 
+use crate::animation::{Animated, Frame, Transition};
 use crate::{
-    layout, mouse, row, text, Align, Clipboard, Element, Event,
-    EventInteraction, Hasher, HorizontalAlignment, Layout, Length, Point,
-    Rectangle, Row, Text, VerticalAlignment, Widget,
+    focus, keyboard, layout, mouse, row, text, Align, Clipboard, Element,
+    Event, EventInteraction, Hasher, HorizontalAlignment, Id, Layout, Length,
+    Point, Rectangle, Row, Text, VerticalAlignment, Widget,
 };
 
+use std::time::Instant;
+
 /// A box that can be checked.
 ///
 /// # Example
 ///
 /// ```
-/// # type Checkbox<Message> = iced_native::Checkbox<Message, iced_native::renderer::Null>;
+/// # type Checkbox<'a, Message> =
+/// #     iced_native::Checkbox<'a, Message, iced_native::renderer::Null>;
 /// #
 /// pub enum Message {
 ///     CheckboxToggled(bool),
@@ -25,8 +29,11 @@ use crate::{
 ///
 /// ![Checkbox drawn by `iced_wgpu`](https://github.com/hecrj/iced/blob/7760618fb112074bc40b148944521f312152012a/docs/images/checkbox.png?raw=true)
 #[allow(missing_debug_implementations)]
-pub struct Checkbox<Message, Renderer: self::Renderer + text::Renderer> {
+pub struct Checkbox<'a, Message, Renderer: self::Renderer + text::Renderer> {
+    state: Option<&'a mut State>,
+    id: Option<Id>,
     is_checked: bool,
+    is_focused: bool,
     on_toggle: Box<dyn Fn(bool) -> Message>,
     label: String,
     width: Length,
@@ -34,11 +41,12 @@ pub struct Checkbox<Message, Renderer: self::Renderer + text::Renderer> {
     spacing: u16,
     text_size: Option<u16>,
     font: Renderer::Font,
+    transition: Transition,
     style: Renderer::Style,
 }
 
-impl<Message, Renderer: self::Renderer + text::Renderer>
-    Checkbox<Message, Renderer>
+impl<'a, Message, Renderer: self::Renderer + text::Renderer>
+    Checkbox<'a, Message, Renderer>
 {
     /// Creates a new [`Checkbox`].
     ///
@@ -55,7 +63,10 @@ impl<Message, Renderer: self::Renderer + text::Renderer>
         F: 'static + Fn(bool) -> Message,
     {
         Checkbox {
+            state: None,
+            id: None,
             is_checked,
+            is_focused: false,
             on_toggle: Box::new(f),
             label: label.into(),
             width: Length::Shrink,
@@ -63,6 +74,7 @@ impl<Message, Renderer: self::Renderer + text::Renderer>
             spacing: Renderer::DEFAULT_SPACING,
             text_size: None,
             font: Renderer::Font::default(),
+            transition: Transition::default(),
             style: Renderer::Style::default(),
         }
     }
@@ -75,6 +87,20 @@ impl<Message, Renderer: self::Renderer + text::Renderer>
         self
     }
 
+    /// Sets the [`Id`] of the [`Checkbox`].
+    ///
+    /// It allows you to focus the [`Checkbox`] with a [`Command`]. See
+    /// [`focus::focus`].
+    ///
+    /// [`Id`]: ../struct.Id.html
+    /// [`Checkbox`]: struct.Checkbox.html
+    /// [`Command`]: ../../struct.Command.html
+    /// [`focus::focus`]: ../../focus/fn.focus.html
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Sets the width of the [`Checkbox`].
     ///
     /// [`Checkbox`]: struct.Checkbox.html
@@ -115,10 +141,80 @@ impl<Message, Renderer: self::Renderer + text::Renderer>
         self.style = style.into();
         self
     }
+
+    /// Sets the [`Transition`] used to blend the styles of the [`Checkbox`]
+    /// when its [`Status`] changes.
+    ///
+    /// The animation is kept in the given [`State`]. By default, styles
+    /// change instantly.
+    ///
+    /// [`Transition`]: ../../animation/struct.Transition.html
+    /// [`Checkbox`]: struct.Checkbox.html
+    /// [`Status`]: enum.Status.html
+    /// [`State`]: struct.State.html
+    pub fn transition(
+        mut self,
+        state: &'a mut State,
+        transition: Transition,
+    ) -> Self {
+        self.state = Some(state);
+        self.transition = transition;
+        self
+    }
+
+    fn status(&self, bounds: Rectangle, cursor_position: Point) -> Status {
+        if bounds.contains(cursor_position) {
+            Status::Hovered
+        } else if self.is_focused {
+            Status::Focused
+        } else {
+            Status::Active
+        }
+    }
+}
+
+/// The local state of a [`Checkbox`] with a [`Transition`].
+///
+/// [`Checkbox`]: struct.Checkbox.html
+/// [`Transition`]: ../../animation/struct.Transition.html
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    status: Animated<Status>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+}
+
+/// The possible statuses of a [`Checkbox`].
+///
+/// [`Checkbox`]: struct.Checkbox.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Status {
+    /// The [`Checkbox`] is not being hovered nor focused.
+    ///
+    /// [`Checkbox`]: struct.Checkbox.html
+    #[default]
+    Active,
+
+    /// The [`Checkbox`] is being hovered.
+    ///
+    /// [`Checkbox`]: struct.Checkbox.html
+    Hovered,
+
+    /// The [`Checkbox`] is focused and it is not being hovered.
+    ///
+    /// [`Checkbox`]: struct.Checkbox.html
+    Focused,
 }
 
-impl<Message, Renderer> Widget<Message, Renderer>
-    for Checkbox<Message, Renderer>
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Checkbox<'a, Message, Renderer>
 where
     Renderer: self::Renderer + text::Renderer + row::Renderer,
 {
@@ -162,19 +258,41 @@ where
         _renderer: &Renderer,
         _clipboard: Option<&dyn Clipboard>,
     ) -> EventInteraction {
-        match event {
+        let interaction = match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
                 let mouse_over = layout.bounds().contains(cursor_position);
 
                 if mouse_over {
                     messages.push((self.on_toggle)(!self.is_checked));
                 }
+                self.is_focused = false;
                 EventInteraction {
                     consumed: mouse_over,
                 }
             }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Space,
+                ..
+            })
+            | Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Enter,
+                ..
+            }) if self.is_focused => {
+                messages.push((self.on_toggle)(!self.is_checked));
+                EventInteraction { consumed: true }
+            }
             _ => EventInteraction { consumed: false },
+        };
+
+        let status = self.status(layout.bounds(), cursor_position);
+
+        if let Some(state) = &mut self.state {
+            state
+                .status
+                .transition(status, self.transition, Instant::now());
         }
+
+        interaction
     }
 
     fn draw(
@@ -204,12 +322,22 @@ where
         );
 
         let is_mouse_over = bounds.contains(cursor_position);
+        let status = self.status(bounds, cursor_position);
+
+        // The status may have changed without any events, like when focusing
+        let status = match &self.state {
+            Some(state) if *state.status.target() == status => {
+                state.status.at(Instant::now())
+            }
+            _ => Frame::settled(status),
+        };
 
         self::Renderer::draw(
             renderer,
             checkbox_bounds,
             self.is_checked,
             is_mouse_over,
+            status,
             label,
             &self.style,
         )
@@ -221,6 +349,44 @@ where
 
         self.label.hash(state);
     }
+
+    fn focusables<'b>(
+        &'b mut self,
+        focusables: &mut Vec<&'b mut dyn focus::Focusable>,
+    ) {
+        focusables.push(self);
+    }
+
+    fn redraw_request(&self) -> Option<Instant> {
+        self.state
+            .as_ref()
+            .and_then(|state| state.status.redraw_request(Instant::now()))
+    }
+}
+
+impl<'a, Message, Renderer> focus::Focusable for Checkbox<'a, Message, Renderer>
+where
+    Renderer: self::Renderer + text::Renderer,
+{
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+
+    fn id(&self) -> Option<&Id> {
+        self.id.as_ref()
+    }
+
+    fn is_stateful(&self) -> bool {
+        false
+    }
 }
 
 /// The renderer of a [`Checkbox`].
@@ -250,28 +416,72 @@ pub trait Renderer: crate::Renderer {
     ///   * the bounds of the [`Checkbox`]
     ///   * whether the [`Checkbox`] is selected or not
     ///   * whether the mouse is over the [`Checkbox`] or not
+    ///   * the current [`Frame`] of the [`Status`] of the [`Checkbox`], which
+    ///     should be used to blend the styles of each [`Status`]
     ///   * the drawn label of the [`Checkbox`]
     ///
     /// [`Checkbox`]: struct.Checkbox.html
+    /// [`Frame`]: ../../animation/struct.Frame.html
+    /// [`Status`]: enum.Status.html
     fn draw(
         &mut self,
         bounds: Rectangle,
         is_checked: bool,
         is_mouse_over: bool,
+        status: Frame<Status>,
         label: Self::Output,
         style: &Self::Style,
     ) -> Self::Output;
 }
 
-impl<'a, Message, Renderer> From<Checkbox<Message, Renderer>>
+impl<'a, Message, Renderer> From<Checkbox<'a, Message, Renderer>>
     for Element<'a, Message, Renderer>
 where
     Renderer: 'a + self::Renderer + text::Renderer + row::Renderer,
     Message: 'a,
 {
     fn from(
-        checkbox: Checkbox<Message, Renderer>,
+        checkbox: Checkbox<'a, Message, Renderer>,
     ) -> Element<'a, Message, Renderer> {
         Element::new(checkbox)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{renderer::Null, Size};
+
+    use std::time::Duration;
+
+    #[test]
+    fn requests_redraws_while_transitioning() {
+        let mut state = State::new();
+        let cursor_position = Point::new(5.0, 5.0);
+
+        let mut checkbox: Checkbox<'_, bool, Null> =
+            Checkbox::new(false, "Toggle me!", |is_checked| is_checked)
+                .transition(
+                    &mut state,
+                    Transition::new(Duration::from_secs(1)),
+                );
+
+        let node = checkbox.layout(
+            &Null,
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 200.0)),
+        );
+
+        assert_eq!(checkbox.redraw_request(), None);
+
+        let _ = checkbox.on_event(
+            Event::Mouse(mouse::Event::CursorMoved { x: 5.0, y: 5.0 }),
+            Layout::new(&node),
+            cursor_position,
+            &mut Vec::new(),
+            &Null,
+            None,
+        );
+
+        assert!(checkbox.redraw_request().is_some());
+    }
+}
@@ -2,10 +2,13 @@
 use std::hash::Hash;
 
 use crate::{
-    layout, overlay, Align, Clipboard, Element, Event, EventInteraction,
-    Hasher, Layout, Length, Point, Widget,
+    focus, keyboard, layout, overlay, AlignItems, Clipboard, Element, Event,
+    EventInteraction, Hasher, Id, Justify, Layout, Length, Padding, Point,
+    Widget,
 };
 
+use std::any::Any;
+use std::time::Instant;
 use std::u32;
 
 /// A container that distributes its contents vertically.
@@ -16,12 +19,13 @@ use std::u32;
 #[allow(missing_debug_implementations)]
 pub struct Column<'a, Message, Renderer> {
     spacing: u16,
-    padding: u16,
+    padding: Padding,
     width: Length,
     height: Length,
     max_width: u32,
     max_height: u32,
-    align_items: Align,
+    justify_content: Justify,
+    align_items: AlignItems,
     children: Vec<Element<'a, Message, Renderer>>,
 }
 
@@ -41,12 +45,13 @@ impl<'a, Message, Renderer> Column<'a, Message, Renderer> {
     ) -> Self {
         Column {
             spacing: 0,
-            padding: 0,
+            padding: Padding::ZERO,
             width: Length::Shrink,
             height: Length::Shrink,
             max_width: u32::MAX,
             max_height: u32::MAX,
-            align_items: Align::Start,
+            justify_content: Justify::Start,
+            align_items: AlignItems::Start,
             children,
         }
     }
@@ -64,8 +69,8 @@ impl<'a, Message, Renderer> Column<'a, Message, Renderer> {
     /// Sets the padding of the [`Column`].
     ///
     /// [`Column`]: struct.Column.html
-    pub fn padding(mut self, units: u16) -> Self {
-        self.padding = units;
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
         self
     }
 
@@ -101,11 +106,19 @@ impl<'a, Message, Renderer> Column<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the vertical distribution of the contents of the [`Column`].
+    ///
+    /// [`Column`]: struct.Column.html
+    pub fn justify_content(mut self, justify: Justify) -> Self {
+        self.justify_content = justify;
+        self
+    }
+
     /// Sets the horizontal alignment of the contents of the [`Column`] .
     ///
     /// [`Column`]: struct.Column.html
-    pub fn align_items(mut self, align: Align) -> Self {
-        self.align_items = align;
+    pub fn align_items<A: Into<AlignItems>>(mut self, align: A) -> Self {
+        self.align_items = align.into();
         self
     }
 
@@ -149,8 +162,9 @@ where
             layout::flex::Axis::Vertical,
             renderer,
             &limits,
-            self.padding as f32,
+            self.padding,
             self.spacing as f32,
+            self.justify_content,
             self.align_items,
             &self.children,
         )
@@ -201,6 +215,7 @@ where
         self.height.hash(state);
         self.max_width.hash(state);
         self.max_height.hash(state);
+        self.justify_content.hash(state);
         self.align_items.hash(state);
         self.spacing.hash(state);
 
@@ -213,11 +228,53 @@ where
         &mut self,
         layout: Layout<'_>,
     ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        overlay::Group::with_children(
+            self.children
+                .iter_mut()
+                .zip(layout.children())
+                .filter_map(|(child, layout)| child.widget.overlay(layout))
+                .collect(),
+        )
+        .overlay()
+    }
+
+    fn on_shortcut(
+        &mut self,
+        key_code: keyboard::KeyCode,
+        modifiers: keyboard::ModifiersState,
+        messages: &mut Vec<Message>,
+    ) -> EventInteraction {
+        self.children.iter_mut().fold(
+            EventInteraction::default(),
+            |interaction, child| {
+                child
+                    .widget
+                    .on_shortcut(key_code, modifiers, messages)
+                    .union(&interaction)
+            },
+        )
+    }
+
+    fn focusables<'b>(
+        &'b mut self,
+        focusables: &mut Vec<&'b mut dyn focus::Focusable>,
+    ) {
+        for child in &mut self.children {
+            child.widget.focusables(focusables);
+        }
+    }
+
+    fn targets<'b>(&'b mut self, targets: &mut Vec<(&'b Id, &'b mut dyn Any)>) {
+        for child in &mut self.children {
+            child.widget.targets(targets);
+        }
+    }
+
+    fn redraw_request(&self) -> Option<Instant> {
         self.children
-            .iter_mut()
-            .zip(layout.children())
-            .filter_map(|(child, layout)| child.widget.overlay(layout))
-            .next()
+            .iter()
+            .filter_map(|child| child.widget.redraw_request())
+            .min()
     }
 }
 
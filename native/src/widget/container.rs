@@ -2,10 +2,13 @@
 use std::hash::Hash;
 
 use crate::{
-    layout, overlay, Align, Clipboard, Element, Event, EventInteraction,
-    Hasher, Layout, Length, Point, Rectangle, Widget,
+    focus, keyboard, layout, overlay, Align, Clipboard, Element, Event,
+    EventInteraction, Hasher, Id, Layout, Length, Padding, Point, Rectangle,
+    Widget,
 };
 
+use std::any::Any;
+use std::time::Instant;
 use std::u32;
 
 /// An element decorating some content.
@@ -13,7 +16,7 @@ use std::u32;
 /// It is normally used for alignment purposes.
 #[allow(missing_debug_implementations)]
 pub struct Container<'a, Message, Renderer: self::Renderer> {
-    padding: u16,
+    padding: Padding,
     width: Length,
     height: Length,
     max_width: u32,
@@ -36,7 +39,7 @@ where
         T: Into<Element<'a, Message, Renderer>>,
     {
         Container {
-            padding: 0,
+            padding: Padding::ZERO,
             width: Length::Shrink,
             height: Length::Shrink,
             max_width: u32::MAX,
@@ -51,8 +54,8 @@ where
     /// Sets the padding of the [`Container`].
     ///
     /// [`Container`]: struct.Column.html
-    pub fn padding(mut self, units: u16) -> Self {
-        self.padding = units;
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
         self
     }
 
@@ -147,7 +150,7 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        let padding = f32::from(self.padding);
+        let padding = self.padding;
 
         let limits = limits
             .loose()
@@ -160,7 +163,10 @@ where
         let mut content = self.content.layout(renderer, &limits.loose());
         let size = limits.resolve(content.size());
 
-        content.move_to(Point::new(padding, padding));
+        content.move_to(Point::new(
+            f32::from(padding.left),
+            f32::from(padding.top),
+        ));
         content.align(self.horizontal_alignment, self.vertical_alignment, size);
 
         layout::Node::with_children(size.pad(padding), vec![content])
@@ -221,6 +227,32 @@ where
     ) -> Option<overlay::Element<'_, Message, Renderer>> {
         self.content.overlay(layout.children().next().unwrap())
     }
+
+    fn on_shortcut(
+        &mut self,
+        key_code: keyboard::KeyCode,
+        modifiers: keyboard::ModifiersState,
+        messages: &mut Vec<Message>,
+    ) -> EventInteraction {
+        self.content
+            .widget
+            .on_shortcut(key_code, modifiers, messages)
+    }
+
+    fn focusables<'b>(
+        &'b mut self,
+        focusables: &mut Vec<&'b mut dyn focus::Focusable>,
+    ) {
+        self.content.focusables(focusables);
+    }
+
+    fn targets<'b>(&'b mut self, targets: &mut Vec<(&'b Id, &'b mut dyn Any)>) {
+        self.content.targets(targets);
+    }
+
+    fn redraw_request(&self) -> Option<Instant> {
+        self.content.redraw_request()
+    }
 }
 
 /// The renderer of a [`Container`].
@@ -0,0 +1,1143 @@
+//! Open a menu of actions when right-clicking other widgets.
+//!
+//! A [`ContextMenu`] has some local [`State`].
+//!
+//! [`ContextMenu`]: struct.ContextMenu.html
+//! [`State`]: struct.State.html
+use crate::{
+    focus, keyboard, layout, mouse, overlay, text, Clipboard, Element, Event,
+    EventInteraction, Hasher, Id, Layout, Length, Padding, Point, Rectangle,
+    Size, Vector, Widget,
+};
+
+use std::any::Any;
+use std::hash::Hash;
+use std::time::Instant;
+
+/// An element that opens a menu at the cursor position when right-clicked.
+///
+/// The menu can also be opened with the `Menu` key while the element is
+/// hovered. It closes when an entry is selected, when clicking outside of it
+/// or when pressing `Escape`.
+///
+/// ```
+/// # use iced_native::{context_menu, renderer::Null, Text};
+/// #
+/// # pub type ContextMenu<'a, Message> =
+/// #     iced_native::ContextMenu<'a, Message, Null>;
+/// #
+/// use context_menu::Item;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Copy,
+///     Paste,
+///     ZoomIn,
+///     ZoomOut,
+/// }
+///
+/// let mut state = context_menu::State::new();
+///
+/// let context_menu: ContextMenu<'_, Message> = ContextMenu::new(
+///     &mut state,
+///     Text::new("Right-click me!"),
+///     vec![
+///         Item::new("Copy", Message::Copy),
+///         Item::disabled("Paste"),
+///         Item::separator(),
+///         Item::submenu(
+///             "Zoom",
+///             vec![
+///                 Item::new("Zoom in", Message::ZoomIn),
+///                 Item::new("Zoom out", Message::ZoomOut),
+///             ],
+///         ),
+///     ],
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ContextMenu<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    content: Element<'a, Message, Renderer>,
+    items: Vec<Item<Message>>,
+    padding: Padding,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style: <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer> ContextMenu<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`ContextMenu`] with some local [`State`], the content
+    /// to right-click and the [`Item`]s of the menu.
+    ///
+    /// [`ContextMenu`]: struct.ContextMenu.html
+    /// [`State`]: struct.State.html
+    /// [`Item`]: enum.Item.html
+    pub fn new(
+        state: &'a mut State,
+        content: impl Into<Element<'a, Message, Renderer>>,
+        items: Vec<Item<Message>>,
+    ) -> Self {
+        ContextMenu {
+            state,
+            content: content.into(),
+            items,
+            padding: Renderer::DEFAULT_PADDING,
+            text_size: None,
+            font: Default::default(),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the padding of the entries of the [`ContextMenu`].
+    ///
+    /// [`ContextMenu`]: struct.ContextMenu.html
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`ContextMenu`].
+    ///
+    /// [`ContextMenu`]: struct.ContextMenu.html
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the font of the [`ContextMenu`].
+    ///
+    /// [`ContextMenu`]: struct.ContextMenu.html
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`ContextMenu`].
+    ///
+    /// [`ContextMenu`]: struct.ContextMenu.html
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as self::Renderer>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// An item of a [`ContextMenu`].
+///
+/// [`ContextMenu`]: struct.ContextMenu.html
+#[derive(Debug, Clone)]
+pub enum Item<Message> {
+    /// An entry producing a message when selected.
+    ///
+    /// The entry is disabled if it has no message.
+    Entry {
+        /// The label of the entry.
+        label: Label,
+
+        /// The message produced when the entry is selected.
+        on_select: Option<Message>,
+
+        /// The checkbox or radio button of the entry, if any.
+        toggle: Option<Toggle>,
+
+        /// The keyboard shortcut of the entry, if any.
+        accelerator: Option<Accelerator>,
+    },
+
+    /// A line separating groups of items.
+    Separator,
+
+    /// An entry opening a nested menu when hovered.
+    Submenu {
+        /// The label of the entry.
+        label: Label,
+
+        /// The items of the nested menu.
+        items: Vec<Item<Message>>,
+    },
+}
+
+impl<Message> Item<Message> {
+    /// Creates an entry producing the given message when selected.
+    ///
+    /// An `&` in the label marks the next character as the mnemonic of the
+    /// entry.
+    pub fn new(label: impl Into<Label>, on_select: Message) -> Self {
+        Item::Entry {
+            label: label.into(),
+            on_select: Some(on_select),
+            toggle: None,
+            accelerator: None,
+        }
+    }
+
+    /// Creates a disabled entry.
+    pub fn disabled(label: impl Into<Label>) -> Self {
+        Item::Entry {
+            label: label.into(),
+            on_select: None,
+            toggle: None,
+            accelerator: None,
+        }
+    }
+
+    /// Creates a separator.
+    pub fn separator() -> Self {
+        Item::Separator
+    }
+
+    /// Creates an entry opening a nested menu with the given items.
+    pub fn submenu(label: impl Into<Label>, items: Vec<Item<Message>>) -> Self {
+        Item::Submenu {
+            label: label.into(),
+            items,
+        }
+    }
+
+    /// Displays a checkbox in the entry.
+    ///
+    /// It has no effect on separators and submenus.
+    pub fn checkbox(self, is_checked: bool) -> Self {
+        self.toggle(Toggle::Checkbox(is_checked))
+    }
+
+    /// Displays a radio button in the entry.
+    ///
+    /// It has no effect on separators and submenus.
+    pub fn radio(self, is_selected: bool) -> Self {
+        self.toggle(Toggle::Radio(is_selected))
+    }
+
+    /// Sets the keyboard shortcut of the entry.
+    ///
+    /// It has no effect on separators and submenus.
+    pub fn accelerator(mut self, accelerator: Accelerator) -> Self {
+        if let Item::Entry {
+            accelerator: current,
+            ..
+        } = &mut self
+        {
+            *current = Some(accelerator);
+        }
+
+        self
+    }
+
+    /// Returns the [`Label`] of the item, if it has one.
+    ///
+    /// [`Label`]: struct.Label.html
+    pub fn label(&self) -> Option<&Label> {
+        match self {
+            Item::Entry { label, .. } | Item::Submenu { label, .. } => {
+                Some(label)
+            }
+            Item::Separator => None,
+        }
+    }
+
+    /// Returns whether the item can be hovered and selected.
+    pub fn is_enabled(&self) -> bool {
+        match self {
+            Item::Entry { on_select, .. } => on_select.is_some(),
+            Item::Separator => false,
+            Item::Submenu { .. } => true,
+        }
+    }
+
+    fn toggle(mut self, toggle: Toggle) -> Self {
+        if let Item::Entry {
+            toggle: current, ..
+        } = &mut self
+        {
+            *current = Some(toggle);
+        }
+
+        self
+    }
+}
+
+/// The checkbox or radio button of an [`Item`].
+///
+/// [`Item`]: enum.Item.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Toggle {
+    /// A checkbox, checked or not.
+    Checkbox(bool),
+
+    /// A radio button, selected or not.
+    Radio(bool),
+}
+
+/// The label of an [`Item`], with an optional mnemonic.
+///
+/// The mnemonic is marked with an `&` before its character, like in `&File`.
+/// Use `&&` to display an ampersand.
+///
+/// [`Item`]: enum.Item.html
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Label {
+    text: String,
+    mnemonic: Option<usize>,
+}
+
+impl Label {
+    /// Creates a new [`Label`], parsing its mnemonic.
+    ///
+    /// [`Label`]: struct.Label.html
+    pub fn new(label: &str) -> Self {
+        let mut text = String::with_capacity(label.len());
+        let mut mnemonic = None;
+        let mut chars = label.chars();
+
+        while let Some(c) = chars.next() {
+            if c != '&' {
+                text.push(c);
+                continue;
+            }
+
+            match chars.next() {
+                Some('&') => text.push('&'),
+                Some(c) => {
+                    if mnemonic.is_none() {
+                        mnemonic = Some(text.len());
+                    }
+
+                    text.push(c);
+                }
+                None => {}
+            }
+        }
+
+        Label { text, mnemonic }
+    }
+
+    /// Returns the text of the [`Label`] to display.
+    ///
+    /// [`Label`]: struct.Label.html
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the byte index of the mnemonic in the text of the [`Label`],
+    /// if any.
+    ///
+    /// [`Label`]: struct.Label.html
+    pub fn mnemonic_index(&self) -> Option<usize> {
+        self.mnemonic
+    }
+
+    /// Returns the mnemonic of the [`Label`], if any.
+    ///
+    /// [`Label`]: struct.Label.html
+    pub fn mnemonic(&self) -> Option<char> {
+        self.mnemonic
+            .and_then(|index| self.text[index..].chars().next())
+    }
+
+    /// Returns whether the given key triggers the mnemonic of the [`Label`].
+    ///
+    /// [`Label`]: struct.Label.html
+    pub fn matches(&self, key_code: keyboard::KeyCode) -> bool {
+        match (self.mnemonic(), key_char(key_code)) {
+            (Some(mnemonic), Some(key)) => mnemonic.to_ascii_uppercase() == key,
+            _ => false,
+        }
+    }
+}
+
+impl From<&str> for Label {
+    fn from(label: &str) -> Label {
+        Label::new(label)
+    }
+}
+
+impl From<String> for Label {
+    fn from(label: String) -> Label {
+        Label::new(&label)
+    }
+}
+
+/// A keyboard shortcut of an [`Item`], like `Ctrl+S`.
+///
+/// [`Item`]: enum.Item.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Accelerator {
+    /// The modifiers that must be pressed.
+    pub modifiers: keyboard::ModifiersState,
+
+    /// The key that must be pressed.
+    pub key_code: keyboard::KeyCode,
+}
+
+impl Accelerator {
+    /// Creates a new [`Accelerator`].
+    ///
+    /// [`Accelerator`]: struct.Accelerator.html
+    pub fn new(
+        modifiers: keyboard::ModifiersState,
+        key_code: keyboard::KeyCode,
+    ) -> Self {
+        Accelerator {
+            modifiers,
+            key_code,
+        }
+    }
+
+    /// Returns whether the given key press triggers the [`Accelerator`].
+    ///
+    /// [`Accelerator`]: struct.Accelerator.html
+    pub fn matches(
+        &self,
+        key_code: keyboard::KeyCode,
+        modifiers: keyboard::ModifiersState,
+    ) -> bool {
+        self.key_code == key_code && self.modifiers == modifiers
+    }
+}
+
+impl std::fmt::Display for Accelerator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let logo = if cfg!(target_os = "macos") {
+            "Cmd"
+        } else {
+            "Super"
+        };
+
+        let modifiers = [
+            (self.modifiers.control, "Ctrl"),
+            (self.modifiers.alt, "Alt"),
+            (self.modifiers.shift, "Shift"),
+            (self.modifiers.logo, logo),
+        ];
+
+        for (_, name) in modifiers.iter().filter(|(is_pressed, _)| *is_pressed)
+        {
+            write!(f, "{}+", name)?;
+        }
+
+        match key_char(self.key_code) {
+            Some(key) => write!(f, "{}", key),
+            None => write!(f, "{:?}", self.key_code),
+        }
+    }
+}
+
+/// Returns the character of a letter or digit key.
+fn key_char(key_code: keyboard::KeyCode) -> Option<char> {
+    use keyboard::KeyCode;
+
+    match key_code {
+        KeyCode::Key0 => Some('0'),
+        KeyCode::Key1 => Some('1'),
+        KeyCode::Key2 => Some('2'),
+        KeyCode::Key3 => Some('3'),
+        KeyCode::Key4 => Some('4'),
+        KeyCode::Key5 => Some('5'),
+        KeyCode::Key6 => Some('6'),
+        KeyCode::Key7 => Some('7'),
+        KeyCode::Key8 => Some('8'),
+        KeyCode::Key9 => Some('9'),
+        KeyCode::A => Some('A'),
+        KeyCode::B => Some('B'),
+        KeyCode::C => Some('C'),
+        KeyCode::D => Some('D'),
+        KeyCode::E => Some('E'),
+        KeyCode::F => Some('F'),
+        KeyCode::G => Some('G'),
+        KeyCode::H => Some('H'),
+        KeyCode::I => Some('I'),
+        KeyCode::J => Some('J'),
+        KeyCode::K => Some('K'),
+        KeyCode::L => Some('L'),
+        KeyCode::M => Some('M'),
+        KeyCode::N => Some('N'),
+        KeyCode::O => Some('O'),
+        KeyCode::P => Some('P'),
+        KeyCode::Q => Some('Q'),
+        KeyCode::R => Some('R'),
+        KeyCode::S => Some('S'),
+        KeyCode::T => Some('T'),
+        KeyCode::U => Some('U'),
+        KeyCode::V => Some('V'),
+        KeyCode::W => Some('W'),
+        KeyCode::X => Some('X'),
+        KeyCode::Y => Some('Y'),
+        KeyCode::Z => Some('Z'),
+        _ => None,
+    }
+}
+
+/// The local state of a [`ContextMenu`].
+///
+/// [`ContextMenu`]: struct.ContextMenu.html
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct State {
+    offset: Option<Vector>,
+    hovered: Vec<usize>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns whether the menu of the [`ContextMenu`] is open.
+    ///
+    /// [`ContextMenu`]: struct.ContextMenu.html
+    pub fn is_open(&self) -> bool {
+        self.offset.is_some()
+    }
+
+    pub(crate) fn open(&mut self, offset: Vector) {
+        self.offset = Some(offset);
+        self.hovered.clear();
+    }
+
+    pub(crate) fn close(&mut self) {
+        self.offset = None;
+        self.hovered.clear();
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for ContextMenu<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> EventInteraction {
+        let interaction = self.content.widget.on_event(
+            event.clone(),
+            layout,
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        );
+
+        // Nested context menus take precedence
+        if interaction.consumed {
+            return interaction;
+        }
+
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right))
+            | Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Apps,
+                ..
+            }) if bounds.contains(cursor_position)
+                && !self.items.is_empty() =>
+            {
+                self.state.open(cursor_position - bounds.position());
+
+                EventInteraction { consumed: true }
+            }
+            _ => interaction,
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self.content
+            .draw(renderer, defaults, layout, cursor_position)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let offset = match self.state.offset {
+            Some(offset) if !self.items.is_empty() => offset,
+            _ => return self.content.overlay(layout),
+        };
+
+        Some(overlay::Element::new(
+            layout.position(),
+            Box::new(Overlay {
+                state: &mut *self.state,
+                items: &self.items,
+                target: Rectangle::new(Point::ORIGIN + offset, Size::ZERO),
+                passthrough: None,
+                padding: self.padding,
+                text_size: self.text_size,
+                font: self.font,
+                style: self.style.clone(),
+            }),
+        ))
+    }
+
+    fn on_shortcut(
+        &mut self,
+        key_code: keyboard::KeyCode,
+        modifiers: keyboard::ModifiersState,
+        messages: &mut Vec<Message>,
+    ) -> EventInteraction {
+        self.content
+            .widget
+            .on_shortcut(key_code, modifiers, messages)
+    }
+
+    fn focusables<'b>(
+        &'b mut self,
+        focusables: &mut Vec<&'b mut dyn focus::Focusable>,
+    ) {
+        self.content.focusables(focusables);
+    }
+
+    fn targets<'b>(&'b mut self, targets: &mut Vec<(&'b Id, &'b mut dyn Any)>) {
+        self.content.targets(targets);
+    }
+
+    fn redraw_request(&self) -> Option<Instant> {
+        self.content.redraw_request()
+    }
+}
+
+/// The open menus of a [`ContextMenu`] or a [`MenuBar`].
+///
+/// [`ContextMenu`]: struct.ContextMenu.html
+/// [`MenuBar`]: ../menu_bar/struct.MenuBar.html
+pub(crate) struct Overlay<'a, Message, Renderer: self::Renderer> {
+    /// The state of the menus, closed when an entry is selected.
+    pub state: &'a mut State,
+
+    /// The items of the outermost menu.
+    pub items: &'a [Item<Message>],
+
+    /// The area the outermost menu is attached to, relative to the position
+    /// of the overlay.
+    ///
+    /// The menu is displayed under it or, if it does not fit, above it.
+    pub target: Rectangle,
+
+    /// An area, relative to the position of the overlay, where clicks are
+    /// left to the widget below instead of closing the menus.
+    pub passthrough: Option<Rectangle>,
+
+    pub padding: Padding,
+    pub text_size: Option<u16>,
+    pub font: Renderer::Font,
+    pub style: <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer> Overlay<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: self::Renderer,
+{
+    /// Returns the items of every open menu, from the outermost one.
+    fn menus(&self) -> Vec<&'a [Item<Message>]> {
+        let mut menus = vec![self.items];
+
+        for index in self.state.hovered.iter() {
+            match menus.last().and_then(|items| items.get(*index)) {
+                Some(Item::Submenu { items, .. }) => menus.push(items),
+                _ => break,
+            }
+        }
+
+        menus
+    }
+
+    /// Returns the open menu and the item under the cursor, if any.
+    fn hovered_item(
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<(usize, Option<usize>)> {
+        let (level, menu) = layout
+            .children()
+            .enumerate()
+            .filter(|(_, menu)| menu.bounds().contains(cursor_position))
+            .last()?;
+
+        let item = menu
+            .children()
+            .position(|item| item.bounds().contains(cursor_position));
+
+        Some((level, item))
+    }
+
+    fn is_passthrough(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> bool {
+        match self.passthrough {
+            Some(passthrough) => {
+                let position = layout.position();
+
+                Rectangle {
+                    x: position.x + passthrough.x,
+                    y: position.y + passthrough.y,
+                    ..passthrough
+                }
+                .contains(cursor_position)
+            }
+            None => false,
+        }
+    }
+
+    fn select(
+        &mut self,
+        level: usize,
+        index: usize,
+        item: &Item<Message>,
+        messages: &mut Vec<Message>,
+    ) {
+        match item {
+            Item::Entry {
+                on_select: Some(on_select),
+                ..
+            } => {
+                messages.push(on_select.clone());
+                self.state.close();
+            }
+            Item::Submenu { .. } => {
+                self.state.hovered.truncate(level);
+                self.state.hovered.push(index);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Overlay<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: self::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let text_size = self.text_size.unwrap_or(renderer.default_size());
+        let padding = self.padding;
+        let entry_height = f32::from(text_size + padding.vertical());
+
+        let measure = |content: &str| {
+            let (width, _) =
+                renderer.measure(content, text_size, self.font, Size::INFINITY);
+
+            width
+        };
+
+        let mut menus: Vec<layout::Node> = Vec::new();
+
+        for (level, items) in self.menus().into_iter().enumerate() {
+            let has_toggles = items.iter().any(|item| match item {
+                Item::Entry { toggle, .. } => toggle.is_some(),
+                _ => false,
+            });
+
+            let width = items
+                .iter()
+                .map(|item| match item {
+                    Item::Entry {
+                        label, accelerator, ..
+                    } => {
+                        measure(label.text())
+                            + accelerator
+                                .map(|accelerator| {
+                                    // Leave a gap before the shortcut
+                                    measure(&accelerator.to_string())
+                                        + f32::from(text_size)
+                                })
+                                .unwrap_or(0.0)
+                    }
+                    Item::Submenu { label, .. } => {
+                        // Leave room for the submenu indicator
+                        measure(label.text()) + f32::from(text_size)
+                    }
+                    Item::Separator => 0.0,
+                })
+                .fold(0.0, f32::max)
+                + f32::from(padding.horizontal())
+                + if has_toggles {
+                    f32::from(text_size)
+                } else {
+                    0.0
+                };
+
+            let mut height = 0.0;
+
+            let rows = items
+                .iter()
+                .map(|item| {
+                    let row_height = match item {
+                        Item::Separator => f32::from(padding.vertical()) + 1.0,
+                        _ => entry_height,
+                    };
+
+                    let mut row =
+                        layout::Node::new(Size::new(width, row_height));
+                    row.move_to(Point::new(0.0, height));
+
+                    height += row_height;
+
+                    row
+                })
+                .collect();
+
+            let size = Size::new(width, height);
+
+            let (x, y) = match menus.last() {
+                None => {
+                    let target = Rectangle {
+                        x: position.x + self.target.x,
+                        y: position.y + self.target.y,
+                        ..self.target
+                    };
+
+                    (
+                        if target.x + size.width > bounds.width {
+                            target.x + target.width - size.width
+                        } else {
+                            target.x
+                        },
+                        if target.y + target.height + size.height
+                            > bounds.height
+                        {
+                            target.y - size.height
+                        } else {
+                            target.y + target.height
+                        },
+                    )
+                }
+                Some(parent) => {
+                    let parent_bounds = parent.bounds();
+
+                    let row = parent
+                        .children()
+                        .get(self.state.hovered[level - 1])
+                        .map(layout::Node::bounds)
+                        .unwrap_or_default();
+
+                    (
+                        if parent_bounds.x + parent_bounds.width + size.width
+                            > bounds.width
+                        {
+                            parent_bounds.x - size.width
+                        } else {
+                            parent_bounds.x + parent_bounds.width
+                        },
+                        parent_bounds.y + row.y,
+                    )
+                }
+            };
+
+            // Keep the menu inside the viewport
+            let x = x.min(bounds.width - size.width).max(0.0);
+            let y = y.min(bounds.height - size.height).max(0.0);
+
+            let mut menu = layout::Node::with_children(size, rows);
+            menu.move_to(Point::new(x, y));
+
+            menus.push(menu);
+        }
+
+        layout::Node::with_children(bounds, menus)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) -> EventInteraction {
+        if let Event::Mouse(_) = event {
+            if self.is_passthrough(layout, cursor_position) {
+                return EventInteraction::default();
+            }
+        }
+
+        let menus = self.menus();
+        let hovered_item = Self::hovered_item(layout, cursor_position);
+
+        let item = hovered_item.and_then(|(level, index)| {
+            let index = index?;
+
+            menus
+                .get(level)
+                .and_then(|items| items.get(index))
+                .map(|item| (level, index, item))
+        });
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some((level, index, item)) = item {
+                    self.state.hovered.truncate(level);
+
+                    if item.is_enabled() {
+                        self.state.hovered.push(index);
+                    }
+                }
+
+                EventInteraction {
+                    consumed: hovered_item.is_some(),
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_)) => {
+                if hovered_item.is_none() {
+                    // Let the click through, it may open another menu
+                    self.state.close();
+
+                    return EventInteraction::default();
+                }
+
+                if let Some((level, index, item)) = item {
+                    self.select(level, index, item, messages);
+                }
+
+                EventInteraction { consumed: true }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Escape,
+                ..
+            }) => {
+                self.state.close();
+
+                EventInteraction { consumed: true }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) if !(modifiers.control || modifiers.alt || modifiers.logo) => {
+                // Mnemonics apply to the innermost menu
+                let level = menus.len() - 1;
+
+                let mnemonic =
+                    menus[level].iter().enumerate().find(|(_, item)| {
+                        item.is_enabled()
+                            && item
+                                .label()
+                                .map(|label| label.matches(key_code))
+                                .unwrap_or(false)
+                    });
+
+                match mnemonic {
+                    Some((index, item)) => {
+                        self.select(level, index, item, messages);
+
+                        EventInteraction { consumed: true }
+                    }
+                    None => EventInteraction::default(),
+                }
+            }
+            _ => EventInteraction::default(),
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let menus: Vec<_> = self
+            .menus()
+            .into_iter()
+            .enumerate()
+            .map(|(level, items)| {
+                (items, self.state.hovered.get(level).copied())
+            })
+            .collect();
+
+        self::Renderer::draw(
+            renderer,
+            layout,
+            cursor_position,
+            &menus,
+            self.padding,
+            self.text_size.unwrap_or(renderer.default_size()),
+            self.font,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher, position: Point) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        (position.x as u32).hash(state);
+        (position.y as u32).hash(state);
+        (self.target.x as u32).hash(state);
+        (self.target.y as u32).hash(state);
+        (self.target.width as u32).hash(state);
+        (self.target.height as u32).hash(state);
+        self.padding.hash(state);
+        self.text_size.hash(state);
+        self.state.hovered.hash(state);
+
+        for items in self.menus() {
+            for item in items {
+                match item {
+                    Item::Entry {
+                        label,
+                        toggle,
+                        accelerator,
+                        ..
+                    } => {
+                        label.hash(state);
+                        toggle.is_some().hash(state);
+                        accelerator.hash(state);
+                    }
+                    Item::Separator => 0.hash(state),
+                    Item::Submenu { label, .. } => label.hash(state),
+                }
+            }
+        }
+    }
+
+    fn is_over(&self, layout: Layout<'_>, cursor_position: Point) -> bool {
+        Self::hovered_item(layout, cursor_position).is_some()
+    }
+}
+
+/// The renderer of a [`ContextMenu`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`ContextMenu`] in your user interface.
+///
+/// [`ContextMenu`]: struct.ContextMenu.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: text::Renderer {
+    /// The default padding of the entries of a [`ContextMenu`].
+    ///
+    /// [`ContextMenu`]: struct.ContextMenu.html
+    const DEFAULT_PADDING: Padding;
+
+    /// The style supported by this renderer.
+    type Style: Default + Clone;
+
+    /// Draws the open menus of a [`ContextMenu`].
+    ///
+    /// It receives:
+    ///   * the [`Layout`] of the menus, with a child per menu which, in turn,
+    ///     has a child per [`Item`]
+    ///   * the cursor position
+    ///   * the [`Item`]s of each open menu, from the outermost one, and the
+    ///     index of the hovered one, if any
+    ///   * the padding, text size and font of the entries
+    ///   * the style of the [`ContextMenu`]
+    ///
+    /// [`ContextMenu`]: struct.ContextMenu.html
+    /// [`Layout`]: ../../struct.Layout.html
+    /// [`Item`]: enum.Item.html
+    fn draw<Message>(
+        &mut self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        menus: &[(&[Item<Message>], Option<usize>)],
+        padding: Padding,
+        text_size: u16,
+        font: Self::Font,
+        style: &<Self as Renderer>::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<ContextMenu<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a + Clone,
+{
+    fn from(
+        context_menu: ContextMenu<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(context_menu)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use keyboard::{KeyCode, ModifiersState};
+
+    #[test]
+    fn label_parses_mnemonic() {
+        let label = Label::new("Save &As...");
+
+        assert_eq!(label.text(), "Save As...");
+        assert_eq!(label.mnemonic(), Some('A'));
+        assert!(label.matches(KeyCode::A));
+        assert!(!label.matches(KeyCode::S));
+
+        let label = Label::new("Fish && &Chips");
+
+        assert_eq!(label.text(), "Fish & Chips");
+        assert_eq!(label.mnemonic_index(), Some(7));
+        assert!(label.matches(KeyCode::C));
+
+        assert_eq!(Label::new("Plain").mnemonic(), None);
+    }
+
+    #[test]
+    fn accelerator_is_displayed() {
+        let accelerator = Accelerator::new(
+            ModifiersState {
+                control: true,
+                shift: true,
+                ..ModifiersState::default()
+            },
+            KeyCode::Key1,
+        );
+
+        assert_eq!(accelerator.to_string(), "Ctrl+Shift+1");
+        assert!(accelerator.matches(KeyCode::Key1, accelerator.modifiers));
+        assert!(!accelerator.matches(KeyCode::Key1, ModifiersState::default()));
+    }
+}
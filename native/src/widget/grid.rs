@@ -0,0 +1,353 @@
+//! Distribute content in the cells of a grid of rows and columns.
+use std::hash::Hash;
+
+use crate::{
+    focus, keyboard, layout, overlay, Clipboard, Element, Event,
+    EventInteraction, Hasher, Id, Layout, Length, Padding, Point, Widget,
+};
+
+use std::any::Any;
+use std::time::Instant;
+
+pub use crate::layout::grid::Area;
+
+/// A container that distributes its contents in the cells of a grid of
+/// column and row tracks.
+///
+/// Every track has its own [`Length`]:
+///   * [`Length::Units`] tracks have a fixed size.
+///   * [`Length::Shrink`] tracks fit their contents.
+///   * [`Length::Fill`] and [`Length::FillPortion`] tracks share the
+///     remaining space.
+///
+/// Every element is placed in an [`Area`], which can span multiple columns
+/// and rows and aligns the element in them.
+///
+/// [`Length`]: ../../enum.Length.html
+/// [`Length::Units`]: ../../enum.Length.html#variant.Units
+/// [`Length::Shrink`]: ../../enum.Length.html#variant.Shrink
+/// [`Length::Fill`]: ../../enum.Length.html#variant.Fill
+/// [`Length::FillPortion`]: ../../enum.Length.html#variant.FillPortion
+/// [`Area`]: struct.Area.html
+#[allow(missing_debug_implementations)]
+pub struct Grid<'a, Message, Renderer> {
+    columns: Vec<Length>,
+    rows: Vec<Length>,
+    column_spacing: u16,
+    row_spacing: u16,
+    padding: Padding,
+    width: Length,
+    height: Length,
+    max_width: u32,
+    max_height: u32,
+    areas: Vec<Area>,
+    children: Vec<Element<'a, Message, Renderer>>,
+}
+
+impl<'a, Message, Renderer> Grid<'a, Message, Renderer> {
+    /// Creates an empty [`Grid`] with the given column and row tracks.
+    ///
+    /// Elements placed outside of these tracks will create new
+    /// [`Length::Shrink`] tracks.
+    ///
+    /// [`Grid`]: struct.Grid.html
+    /// [`Length::Shrink`]: ../../enum.Length.html#variant.Shrink
+    pub fn new(columns: Vec<Length>, rows: Vec<Length>) -> Self {
+        Grid {
+            columns,
+            rows,
+            column_spacing: 0,
+            row_spacing: 0,
+            padding: Padding::ZERO,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            max_width: u32::MAX,
+            max_height: u32::MAX,
+            areas: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets both the column and the row spacing of the [`Grid`].
+    ///
+    /// Custom margins per element do not exist in Iced. You should use this
+    /// method instead! While less flexible, it helps you keep spacing between
+    /// elements consistent.
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn spacing(mut self, units: u16) -> Self {
+        self.column_spacing = units;
+        self.row_spacing = units;
+        self
+    }
+
+    /// Sets the horizontal spacing _between_ the columns of the [`Grid`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn column_spacing(mut self, units: u16) -> Self {
+        self.column_spacing = units;
+        self
+    }
+
+    /// Sets the vertical spacing _between_ the rows of the [`Grid`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn row_spacing(mut self, units: u16) -> Self {
+        self.row_spacing = units;
+        self
+    }
+
+    /// Sets the padding of the [`Grid`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the [`Grid`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Grid`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the maximum width of the [`Grid`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn max_width(mut self, max_width: u32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the maximum height of the [`Grid`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn max_height(mut self, max_height: u32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// Adds an [`Element`] to the [`Grid`] in a single cell.
+    ///
+    /// [`Element`]: ../struct.Element.html
+    /// [`Grid`]: struct.Grid.html
+    pub fn push<E>(self, column: usize, row: usize, child: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.push_area(Area::new(column, row), child)
+    }
+
+    /// Adds an [`Element`] to the [`Grid`] in the given [`Area`].
+    ///
+    /// [`Element`]: ../struct.Element.html
+    /// [`Grid`]: struct.Grid.html
+    /// [`Area`]: struct.Area.html
+    pub fn push_area<E>(mut self, area: Area, child: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.areas.push(area);
+        self.children.push(child.into());
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Grid<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits
+            .max_width(self.max_width)
+            .max_height(self.max_height)
+            .width(self.width)
+            .height(self.height);
+
+        layout::grid::resolve(
+            renderer,
+            &limits,
+            self.padding,
+            layout::grid::Tracks {
+                columns: &self.columns,
+                rows: &self.rows,
+                column_spacing: self.column_spacing as f32,
+                row_spacing: self.row_spacing as f32,
+            },
+            &self.areas,
+            &self.children,
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> EventInteraction {
+        self.children.iter_mut().zip(layout.children()).fold(
+            EventInteraction::default(),
+            |interaction, (child, layout)| {
+                child
+                    .widget
+                    .on_event(
+                        event.clone(),
+                        layout,
+                        cursor_position,
+                        messages,
+                        renderer,
+                        clipboard,
+                    )
+                    .union(&interaction)
+            },
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        renderer.draw(defaults, &self.children, layout, cursor_position)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.columns.hash(state);
+        self.rows.hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+        self.max_width.hash(state);
+        self.max_height.hash(state);
+        self.column_spacing.hash(state);
+        self.row_spacing.hash(state);
+        self.padding.hash(state);
+        self.areas.hash(state);
+
+        for child in &self.children {
+            child.widget.hash_layout(state);
+        }
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        overlay::Group::with_children(
+            self.children
+                .iter_mut()
+                .zip(layout.children())
+                .filter_map(|(child, layout)| child.widget.overlay(layout))
+                .collect(),
+        )
+        .overlay()
+    }
+
+    fn on_shortcut(
+        &mut self,
+        key_code: keyboard::KeyCode,
+        modifiers: keyboard::ModifiersState,
+        messages: &mut Vec<Message>,
+    ) -> EventInteraction {
+        self.children.iter_mut().fold(
+            EventInteraction::default(),
+            |interaction, child| {
+                child
+                    .widget
+                    .on_shortcut(key_code, modifiers, messages)
+                    .union(&interaction)
+            },
+        )
+    }
+
+    fn focusables<'b>(
+        &'b mut self,
+        focusables: &mut Vec<&'b mut dyn focus::Focusable>,
+    ) {
+        for child in &mut self.children {
+            child.widget.focusables(focusables);
+        }
+    }
+
+    fn targets<'b>(&'b mut self, targets: &mut Vec<(&'b Id, &'b mut dyn Any)>) {
+        for child in &mut self.children {
+            child.widget.targets(targets);
+        }
+    }
+
+    fn redraw_request(&self) -> Option<Instant> {
+        self.children
+            .iter()
+            .filter_map(|child| child.widget.redraw_request())
+            .min()
+    }
+}
+
+/// The renderer of a [`Grid`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Grid`] in your user interface.
+///
+/// [`Grid`]: struct.Grid.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer + Sized {
+    /// Draws a [`Grid`].
+    ///
+    /// It receives:
+    /// - the children of the [`Grid`]
+    /// - the [`Layout`] of the [`Grid`] and its children
+    /// - the cursor position
+    ///
+    /// [`Grid`]: struct.Grid.html
+    /// [`Layout`]: ../layout/struct.Layout.html
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        children: &[Element<'_, Message, Self>],
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Grid<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        grid: Grid<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(grid)
+    }
+}
@@ -0,0 +1,40 @@
+use std::borrow::Cow;
+use std::sync::atomic::{self, AtomicUsize};
+
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// The identifier of a widget.
+///
+/// It can be used to target a specific widget with a [`Command`], like
+/// [`focus::focus`].
+///
+/// [`Command`]: ../struct.Command.html
+/// [`focus::focus`]: ../focus/fn.focus.html
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Id(Internal);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Internal {
+    Unique(usize),
+    Custom(Cow<'static, str>),
+}
+
+impl Id {
+    /// Creates a custom [`Id`] with the given name.
+    ///
+    /// [`Id`]: struct.Id.html
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        Id(Internal::Custom(name.into()))
+    }
+
+    /// Creates a unique [`Id`].
+    ///
+    /// Every call produces a different [`Id`].
+    ///
+    /// [`Id`]: struct.Id.html
+    pub fn unique() -> Self {
+        Id(Internal::Unique(
+            NEXT_ID.fetch_add(1, atomic::Ordering::Relaxed),
+        ))
+    }
+}
@@ -0,0 +1,503 @@
+//! Show an application menu bar.
+//!
+//! A [`MenuBar`] has some local [`State`].
+//!
+//! [`MenuBar`]: struct.MenuBar.html
+//! [`State`]: struct.State.html
+use crate::context_menu::{self, Item, Label};
+use crate::{
+    keyboard, layout, mouse, overlay, Clipboard, Element, Event,
+    EventInteraction, Hasher, Layout, Length, Padding, Point, Rectangle, Size,
+    Widget,
+};
+
+use std::hash::Hash;
+
+/// A horizontal bar of menus, like the `File`, `Edit` and `View` menus of a
+/// desktop application.
+///
+/// Menus are opened by clicking their title or by pressing `Alt` and the
+/// mnemonic of their title. While a menu is open, hovering another title
+/// switches to its menu.
+///
+/// The [`Accelerator`] of every entry is dispatched as soon as its shortcut
+/// is pressed, even if its menu is closed, unless the focused widget uses the
+/// key, like a [`TextInput`] does with `Ctrl+A`.
+///
+/// ```
+/// # use iced_native::{context_menu, menu_bar, renderer::Null};
+/// # use iced_native::keyboard::{KeyCode, ModifiersState};
+/// #
+/// # pub type MenuBar<'a, Message> = iced_native::MenuBar<'a, Message, Null>;
+/// #
+/// use context_menu::{Accelerator, Item};
+/// use menu_bar::Menu;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Save,
+///     Quit,
+///     ToggleStatusBar,
+/// }
+///
+/// let ctrl = ModifiersState {
+///     control: true,
+///     ..ModifiersState::default()
+/// };
+///
+/// let mut state = menu_bar::State::new();
+///
+/// let menu_bar: MenuBar<'_, Message> = MenuBar::new(
+///     &mut state,
+///     vec![
+///         Menu::new(
+///             "&File",
+///             vec![
+///                 Item::new("&Save", Message::Save)
+///                     .accelerator(Accelerator::new(ctrl, KeyCode::S)),
+///                 Item::separator(),
+///                 Item::new("&Quit", Message::Quit),
+///             ],
+///         ),
+///         Menu::new(
+///             "&View",
+///             vec![Item::new("&Status bar", Message::ToggleStatusBar)
+///                 .checkbox(true)],
+///         ),
+///     ],
+/// );
+/// ```
+///
+/// [`Accelerator`]: ../context_menu/struct.Accelerator.html
+/// [`TextInput`]: ../text_input/struct.TextInput.html
+#[allow(missing_debug_implementations)]
+pub struct MenuBar<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    menus: Vec<Menu<Message>>,
+    width: Length,
+    padding: Padding,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style: <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer> MenuBar<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`MenuBar`] with some local [`State`] and the given
+    /// [`Menu`]s.
+    ///
+    /// [`MenuBar`]: struct.MenuBar.html
+    /// [`State`]: struct.State.html
+    /// [`Menu`]: struct.Menu.html
+    pub fn new(state: &'a mut State, menus: Vec<Menu<Message>>) -> Self {
+        MenuBar {
+            state,
+            menus,
+            width: Length::Fill,
+            padding: <Renderer as self::Renderer>::DEFAULT_PADDING,
+            text_size: None,
+            font: Default::default(),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the width of the [`MenuBar`].
+    ///
+    /// [`MenuBar`]: struct.MenuBar.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the padding of the titles and entries of the [`MenuBar`].
+    ///
+    /// [`MenuBar`]: struct.MenuBar.html
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`MenuBar`].
+    ///
+    /// [`MenuBar`]: struct.MenuBar.html
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the font of the [`MenuBar`].
+    ///
+    /// [`MenuBar`]: struct.MenuBar.html
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`MenuBar`].
+    ///
+    /// [`MenuBar`]: struct.MenuBar.html
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as self::Renderer>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn open(&mut self, index: usize, layout: Layout<'_>) {
+        if let Some(title) = layout.children().nth(index) {
+            self.state.selected = index;
+            self.state.menu.open(title.position() - layout.position());
+        }
+    }
+}
+
+/// A top-level menu of a [`MenuBar`].
+///
+/// [`MenuBar`]: struct.MenuBar.html
+#[derive(Debug, Clone)]
+pub struct Menu<Message> {
+    /// The title of the menu, displayed in the [`MenuBar`].
+    ///
+    /// [`MenuBar`]: struct.MenuBar.html
+    pub label: Label,
+
+    /// The items of the menu.
+    pub items: Vec<Item<Message>>,
+}
+
+impl<Message> Menu<Message> {
+    /// Creates a new [`Menu`] with the given title and items.
+    ///
+    /// An `&` in the title marks the next character as the mnemonic of the
+    /// [`Menu`].
+    ///
+    /// [`Menu`]: struct.Menu.html
+    pub fn new(label: impl Into<Label>, items: Vec<Item<Message>>) -> Self {
+        Menu {
+            label: label.into(),
+            items,
+        }
+    }
+}
+
+/// The local state of a [`MenuBar`].
+///
+/// [`MenuBar`]: struct.MenuBar.html
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct State {
+    selected: usize,
+    menu: context_menu::State,
+    show_mnemonics: bool,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns the index of the open [`Menu`], if any.
+    ///
+    /// [`Menu`]: struct.Menu.html
+    pub fn open_menu(&self) -> Option<usize> {
+        if self.menu.is_open() {
+            Some(self.selected)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for MenuBar<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let text_size = self.text_size.unwrap_or(renderer.default_size());
+        let horizontal_padding = f32::from(self.padding.horizontal());
+        let height = f32::from(text_size + self.padding.vertical());
+
+        let limits = limits.width(self.width).height(Length::Shrink);
+
+        let mut width = 0.0;
+
+        let titles = self
+            .menus
+            .iter()
+            .map(|menu| {
+                let (text_width, _) = renderer.measure(
+                    menu.label.text(),
+                    text_size,
+                    self.font,
+                    Size::INFINITY,
+                );
+
+                let mut title = layout::Node::new(Size::new(
+                    text_width + horizontal_padding,
+                    height,
+                ));
+                title.move_to(Point::new(width, 0.0));
+
+                width += text_width + horizontal_padding;
+
+                title
+            })
+            .collect();
+
+        let size = limits.resolve(Size::new(width, height));
+
+        layout::Node::with_children(size, titles)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _messages: &mut Vec<Message>,
+        _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) -> EventInteraction {
+        let hovered_title = layout
+            .children()
+            .position(|title| title.bounds().contains(cursor_position));
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                match hovered_title {
+                    Some(index) => {
+                        if self.state.open_menu() == Some(index) {
+                            self.state.menu.close();
+                        } else {
+                            self.open(index, layout);
+                        }
+
+                        EventInteraction { consumed: true }
+                    }
+                    None => EventInteraction::default(),
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                // Switch menus while one is open
+                match (self.state.open_menu(), hovered_title) {
+                    (Some(open), Some(index)) if open != index => {
+                        self.open(index, layout);
+                    }
+                    _ => {}
+                }
+
+                EventInteraction::default()
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                self.state.show_mnemonics = modifiers.alt;
+
+                EventInteraction::default()
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) => {
+                if !modifiers.alt || modifiers.control || modifiers.logo {
+                    return EventInteraction::default();
+                }
+
+                match self
+                    .menus
+                    .iter()
+                    .position(|menu| menu.label.matches(key_code))
+                {
+                    Some(index) => {
+                        self.open(index, layout);
+
+                        EventInteraction { consumed: true }
+                    }
+                    None => EventInteraction::default(),
+                }
+            }
+            _ => EventInteraction::default(),
+        }
+    }
+
+    fn on_shortcut(
+        &mut self,
+        key_code: keyboard::KeyCode,
+        modifiers: keyboard::ModifiersState,
+        messages: &mut Vec<Message>,
+    ) -> EventInteraction {
+        let accelerator = self
+            .menus
+            .iter()
+            .find_map(|menu| accelerated(&menu.items, key_code, modifiers));
+
+        match accelerator {
+            Some(message) => {
+                messages.push(message.clone());
+                self.state.menu.close();
+
+                EventInteraction { consumed: true }
+            }
+            None => EventInteraction::default(),
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self::Renderer::draw(
+            renderer,
+            layout,
+            cursor_position,
+            &self.menus,
+            self.state.open_menu(),
+            self.state.show_mnemonics,
+            self.padding,
+            self.text_size.unwrap_or(renderer.default_size()),
+            self.font,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.padding.hash(state);
+        self.text_size.hash(state);
+
+        for menu in &self.menus {
+            menu.label.hash(state);
+        }
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let index = self.state.open_menu()?;
+        let menu = self.menus.get(index)?;
+        let title = layout.children().nth(index)?.bounds();
+        let bounds = layout.bounds();
+
+        Some(overlay::Element::new(
+            layout.position(),
+            Box::new(context_menu::Overlay {
+                state: &mut self.state.menu,
+                items: &menu.items,
+                target: Rectangle {
+                    x: title.x - bounds.x,
+                    y: title.y - bounds.y,
+                    ..title
+                },
+                passthrough: Some(Rectangle::with_size(bounds.size())),
+                padding: self.padding,
+                text_size: self.text_size,
+                font: self.font,
+                style: Renderer::menu_style(&self.style),
+            }),
+        ))
+    }
+}
+
+/// Returns the message of the enabled entry with the given shortcut, if any.
+fn accelerated<Message>(
+    items: &[Item<Message>],
+    key_code: keyboard::KeyCode,
+    modifiers: keyboard::ModifiersState,
+) -> Option<&Message> {
+    items.iter().find_map(|item| match item {
+        Item::Entry {
+            on_select: Some(on_select),
+            accelerator: Some(accelerator),
+            ..
+        } if accelerator.matches(key_code, modifiers) => Some(on_select),
+        Item::Submenu { items, .. } => accelerated(items, key_code, modifiers),
+        _ => None,
+    })
+}
+
+/// The renderer of a [`MenuBar`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`MenuBar`] in your user interface.
+///
+/// [`MenuBar`]: struct.MenuBar.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: context_menu::Renderer {
+    /// The default padding of a [`MenuBar`].
+    ///
+    /// [`MenuBar`]: struct.MenuBar.html
+    const DEFAULT_PADDING: Padding;
+
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Returns the style of the menus opened from a [`MenuBar`].
+    ///
+    /// [`MenuBar`]: struct.MenuBar.html
+    fn menu_style(
+        style: &<Self as Renderer>::Style,
+    ) -> <Self as context_menu::Renderer>::Style;
+
+    /// Draws a [`MenuBar`].
+    ///
+    /// It receives:
+    ///   * the [`Layout`] of the [`MenuBar`], with a child per [`Menu`]
+    ///   * the cursor position
+    ///   * the [`Menu`]s of the [`MenuBar`] and the index of the open one
+    ///   * whether the mnemonics of the titles should be shown
+    ///   * the padding, text size and font of the titles
+    ///   * the style of the [`MenuBar`]
+    ///
+    /// [`MenuBar`]: struct.MenuBar.html
+    /// [`Menu`]: struct.Menu.html
+    /// [`Layout`]: ../../struct.Layout.html
+    fn draw<Message>(
+        &mut self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        menus: &[Menu<Message>],
+        open_menu: Option<usize>,
+        show_mnemonics: bool,
+        padding: Padding,
+        text_size: u16,
+        font: Self::Font,
+        style: &<Self as Renderer>::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<MenuBar<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a + Clone,
+{
+    fn from(
+        menu_bar: MenuBar<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(menu_bar)
+    }
+}
@@ -0,0 +1,438 @@
+//! Display a dialog on top of other widgets, blocking any interaction with
+//! them.
+use crate::{
+    focus, keyboard, layout, mouse, overlay, Clipboard, Element, Event,
+    EventInteraction, Hasher, Id, Layout, Length, Point, Rectangle, Size,
+    Widget,
+};
+
+use std::any::Any;
+use std::hash::Hash;
+use std::time::Instant;
+
+/// An element that displays some content centered on top of an underlay,
+/// dimming it and blocking any interaction with it.
+///
+/// A [`Modal`] only blocks its underlay. Any widget outside of it still
+/// receives keyboard events and takes part in focus traversal while the
+/// [`Modal`] is open. Therefore, the underlay should be the whole view of
+/// your application, as in the example below.
+///
+/// ```
+/// # use iced_native::{renderer::Null, Text};
+/// #
+/// # pub type Modal<'a, Message> = iced_native::Modal<'a, Message, Null>;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     CloseDialog,
+/// }
+///
+/// let modal: Modal<'_, Message> = Modal::new(
+///     true,
+///     Text::new("Application content"),
+///     Text::new("Are you sure?"),
+/// )
+/// .on_close(Message::CloseDialog);
+/// ```
+///
+/// [`Modal`]: struct.Modal.html
+#[allow(missing_debug_implementations)]
+pub struct Modal<'a, Message, Renderer: self::Renderer> {
+    is_open: bool,
+    underlay: Element<'a, Message, Renderer>,
+    content: Element<'a, Message, Renderer>,
+    on_close: Option<Message>,
+    style: <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer> Modal<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`Modal`] displaying the given content on top of the
+    /// underlay when open.
+    ///
+    /// [`Modal`]: struct.Modal.html
+    pub fn new(
+        is_open: bool,
+        underlay: impl Into<Element<'a, Message, Renderer>>,
+        content: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        Modal {
+            is_open,
+            underlay: underlay.into(),
+            content: content.into(),
+            on_close: None,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the message that will be produced when the [`Modal`] is
+    /// dismissed, either by pressing `Escape` or by clicking the backdrop.
+    ///
+    /// [`Modal`]: struct.Modal.html
+    pub fn on_close(mut self, message: Message) -> Self {
+        self.on_close = Some(message);
+        self
+    }
+
+    /// Sets the style of the [`Modal`].
+    ///
+    /// [`Modal`]: struct.Modal.html
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as self::Renderer>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Modal<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.underlay.width()
+    }
+
+    fn height(&self) -> Length {
+        self.underlay.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.underlay.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> EventInteraction {
+        if self.is_open {
+            match event {
+                // The content of the modal handles user input
+                Event::Mouse(_) | Event::Keyboard(_) => {
+                    return EventInteraction::default();
+                }
+                _ => {}
+            }
+        }
+
+        self.underlay.widget.on_event(
+            event,
+            layout,
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self.underlay
+            .draw(renderer, defaults, layout, cursor_position)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.underlay.hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        if !self.is_open {
+            return self.underlay.overlay(layout);
+        }
+
+        Some(overlay::Element::new(
+            Point::ORIGIN,
+            Box::new(Overlay {
+                content: &mut self.content,
+                on_close: self.on_close.clone(),
+                style: &self.style,
+            }),
+        ))
+    }
+
+    fn on_shortcut(
+        &mut self,
+        key_code: keyboard::KeyCode,
+        modifiers: keyboard::ModifiersState,
+        messages: &mut Vec<Message>,
+    ) -> EventInteraction {
+        // The underlay is blocked while the modal is open
+        if self.is_open {
+            return EventInteraction::default();
+        }
+
+        self.underlay
+            .widget
+            .on_shortcut(key_code, modifiers, messages)
+    }
+
+    fn focusables<'b>(
+        &'b mut self,
+        focusables: &mut Vec<&'b mut dyn focus::Focusable>,
+    ) {
+        // Keep the focus trapped inside the modal while it is open
+        if self.is_open {
+            self.content.focusables(focusables);
+        } else {
+            self.underlay.focusables(focusables);
+        }
+    }
+
+    fn targets<'b>(&'b mut self, targets: &mut Vec<(&'b Id, &'b mut dyn Any)>) {
+        self.underlay.targets(targets);
+
+        if self.is_open {
+            self.content.targets(targets);
+        }
+    }
+
+    fn redraw_request(&self) -> Option<Instant> {
+        // The content is displayed, and redrawn, by the overlay
+        self.underlay.redraw_request()
+    }
+}
+
+struct Overlay<'a, 'b, Message, Renderer: self::Renderer> {
+    content: &'b mut Element<'a, Message, Renderer>,
+    on_close: Option<Message>,
+    style: &'b <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, 'b, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Overlay<'a, 'b, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: self::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        _position: Point,
+    ) -> layout::Node {
+        let limits = layout::Limits::new(Size::ZERO, bounds);
+
+        let mut content = self.content.layout(renderer, &limits);
+        let size = content.size();
+
+        content.move_to(Point::new(
+            ((bounds.width - size.width) / 2.0).max(0.0),
+            ((bounds.height - size.height) / 2.0).max(0.0),
+        ));
+
+        layout::Node::with_children(bounds, vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> EventInteraction {
+        let content_layout = layout.children().next().unwrap();
+
+        let interaction = self.content.widget.on_event(
+            event.clone(),
+            content_layout,
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        );
+
+        if interaction.consumed {
+            return interaction;
+        }
+
+        let is_dismissed = match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Escape,
+                ..
+            }) => true,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                layout.bounds().contains(cursor_position)
+                    && !content_layout.bounds().contains(cursor_position)
+            }
+            _ => false,
+        };
+
+        match self.on_close.clone() {
+            Some(on_close) if is_dismissed => {
+                messages.push(on_close);
+
+                EventInteraction { consumed: true }
+            }
+            _ => interaction,
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self::Renderer::draw(
+            renderer,
+            defaults,
+            layout.bounds(),
+            cursor_position,
+            self.style,
+            self.content,
+            layout.children().next().unwrap(),
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher, _position: Point) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content.overlay(layout.children().next().unwrap())
+    }
+
+    fn redraw_request(&self) -> Option<Instant> {
+        self.content.redraw_request()
+    }
+}
+
+/// The renderer of a [`Modal`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Modal`] in your user interface.
+///
+/// [`Modal`]: struct.Modal.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws the dialog of a [`Modal`].
+    ///
+    /// It receives:
+    ///   * the bounds of the backdrop, covering the whole viewport
+    ///   * the cursor position
+    ///   * the style of the [`Modal`]
+    ///   * the content of the dialog and its [`Layout`]
+    ///
+    /// [`Modal`]: struct.Modal.html
+    /// [`Layout`]: ../../struct.Layout.html
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        bounds: Rectangle,
+        cursor_position: Point,
+        style: &<Self as Renderer>::Style,
+        content: &Element<'_, Message, Self>,
+        content_layout: Layout<'_>,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Modal<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a + Clone,
+{
+    fn from(
+        modal: Modal<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(modal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{renderer::Null, Cache, Checkbox, Text, UserInterface};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Toggled(bool),
+        Close,
+    }
+
+    fn update(is_open: bool, event: Event) -> Vec<Message> {
+        let mut renderer = Null;
+
+        let modal: Modal<'_, Message, Null> = Modal::new(
+            is_open,
+            Checkbox::new(false, "Underlay", Message::Toggled),
+            Text::new("Dialog"),
+        )
+        .on_close(Message::Close);
+
+        let mut user_interface = UserInterface::build(
+            modal,
+            Size::new(500.0, 500.0),
+            Cache::new(),
+            &mut renderer,
+        );
+
+        // The checkbox of the underlay is at the top left corner
+        let (messages, _) = user_interface.update(
+            &[event],
+            Point::new(5.0, 5.0),
+            None,
+            &renderer,
+        );
+
+        messages
+    }
+
+    #[test]
+    fn blocks_clicks_outside_of_the_dialog() {
+        let click =
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left));
+
+        assert_eq!(update(false, click.clone()), vec![Message::Toggled(true)]);
+        assert_eq!(update(true, click), vec![Message::Close]);
+    }
+
+    #[test]
+    fn closes_when_pressing_escape() {
+        let escape = Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code: keyboard::KeyCode::Escape,
+            modifiers: keyboard::ModifiersState::default(),
+        });
+
+        assert_eq!(update(false, escape.clone()), vec![]);
+        assert_eq!(update(true, escape), vec![Message::Close]);
+    }
+}
@@ -26,14 +26,19 @@ pub use node::Node;
 pub use pane::Pane;
 pub use split::Split;
 pub use state::{Focus, State};
+
+pub(crate) use state::Internal;
 pub use title_bar::TitleBar;
 
 use crate::{
-    container, keyboard, layout, mouse, overlay, row, text, Clipboard, Element,
-    Event, EventInteraction, Hasher, Layout, Length, Point, Rectangle, Size,
-    Vector, Widget,
+    command, container, focus, keyboard, layout, mouse, overlay, row, text,
+    Clipboard, Command, Element, Event, EventInteraction, Hasher, Id, Layout,
+    Length, Point, Rectangle, Size, Vector, Widget,
 };
 
+use std::any::Any;
+use std::time::Instant;
+
 /// A collection of panes distributed using either vertical or horizontal splits
 /// to completely fill the space available.
 ///
@@ -88,6 +93,7 @@ use crate::{
 /// [`State`]: struct.State.html
 #[allow(missing_debug_implementations)]
 pub struct PaneGrid<'a, Message, Renderer: self::Renderer> {
+    id: Option<Id>,
     state: &'a mut state::Internal,
     elements: Vec<(Pane, Content<'a, Message, Renderer>)>,
     width: Length,
@@ -142,6 +148,7 @@ where
         };
 
         Self {
+            id: None,
             state: &mut state.internal,
             elements,
             width: Length::Fill,
@@ -157,6 +164,19 @@ where
         }
     }
 
+    /// Sets the [`Id`] of the [`PaneGrid`].
+    ///
+    /// It allows you to focus its panes with a [`Command`]. See [`focus`].
+    ///
+    /// [`Id`]: ../struct.Id.html
+    /// [`PaneGrid`]: struct.PaneGrid.html
+    /// [`Command`]: ../../struct.Command.html
+    /// [`focus`]: fn.focus.html
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Sets the width of the [`PaneGrid`].
     ///
     /// [`PaneGrid`]: struct.PaneGrid.html
@@ -651,11 +671,55 @@ where
         &mut self,
         layout: Layout<'_>,
     ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        overlay::Group::with_children(
+            self.elements
+                .iter_mut()
+                .zip(layout.children())
+                .filter_map(|((_, pane), layout)| pane.overlay(layout))
+                .collect(),
+        )
+        .overlay()
+    }
+
+    fn on_shortcut(
+        &mut self,
+        key_code: keyboard::KeyCode,
+        modifiers: keyboard::ModifiersState,
+        messages: &mut Vec<Message>,
+    ) -> EventInteraction {
+        self.elements.iter_mut().fold(
+            EventInteraction::default(),
+            |interaction, (_, pane)| {
+                pane.on_shortcut(key_code, modifiers, messages)
+                    .union(&interaction)
+            },
+        )
+    }
+
+    fn focusables<'b>(
+        &'b mut self,
+        focusables: &mut Vec<&'b mut dyn focus::Focusable>,
+    ) {
+        for (_, pane) in &mut self.elements {
+            pane.focusables(focusables);
+        }
+    }
+
+    fn targets<'b>(&'b mut self, targets: &mut Vec<(&'b Id, &'b mut dyn Any)>) {
+        if let Some(id) = &self.id {
+            targets.push((id, &mut *self.state));
+        }
+
+        for (_, pane) in &mut self.elements {
+            pane.targets(targets);
+        }
+    }
+
+    fn redraw_request(&self) -> Option<Instant> {
         self.elements
-            .iter_mut()
-            .zip(layout.children())
-            .filter_map(|((_, pane), layout)| pane.overlay(layout))
-            .next()
+            .iter()
+            .filter_map(|(_, pane)| pane.redraw_request())
+            .min()
     }
 }
 
@@ -749,6 +813,36 @@ where
     }
 }
 
+/// Produces a [`Command`] that focuses a [`Pane`] of the [`PaneGrid`] with
+/// the given [`Id`].
+///
+/// It is equivalent to [`State::focus`], but it does not need access to the
+/// [`State`].
+///
+/// [`Command`]: ../../struct.Command.html
+/// [`Pane`]: struct.Pane.html
+/// [`PaneGrid`]: struct.PaneGrid.html
+/// [`Id`]: ../struct.Id.html
+/// [`State::focus`]: struct.State.html#method.focus
+/// [`State`]: struct.State.html
+pub fn focus<T>(id: Id, pane: Pane) -> Command<T> {
+    Command::single(command::Action::Focus(focus::Action::FocusPane(id, pane)))
+}
+
+/// Produces a [`Command`] that unfocuses the focused [`Pane`] of the
+/// [`PaneGrid`] with the given [`Id`].
+///
+/// It is equivalent to [`State::unfocus`].
+///
+/// [`Command`]: ../../struct.Command.html
+/// [`Pane`]: struct.Pane.html
+/// [`PaneGrid`]: struct.PaneGrid.html
+/// [`Id`]: ../struct.Id.html
+/// [`State::unfocus`]: struct.State.html#method.unfocus
+pub fn unfocus<T>(id: Id) -> Command<T> {
+    Command::single(command::Action::Focus(focus::Action::UnfocusPane(id)))
+}
+
 /*
  * Helpers
  */
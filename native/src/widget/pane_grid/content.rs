@@ -1,11 +1,17 @@
 use crate::container;
+use crate::focus;
+use crate::keyboard;
 use crate::layout;
 use crate::overlay;
 use crate::pane_grid::{self, TitleBar};
 use crate::{
-    Clipboard, Element, Event, EventInteraction, Hasher, Layout, Point, Size,
+    Clipboard, Element, Event, EventInteraction, Hasher, Id, Layout, Point,
+    Size,
 };
 
+use std::any::Any;
+use std::time::Instant;
+
 /// The content of a [`Pane`].
 ///
 /// [`Pane`]: struct.Pane.html
@@ -210,6 +216,59 @@ where
 
         self.body.overlay(body_layout)
     }
+
+    pub(crate) fn on_shortcut(
+        &mut self,
+        key_code: keyboard::KeyCode,
+        modifiers: keyboard::ModifiersState,
+        messages: &mut Vec<Message>,
+    ) -> EventInteraction {
+        let interaction = match &mut self.title_bar {
+            Some(title_bar) => {
+                title_bar.on_shortcut(key_code, modifiers, messages)
+            }
+            None => EventInteraction::default(),
+        };
+
+        self.body
+            .widget
+            .on_shortcut(key_code, modifiers, messages)
+            .union(&interaction)
+    }
+
+    pub(crate) fn focusables<'b>(
+        &'b mut self,
+        focusables: &mut Vec<&'b mut dyn focus::Focusable>,
+    ) {
+        if let Some(title_bar) = &mut self.title_bar {
+            title_bar.focusables(focusables);
+        }
+
+        self.body.focusables(focusables);
+    }
+
+    pub(crate) fn targets<'b>(
+        &'b mut self,
+        targets: &mut Vec<(&'b Id, &'b mut dyn Any)>,
+    ) {
+        if let Some(title_bar) = &mut self.title_bar {
+            title_bar.targets(targets);
+        }
+
+        self.body.targets(targets);
+    }
+
+    pub(crate) fn redraw_request(&self) -> Option<Instant> {
+        let title_bar = self
+            .title_bar
+            .as_ref()
+            .and_then(|title_bar| title_bar.redraw_request());
+
+        match (title_bar, self.body.redraw_request()) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        }
+    }
 }
 
 impl<'a, T, Message, Renderer> From<T> for Content<'a, Message, Renderer>
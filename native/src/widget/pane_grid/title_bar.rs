@@ -1,9 +1,15 @@
+use crate::focus;
+use crate::keyboard;
 use crate::layout;
 use crate::pane_grid;
 use crate::{
-    Clipboard, Element, Event, EventInteraction, Layout, Point, Rectangle, Size,
+    Clipboard, Element, Event, EventInteraction, Id, Layout, Padding, Point,
+    Rectangle, Size,
 };
 
+use std::any::Any;
+use std::time::Instant;
+
 /// The title bar of a [`Pane`].
 ///
 /// [`Pane`]: struct.Pane.html
@@ -12,7 +18,7 @@ pub struct TitleBar<'a, Message, Renderer: pane_grid::Renderer> {
     title: String,
     title_size: Option<u16>,
     controls: Option<Element<'a, Message, Renderer>>,
-    padding: u16,
+    padding: Padding,
     always_show_controls: bool,
     style: Renderer::Style,
 }
@@ -29,7 +35,7 @@ where
             title: title.into(),
             title_size: None,
             controls: None,
-            padding: 0,
+            padding: Padding::ZERO,
             always_show_controls: false,
             style: Renderer::Style::default(),
         }
@@ -57,8 +63,8 @@ where
     /// Sets the padding of the [`TitleBar`].
     ///
     /// [`TitleBar`]: struct.TitleBar.html
-    pub fn padding(mut self, units: u16) -> Self {
-        self.padding = units;
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
         self
     }
 
@@ -183,7 +189,7 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        let padding = f32::from(self.padding);
+        let padding = self.padding;
         let limits = limits.pad(padding);
         let max_size = limits.max();
 
@@ -224,7 +230,10 @@ where
             layout::Node::new(Size::new(max_size.width, title_height))
         };
 
-        node.move_to(Point::new(padding, padding));
+        node.move_to(Point::new(
+            f32::from(padding.left),
+            f32::from(padding.top),
+        ));
 
         layout::Node::with_children(node.size().pad(padding), vec![node])
     }
@@ -258,4 +267,44 @@ where
             EventInteraction::default()
         }
     }
+
+    pub(crate) fn on_shortcut(
+        &mut self,
+        key_code: keyboard::KeyCode,
+        modifiers: keyboard::ModifiersState,
+        messages: &mut Vec<Message>,
+    ) -> EventInteraction {
+        match &mut self.controls {
+            Some(controls) => {
+                controls.widget.on_shortcut(key_code, modifiers, messages)
+            }
+            None => EventInteraction::default(),
+        }
+    }
+
+    pub(crate) fn focusables<'b>(
+        &'b mut self,
+        focusables: &mut Vec<&'b mut dyn focus::Focusable>,
+    ) {
+        if let Some(controls) = &mut self.controls {
+            controls.focusables(focusables);
+        }
+    }
+
+    pub(crate) fn targets<'b>(
+        &'b mut self,
+        targets: &mut Vec<(&'b Id, &'b mut dyn Any)>,
+    ) {
+        if let Some(controls) = &mut self.controls {
+            controls.targets(targets);
+        }
+    }
+
+    pub(crate) fn redraw_request(&self) -> Option<Instant> {
+        // The title is plain text drawn by the renderer, only the controls
+        // are widgets that can be animated
+        self.controls
+            .as_ref()
+            .and_then(|controls| controls.redraw_request())
+    }
 }
@@ -1,9 +1,9 @@
 //! Display a dropdown list of selectable values.
 use crate::{
-    layout, mouse, overlay,
+    focus, keyboard, layout, mouse, overlay,
     overlay::menu::{self, Menu},
-    scrollable, text, Clipboard, Element, Event, EventInteraction, Hasher,
-    Layout, Length, Point, Rectangle, Size, Widget,
+    scrollable, text, Clipboard, Element, Event, EventInteraction, Hasher, Id,
+    Layout, Length, Padding, Point, Rectangle, Size, Widget,
 };
 use std::borrow::Cow;
 
@@ -13,15 +13,17 @@ pub struct PickList<'a, T, Message, Renderer: self::Renderer>
 where
     [T]: ToOwned<Owned = Vec<T>>,
 {
+    id: Option<Id>,
     menu: &'a mut menu::State,
     is_open: &'a mut bool,
+    is_focused: &'a mut bool,
     hovered_option: &'a mut Option<usize>,
     last_selection: &'a mut Option<T>,
     on_selected: Box<dyn Fn(T) -> Message>,
     options: Cow<'a, [T]>,
     selected: Option<T>,
     width: Length,
-    padding: u16,
+    padding: Padding,
     text_size: Option<u16>,
     font: Renderer::Font,
     style: <Renderer as self::Renderer>::Style,
@@ -34,6 +36,7 @@ where
 pub struct State<T> {
     menu: menu::State,
     is_open: bool,
+    is_focused: bool,
     hovered_option: Option<usize>,
     last_selection: Option<T>,
 }
@@ -43,6 +46,7 @@ impl<T> Default for State<T> {
         Self {
             menu: menu::State::default(),
             is_open: bool::default(),
+            is_focused: bool::default(),
             hovered_option: Option::default(),
             last_selection: Option::default(),
         }
@@ -70,13 +74,16 @@ where
         let State {
             menu,
             is_open,
+            is_focused,
             hovered_option,
             last_selection,
         } = state;
 
         Self {
+            id: None,
             menu,
             is_open,
+            is_focused,
             hovered_option,
             last_selection,
             on_selected: Box::new(on_selected),
@@ -90,6 +97,20 @@ where
         }
     }
 
+    /// Sets the [`Id`] of the [`PickList`].
+    ///
+    /// It allows you to focus the [`PickList`] with a [`Command`]. See
+    /// [`focus::focus`].
+    ///
+    /// [`Id`]: ../struct.Id.html
+    /// [`PickList`]: struct.PickList.html
+    /// [`Command`]: ../../struct.Command.html
+    /// [`focus::focus`]: ../../focus/fn.focus.html
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Sets the width of the [`PickList`].
     ///
     /// [`PickList`]: struct.PickList.html
@@ -101,8 +122,8 @@ where
     /// Sets the padding of the [`PickList`].
     ///
     /// [`PickList`]: struct.PickList.html
-    pub fn padding(mut self, padding: u16) -> Self {
-        self.padding = padding;
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
         self
     }
 
@@ -160,7 +181,7 @@ where
         let limits = limits
             .width(self.width)
             .height(Length::Shrink)
-            .pad(f32::from(self.padding));
+            .pad(self.padding);
 
         let text_size = self.text_size.unwrap_or(renderer.default_size());
 
@@ -189,11 +210,11 @@ where
             let intrinsic = Size::new(
                 max_width as f32
                     + f32::from(text_size)
-                    + f32::from(self.padding),
+                    + f32::from(self.padding.left),
                 f32::from(text_size),
             );
 
-            limits.resolve(intrinsic).pad(f32::from(self.padding))
+            limits.resolve(intrinsic).pad(self.padding)
         };
 
         layout::Node::new(size)
@@ -227,6 +248,8 @@ where
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
                 let mut consumed = false;
+                *self.is_focused = false;
+
                 if *self.is_open {
                     // TODO: Encode cursor availability in the type system
                     *self.is_open =
@@ -250,6 +273,87 @@ where
 
                 EventInteraction { consumed }
             }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) if *self.is_focused => {
+                let consumed = if *self.is_open {
+                    match key_code {
+                        keyboard::KeyCode::Up => {
+                            *self.hovered_option = Some(
+                                self.hovered_option
+                                    .map(|index| index.saturating_sub(1))
+                                    .unwrap_or(0),
+                            );
+
+                            true
+                        }
+                        keyboard::KeyCode::Down => {
+                            let last = self.options.len().saturating_sub(1);
+
+                            *self.hovered_option = Some(
+                                self.hovered_option
+                                    .map(|index| (index + 1).min(last))
+                                    .unwrap_or(0),
+                            );
+
+                            true
+                        }
+                        keyboard::KeyCode::Enter | keyboard::KeyCode::Space => {
+                            if let Some(option) = self
+                                .hovered_option
+                                .and_then(|index| self.options.get(index))
+                            {
+                                messages
+                                    .push((self.on_selected)(option.clone()));
+                            }
+
+                            *self.is_open = false;
+
+                            true
+                        }
+                        keyboard::KeyCode::Escape => {
+                            *self.is_open = false;
+
+                            true
+                        }
+                        _ => false,
+                    }
+                } else {
+                    let selected = self.options.iter().position(|option| {
+                        Some(option) == self.selected.as_ref()
+                    });
+
+                    match key_code {
+                        keyboard::KeyCode::Up | keyboard::KeyCode::Down => {
+                            let next = match (key_code, selected) {
+                                (keyboard::KeyCode::Up, Some(index)) => {
+                                    index.checked_sub(1)
+                                }
+                                (_, Some(index)) => Some(index + 1),
+                                (_, None) => Some(0),
+                            };
+
+                            if let Some(option) =
+                                next.and_then(|index| self.options.get(index))
+                            {
+                                messages
+                                    .push((self.on_selected)(option.clone()));
+                            }
+
+                            true
+                        }
+                        keyboard::KeyCode::Enter | keyboard::KeyCode::Space => {
+                            *self.is_open = true;
+                            *self.hovered_option = selected;
+
+                            true
+                        }
+                        _ => false,
+                    }
+                };
+
+                EventInteraction { consumed }
+            }
             _ => EventInteraction::default(),
         }
     }
@@ -266,6 +370,7 @@ where
             layout.bounds(),
             cursor_position,
             self.selected.as_ref().map(ToString::to_string),
+            *self.is_focused,
             self.padding,
             self.text_size.unwrap_or(renderer.default_size()),
             self.font,
@@ -300,6 +405,37 @@ where
             None
         }
     }
+
+    fn focusables<'b>(
+        &'b mut self,
+        focusables: &mut Vec<&'b mut dyn focus::Focusable>,
+    ) {
+        focusables.push(self);
+    }
+}
+
+impl<'a, T, Message, Renderer> focus::Focusable
+    for PickList<'a, T, Message, Renderer>
+where
+    [T]: ToOwned<Owned = Vec<T>>,
+    Renderer: self::Renderer,
+{
+    fn is_focused(&self) -> bool {
+        *self.is_focused
+    }
+
+    fn focus(&mut self) {
+        *self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        *self.is_focused = false;
+        *self.is_open = false;
+    }
+
+    fn id(&self) -> Option<&Id> {
+        self.id.as_ref()
+    }
 }
 
 /// The renderer of a [`PickList`].
@@ -313,7 +449,7 @@ pub trait Renderer: text::Renderer + menu::Renderer {
     /// The default padding of a [`PickList`].
     ///
     /// [`PickList`]: struct.PickList.html
-    const DEFAULT_PADDING: u16;
+    const DEFAULT_PADDING: Padding;
 
     /// The [`PickList`] style supported by this renderer.
     ///
@@ -336,7 +472,8 @@ pub trait Renderer: text::Renderer + menu::Renderer {
         bounds: Rectangle,
         cursor_position: Point,
         selected: Option<String>,
-        padding: u16,
+        is_focused: bool,
+        padding: Padding,
         text_size: u16,
         font: Self::Font,
         style: &<Self as Renderer>::Style,
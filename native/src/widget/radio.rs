@@ -1,18 +1,20 @@
 //! Create choices using radio buttons.
+use crate::animation::{Animated, Frame, Transition};
 use crate::{
-    layout, mouse, row, text, Align, Clipboard, Element, Event,
-    EventInteraction, Hasher, HorizontalAlignment, Layout, Length, Point,
-    Rectangle, Row, Text, VerticalAlignment, Widget,
+    focus, keyboard, layout, mouse, row, text, Align, Clipboard, Element,
+    Event, EventInteraction, Hasher, HorizontalAlignment, Id, Layout, Length,
+    Point, Rectangle, Row, Text, VerticalAlignment, Widget,
 };
 
 use std::hash::Hash;
+use std::time::Instant;
 
 /// A circular button representing a choice.
 ///
 /// # Example
 /// ```
-/// # type Radio<Message> =
-/// #     iced_native::Radio<Message, iced_native::renderer::Null>;
+/// # type Radio<'a, Message> =
+/// #     iced_native::Radio<'a, Message, iced_native::renderer::Null>;
 /// #
 /// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 /// pub enum Choice {
@@ -34,19 +36,23 @@ use std::hash::Hash;
 ///
 /// ![Radio buttons drawn by `iced_wgpu`](https://github.com/hecrj/iced/blob/7760618fb112074bc40b148944521f312152012a/docs/images/radio.png?raw=true)
 #[allow(missing_debug_implementations)]
-pub struct Radio<Message, Renderer: self::Renderer + text::Renderer> {
+pub struct Radio<'a, Message, Renderer: self::Renderer + text::Renderer> {
+    state: Option<&'a mut State>,
+    id: Option<Id>,
     is_selected: bool,
+    is_focused: bool,
     on_click: Message,
     label: String,
     width: Length,
     size: u16,
     spacing: u16,
     text_size: Option<u16>,
+    transition: Transition,
     style: Renderer::Style,
 }
 
-impl<Message, Renderer: self::Renderer + text::Renderer>
-    Radio<Message, Renderer>
+impl<'a, Message, Renderer: self::Renderer + text::Renderer>
+    Radio<'a, Message, Renderer>
 {
     /// Creates a new [`Radio`] button.
     ///
@@ -69,17 +75,35 @@ impl<Message, Renderer: self::Renderer + text::Renderer>
         F: 'static + Fn(V) -> Message,
     {
         Radio {
+            state: None,
+            id: None,
             is_selected: Some(value) == selected,
+            is_focused: false,
             on_click: f(value),
             label: label.into(),
             width: Length::Shrink,
             size: <Renderer as self::Renderer>::DEFAULT_SIZE,
             spacing: Renderer::DEFAULT_SPACING, //15
             text_size: None,
+            transition: Transition::default(),
             style: Renderer::Style::default(),
         }
     }
 
+    /// Sets the [`Id`] of the [`Radio`].
+    ///
+    /// It allows you to focus the [`Radio`] with a [`Command`]. See
+    /// [`focus::focus`].
+    ///
+    /// [`Id`]: ../struct.Id.html
+    /// [`Radio`]: struct.Radio.html
+    /// [`Command`]: ../../struct.Command.html
+    /// [`focus::focus`]: ../../focus/fn.focus.html
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Sets the size of the [`Radio`] button.
     ///
     /// [`Radio`]: struct.Radio.html
@@ -119,9 +143,80 @@ impl<Message, Renderer: self::Renderer + text::Renderer>
         self.style = style.into();
         self
     }
+
+    /// Sets the [`Transition`] used to blend the styles of the [`Radio`]
+    /// button when its [`Status`] changes.
+    ///
+    /// The animation is kept in the given [`State`]. By default, styles
+    /// change instantly.
+    ///
+    /// [`Transition`]: ../../animation/struct.Transition.html
+    /// [`Radio`]: struct.Radio.html
+    /// [`Status`]: enum.Status.html
+    /// [`State`]: struct.State.html
+    pub fn transition(
+        mut self,
+        state: &'a mut State,
+        transition: Transition,
+    ) -> Self {
+        self.state = Some(state);
+        self.transition = transition;
+        self
+    }
+
+    fn status(&self, bounds: Rectangle, cursor_position: Point) -> Status {
+        if bounds.contains(cursor_position) {
+            Status::Hovered
+        } else if self.is_focused {
+            Status::Focused
+        } else {
+            Status::Active
+        }
+    }
 }
 
-impl<Message, Renderer> Widget<Message, Renderer> for Radio<Message, Renderer>
+/// The local state of a [`Radio`] button with a [`Transition`].
+///
+/// [`Radio`]: struct.Radio.html
+/// [`Transition`]: ../../animation/struct.Transition.html
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    status: Animated<Status>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+}
+
+/// The possible statuses of a [`Radio`] button.
+///
+/// [`Radio`]: struct.Radio.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Status {
+    /// The [`Radio`] button is not being hovered nor focused.
+    ///
+    /// [`Radio`]: struct.Radio.html
+    #[default]
+    Active,
+
+    /// The [`Radio`] button is being hovered.
+    ///
+    /// [`Radio`]: struct.Radio.html
+    Hovered,
+
+    /// The [`Radio`] button is focused and it is not being hovered.
+    ///
+    /// [`Radio`]: struct.Radio.html
+    Focused,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Radio<'a, Message, Renderer>
 where
     Renderer: self::Renderer + text::Renderer + row::Renderer,
     Message: Clone,
@@ -165,8 +260,10 @@ where
         _renderer: &Renderer,
         _clipboard: Option<&dyn Clipboard>,
     ) -> EventInteraction {
-        match event {
+        let interaction = match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                self.is_focused = false;
+
                 if layout.bounds().contains(cursor_position) {
                     messages.push(self.on_click.clone());
                     EventInteraction { consumed: true }
@@ -174,8 +271,29 @@ where
                     EventInteraction::default()
                 }
             }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Space,
+                ..
+            })
+            | Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Enter,
+                ..
+            }) if self.is_focused => {
+                messages.push(self.on_click.clone());
+                EventInteraction { consumed: true }
+            }
             _ => EventInteraction::default(),
+        };
+
+        let status = self.status(layout.bounds(), cursor_position);
+
+        if let Some(state) = &mut self.state {
+            state
+                .status
+                .transition(status, self.transition, Instant::now());
         }
+
+        interaction
     }
 
     fn draw(
@@ -205,12 +323,22 @@ where
         );
 
         let is_mouse_over = bounds.contains(cursor_position);
+        let status = self.status(bounds, cursor_position);
+
+        // The status may have changed without any events, like when focusing
+        let status = match &self.state {
+            Some(state) if *state.status.target() == status => {
+                state.status.at(Instant::now())
+            }
+            _ => Frame::settled(status),
+        };
 
         self::Renderer::draw(
             renderer,
             radio_bounds,
             self.is_selected,
             is_mouse_over,
+            status,
             label,
             &self.style,
         )
@@ -222,6 +350,44 @@ where
 
         self.label.hash(state);
     }
+
+    fn focusables<'b>(
+        &'b mut self,
+        focusables: &mut Vec<&'b mut dyn focus::Focusable>,
+    ) {
+        focusables.push(self);
+    }
+
+    fn redraw_request(&self) -> Option<Instant> {
+        self.state
+            .as_ref()
+            .and_then(|state| state.status.redraw_request(Instant::now()))
+    }
+}
+
+impl<'a, Message, Renderer> focus::Focusable for Radio<'a, Message, Renderer>
+where
+    Renderer: self::Renderer + text::Renderer,
+{
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+
+    fn id(&self) -> Option<&Id> {
+        self.id.as_ref()
+    }
+
+    fn is_stateful(&self) -> bool {
+        false
+    }
 }
 
 /// The renderer of a [`Radio`] button.
@@ -251,26 +417,33 @@ pub trait Renderer: crate::Renderer {
     ///   * the bounds of the [`Radio`]
     ///   * whether the [`Radio`] is selected or not
     ///   * whether the mouse is over the [`Radio`] or not
+    ///   * the current [`Frame`] of the [`Status`] of the [`Radio`], which
+    ///     should be used to blend the styles of each [`Status`]
     ///   * the drawn label of the [`Radio`]
     ///
     /// [`Radio`]: struct.Radio.html
+    /// [`Frame`]: ../../animation/struct.Frame.html
+    /// [`Status`]: enum.Status.html
     fn draw(
         &mut self,
         bounds: Rectangle,
         is_selected: bool,
         is_mouse_over: bool,
+        status: Frame<Status>,
         label: Self::Output,
         style: &Self::Style,
     ) -> Self::Output;
 }
 
-impl<'a, Message, Renderer> From<Radio<Message, Renderer>>
+impl<'a, Message, Renderer> From<Radio<'a, Message, Renderer>>
     for Element<'a, Message, Renderer>
 where
     Renderer: 'a + self::Renderer + row::Renderer + text::Renderer,
     Message: 'a + Clone,
 {
-    fn from(radio: Radio<Message, Renderer>) -> Element<'a, Message, Renderer> {
+    fn from(
+        radio: Radio<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
         Element::new(radio)
     }
 }
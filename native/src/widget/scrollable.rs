@@ -1,17 +1,24 @@
 //! Navigate an endless amount of content with a scrollbar.
 use crate::{
-    column, layout, mouse, overlay, Align, Clipboard, Column, Element, Event,
-    EventInteraction, Hasher, Layout, Length, Point, Rectangle, Size, Vector,
-    Widget,
+    column, command, focus, keyboard, layout, mouse, overlay, AlignItems,
+    Clipboard, Column, Command, Element, Event, EventInteraction, Hasher, Id,
+    Justify, Layout, Length, Padding, Point, Rectangle, Size, Vector, Widget,
 };
 
-use std::{f32, hash::Hash, u32};
+use std::{any::Any, f32, hash::Hash, time::Instant, u32};
 
-/// A widget that can vertically display an infinite amount of content with a
-/// scrollbar.
+/// A widget that can display an infinite amount of content with scrollbars.
+///
+/// By default, the content is only scrolled vertically. Use
+/// [`Scrollable::direction`] to scroll it horizontally too.
+///
+/// [`Scrollable::direction`]: struct.Scrollable.html#method.direction
 #[allow(missing_debug_implementations)]
 pub struct Scrollable<'a, Message, Renderer: self::Renderer> {
+    id: Option<Id>,
     state: &'a mut State,
+    direction: Direction,
+    on_scroll: Option<Box<dyn Fn(Viewport) -> Message + 'a>>,
     height: Length,
     max_height: u32,
     content: Column<'a, Message, Renderer>,
@@ -25,7 +32,10 @@ impl<'a, Message, Renderer: self::Renderer> Scrollable<'a, Message, Renderer> {
     /// [`State`]: struct.State.html
     pub fn new(state: &'a mut State) -> Self {
         Scrollable {
+            id: None,
             state,
+            direction: Direction::default(),
+            on_scroll: None,
             height: Length::Shrink,
             max_height: u32::MAX,
             content: Column::new(),
@@ -33,6 +43,74 @@ impl<'a, Message, Renderer: self::Renderer> Scrollable<'a, Message, Renderer> {
         }
     }
 
+    /// Sets the [`Direction`] in which the contents of the [`Scrollable`] can
+    /// be scrolled.
+    ///
+    /// When scrolling horizontally, the width of the contents is not limited
+    /// by the [`Scrollable`], so they should not fill the available width.
+    ///
+    /// A vertical mouse wheel scrolls horizontally when that is the only
+    /// [`Direction`], or while holding `Shift` when both are allowed.
+    ///
+    /// [`Direction`]: enum.Direction.html
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Sets the [`Id`] of the [`Scrollable`].
+    ///
+    /// It allows you to scroll it with a [`Command`]. See [`snap_to`].
+    ///
+    /// [`Id`]: ../struct.Id.html
+    /// [`Scrollable`]: struct.Scrollable.html
+    /// [`Command`]: ../../struct.Command.html
+    /// [`snap_to`]: fn.snap_to.html
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the message that will be produced when the user scrolls the
+    /// [`Scrollable`].
+    ///
+    /// The closure receives the [`Viewport`] of the [`Scrollable`] after
+    /// scrolling.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    /// [`Viewport`]: struct.Viewport.html
+    pub fn on_scroll(mut self, f: impl Fn(Viewport) -> Message + 'a) -> Self {
+        self.on_scroll = Some(Box::new(f));
+        self
+    }
+
+    /// Sets whether the [`Scrollable`] sticks to the bottom of its contents.
+    ///
+    /// When enabled, the [`Scrollable`] starts scrolled to the bottom and
+    /// stays there as its contents grow, until the user scrolls up. Scrolling
+    /// back to the bottom makes it stick again. This is useful for logs and
+    /// chats.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn stick_to_bottom(self, stick: bool) -> Self {
+        self.state.stick_to_bottom(stick);
+        self
+    }
+
+    /// Returns the [`State`] of the [`Scrollable`], with any child requested
+    /// by [`State::scroll_to_child`] scrolled into view.
+    ///
+    /// [`State`]: struct.State.html
+    /// [`Scrollable`]: struct.Scrollable.html
+    /// [`State::scroll_to_child`]: struct.State.html#method.scroll_to_child
+    fn resolved_state(&self, layout: Layout<'_>) -> State {
+        let mut state = *self.state;
+        state.reveal_child(layout.bounds(), layout.children().next().unwrap());
+
+        state
+    }
+
     /// Sets the vertical spacing _between_ elements.
     ///
     /// Custom margins per element do not exist in Iced. You should use this
@@ -46,8 +124,8 @@ impl<'a, Message, Renderer: self::Renderer> Scrollable<'a, Message, Renderer> {
     /// Sets the padding of the [`Scrollable`].
     ///
     /// [`Scrollable`]: struct.Scrollable.html
-    pub fn padding(mut self, units: u16) -> Self {
-        self.content = self.content.padding(units);
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.content = self.content.padding(padding);
         self
     }
 
@@ -83,10 +161,18 @@ impl<'a, Message, Renderer: self::Renderer> Scrollable<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the vertical distribution of the contents of the [`Scrollable`].
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn justify_content(mut self, justify: Justify) -> Self {
+        self.content = self.content.justify_content(justify);
+        self
+    }
+
     /// Sets the horizontal alignment of the contents of the [`Scrollable`] .
     ///
     /// [`Scrollable`]: struct.Scrollable.html
-    pub fn align_items(mut self, align_items: Align) -> Self {
+    pub fn align_items<A: Into<AlignItems>>(mut self, align_items: A) -> Self {
         self.content = self.content.align_items(align_items);
         self
     }
@@ -134,9 +220,21 @@ where
             .width(Widget::<Message, Renderer>::width(&self.content))
             .height(self.height);
 
+        let max_width = if self.direction.is_horizontal() {
+            f32::INFINITY
+        } else {
+            limits.max().width
+        };
+
+        let max_height = if self.direction.is_vertical() {
+            f32::INFINITY
+        } else {
+            limits.max().height
+        };
+
         let child_limits = layout::Limits::new(
             Size::new(limits.min().width, 0.0),
-            Size::new(limits.max().width, f32::INFINITY),
+            Size::new(max_width, max_height),
         );
 
         let content = self.content.layout(renderer, &child_limits);
@@ -155,100 +253,31 @@ where
         clipboard: Option<&dyn Clipboard>,
     ) -> EventInteraction {
         let bounds = layout.bounds();
-        let is_mouse_over = bounds.contains(cursor_position);
-
         let content = layout.children().next().unwrap();
         let content_bounds = content.bounds();
 
-        // TODO: Event capture. Nested scrollables should capture scroll events.
-        if is_mouse_over {
-            match event {
-                Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
-                    match delta {
-                        mouse::ScrollDelta::Lines { y, .. } => {
-                            // TODO: Configurable speed (?)
-                            self.state.scroll(y * 60.0, bounds, content_bounds);
-                        }
-                        mouse::ScrollDelta::Pixels { y, .. } => {
-                            self.state.scroll(y, bounds, content_bounds);
-                        }
-                    }
-                }
-                _ => {}
-            }
-        }
+        self.state.reveal_child(bounds, content);
 
-        let offset = self.state.offset(bounds, content_bounds);
-        let scrollbar = renderer.scrollbar(bounds, content_bounds, offset);
-        let is_mouse_over_scrollbar = scrollbar
-            .as_ref()
-            .map(|scrollbar| scrollbar.is_mouse_over(cursor_position))
-            .unwrap_or(false);
-
-        if self.state.is_scroller_grabbed() {
-            match event {
-                Event::Mouse(mouse::Event::ButtonReleased(
-                    mouse::Button::Left,
-                )) => {
-                    self.state.scroller_grabbed_at = None;
-                }
-                Event::Mouse(mouse::Event::CursorMoved { .. }) => {
-                    if let (Some(scrollbar), Some(scroller_grabbed_at)) =
-                        (scrollbar, self.state.scroller_grabbed_at)
-                    {
-                        self.state.scroll_to(
-                            scrollbar.scroll_percentage(
-                                scroller_grabbed_at,
-                                cursor_position,
-                            ),
-                            bounds,
-                            content_bounds,
-                        );
-                    }
-                }
-                _ => {}
-            }
-        } else if is_mouse_over_scrollbar {
-            match event {
-                Event::Mouse(mouse::Event::ButtonPressed(
-                    mouse::Button::Left,
-                )) => {
-                    if let Some(scrollbar) = scrollbar {
-                        if let Some(scroller_grabbed_at) =
-                            scrollbar.grab_scroller(cursor_position)
-                        {
-                            self.state.scroll_to(
-                                scrollbar.scroll_percentage(
-                                    scroller_grabbed_at,
-                                    cursor_position,
-                                ),
-                                bounds,
-                                content_bounds,
-                            );
-
-                            self.state.scroller_grabbed_at =
-                                Some(scroller_grabbed_at);
-                        }
-                    }
-                }
-                _ => {}
+        let offset = self.state.translation(bounds, content_bounds);
+
+        let cursor_position = update(
+            self.state,
+            self.direction,
+            &event,
+            bounds,
+            content_bounds,
+            cursor_position,
+            renderer,
+        );
+
+        if let Some(on_scroll) = &self.on_scroll {
+            if self.state.translation(bounds, content_bounds) != offset {
+                messages.push(on_scroll(
+                    self.state.viewport(bounds, content_bounds),
+                ));
             }
         }
 
-        let cursor_position = if is_mouse_over && !is_mouse_over_scrollbar {
-            Point::new(
-                cursor_position.x,
-                cursor_position.y
-                    + self.state.offset(bounds, content_bounds) as f32,
-            )
-        } else {
-            // TODO: Make `cursor_position` an `Option<Point>` so we can encode
-            // cursor availability.
-            // This will probably happen naturally once we add multi-window
-            // support.
-            Point::new(cursor_position.x, -1.0)
-        };
-
         self.content.on_event(
             event,
             content,
@@ -269,18 +298,19 @@ where
         let bounds = layout.bounds();
         let content_layout = layout.children().next().unwrap();
         let content_bounds = content_layout.bounds();
-        let offset = self.state.offset(bounds, content_bounds);
-        let scrollbar = renderer.scrollbar(bounds, content_bounds, offset);
+        let state = self.resolved_state(layout);
+        let offset = state.translation(bounds, content_bounds);
+        let scrollbars = renderer.scrollbars(bounds, content_bounds, offset);
 
         let is_mouse_over = bounds.contains(cursor_position);
-        let is_mouse_over_scrollbar = scrollbar
-            .as_ref()
-            .map(|scrollbar| scrollbar.is_mouse_over(cursor_position))
-            .unwrap_or(false);
+        let is_mouse_over_scrollbar = scrollbars.is_mouse_over(cursor_position);
 
         let content = {
             let cursor_position = if is_mouse_over && !is_mouse_over_scrollbar {
-                Point::new(cursor_position.x, cursor_position.y + offset as f32)
+                Point::new(
+                    cursor_position.x + offset.x as f32,
+                    cursor_position.y + offset.y as f32,
+                )
             } else {
                 Point::new(cursor_position.x, -1.0)
             };
@@ -295,12 +325,12 @@ where
 
         self::Renderer::draw(
             renderer,
-            &self.state,
+            &state,
             bounds,
             content_layout.bounds(),
             is_mouse_over,
-            is_mouse_over_scrollbar,
-            scrollbar,
+            cursor_position,
+            scrollbars,
             offset,
             &self.style,
             content,
@@ -311,6 +341,7 @@ where
         struct Marker;
         std::any::TypeId::of::<Marker>().hash(state);
 
+        self.direction.hash(state);
         self.height.hash(state);
         self.max_height.hash(state);
 
@@ -321,18 +352,231 @@ where
         &mut self,
         layout: Layout<'_>,
     ) -> Option<overlay::Element<'_, Message, Renderer>> {
-        let Self { content, state, .. } = self;
+        let content_layout = layout.children().next().unwrap();
+        let offset = self
+            .resolved_state(layout)
+            .translation(layout.bounds(), content_layout.bounds());
 
-        content
-            .overlay(layout.children().next().unwrap())
-            .map(|overlay| {
-                let bounds = layout.bounds();
-                let content_layout = layout.children().next().unwrap();
-                let content_bounds = content_layout.bounds();
-                let offset = state.offset(bounds, content_bounds);
+        self.content.overlay(content_layout).map(|overlay| {
+            overlay
+                .translate(Vector::new(-(offset.x as f32), -(offset.y as f32)))
+        })
+    }
 
-                overlay.translate(Vector::new(0.0, -(offset as f32)))
-            })
+    fn on_shortcut(
+        &mut self,
+        key_code: keyboard::KeyCode,
+        modifiers: keyboard::ModifiersState,
+        messages: &mut Vec<Message>,
+    ) -> EventInteraction {
+        self.content.on_shortcut(key_code, modifiers, messages)
+    }
+
+    fn focusables<'b>(
+        &'b mut self,
+        focusables: &mut Vec<&'b mut dyn focus::Focusable>,
+    ) {
+        self.content.focusables(focusables);
+    }
+
+    fn targets<'b>(&'b mut self, targets: &mut Vec<(&'b Id, &'b mut dyn Any)>) {
+        if let Some(id) = &self.id {
+            targets.push((id, &mut *self.state));
+        }
+
+        self.content.targets(targets);
+    }
+
+    fn redraw_request(&self) -> Option<Instant> {
+        self.content.redraw_request()
+    }
+}
+
+/// The directions in which the contents of a [`Scrollable`] can be scrolled.
+///
+/// [`Scrollable`]: struct.Scrollable.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Direction {
+    /// The contents can only be scrolled vertically.
+    #[default]
+    Vertical,
+
+    /// The contents can only be scrolled horizontally.
+    Horizontal,
+
+    /// The contents can be scrolled both vertically and horizontally.
+    Both,
+}
+
+impl Direction {
+    fn is_vertical(self) -> bool {
+        match self {
+            Direction::Vertical | Direction::Both => true,
+            Direction::Horizontal => false,
+        }
+    }
+
+    fn is_horizontal(self) -> bool {
+        match self {
+            Direction::Horizontal | Direction::Both => true,
+            Direction::Vertical => false,
+        }
+    }
+}
+
+/// Processes the scrolling interactions of an [`Event`] in a scrollable area
+/// and returns the cursor position relative to its content.
+///
+/// [`Event`]: ../../enum.Event.html
+pub(crate) fn update<Renderer: self::Renderer>(
+    state: &mut State,
+    direction: Direction,
+    event: &Event,
+    bounds: Rectangle,
+    content_bounds: Rectangle,
+    cursor_position: Point,
+    renderer: &Renderer,
+) -> Point {
+    let is_mouse_over = bounds.contains(cursor_position);
+
+    if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) =
+        *event
+    {
+        state.keyboard_modifiers = modifiers;
+    }
+
+    // TODO: Event capture. Nested scrollables should capture scroll events.
+    if is_mouse_over {
+        if let Event::Mouse(mouse::Event::WheelScrolled { delta }) = *event {
+            let (x, y) = match delta {
+                mouse::ScrollDelta::Lines { x, y } => {
+                    // TODO: Configurable speed (?)
+                    (x * 60.0, y * 60.0)
+                }
+                mouse::ScrollDelta::Pixels { x, y } => (x, y),
+            };
+
+            // Most mice only have a vertical wheel, which scrolls
+            // horizontally when it is the only direction or while holding
+            // shift
+            let is_wheel_horizontal = match direction {
+                Direction::Horizontal => true,
+                Direction::Both => state.keyboard_modifiers.shift,
+                Direction::Vertical => false,
+            };
+
+            let (x, y) = if is_wheel_horizontal && x == 0.0 {
+                (y, 0.0)
+            } else {
+                (x, y)
+            };
+
+            if direction.is_vertical() {
+                state.scroll(y, bounds, content_bounds);
+            }
+
+            if direction.is_horizontal() {
+                state.scroll_x(x, bounds, content_bounds);
+            }
+        }
+    }
+
+    let offset = state.translation(bounds, content_bounds);
+    let scrollbars = renderer.scrollbars(bounds, content_bounds, offset);
+    let is_mouse_over_scrollbar = scrollbars.is_mouse_over(cursor_position);
+
+    if state.is_scroller_grabbed() {
+        match *event {
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                state.y_scroller_grabbed_at = None;
+                state.x_scroller_grabbed_at = None;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let (Some(scrollbar), Some(scroller_grabbed_at)) =
+                    (&scrollbars.vertical, state.y_scroller_grabbed_at)
+                {
+                    state.scroll_to(
+                        scrollbar.scroll_percentage(
+                            Axis::Vertical,
+                            scroller_grabbed_at,
+                            cursor_position,
+                        ),
+                        bounds,
+                        content_bounds,
+                    );
+                }
+
+                if let (Some(scrollbar), Some(scroller_grabbed_at)) =
+                    (&scrollbars.horizontal, state.x_scroller_grabbed_at)
+                {
+                    state.scroll_x_to(
+                        scrollbar.scroll_percentage(
+                            Axis::Horizontal,
+                            scroller_grabbed_at,
+                            cursor_position,
+                        ),
+                        bounds,
+                        content_bounds,
+                    );
+                }
+            }
+            _ => {}
+        }
+    } else if is_mouse_over_scrollbar {
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) =
+            *event
+        {
+            if let Some(scrollbar) = &scrollbars.vertical {
+                if let Some(scroller_grabbed_at) =
+                    scrollbar.grab_scroller(Axis::Vertical, cursor_position)
+                {
+                    state.scroll_to(
+                        scrollbar.scroll_percentage(
+                            Axis::Vertical,
+                            scroller_grabbed_at,
+                            cursor_position,
+                        ),
+                        bounds,
+                        content_bounds,
+                    );
+
+                    state.y_scroller_grabbed_at = Some(scroller_grabbed_at);
+                }
+            }
+
+            if let Some(scrollbar) = &scrollbars.horizontal {
+                if let Some(scroller_grabbed_at) =
+                    scrollbar.grab_scroller(Axis::Horizontal, cursor_position)
+                {
+                    state.scroll_x_to(
+                        scrollbar.scroll_percentage(
+                            Axis::Horizontal,
+                            scroller_grabbed_at,
+                            cursor_position,
+                        ),
+                        bounds,
+                        content_bounds,
+                    );
+
+                    state.x_scroller_grabbed_at = Some(scroller_grabbed_at);
+                }
+            }
+        }
+    }
+
+    if is_mouse_over && !is_mouse_over_scrollbar {
+        let offset = state.translation(bounds, content_bounds);
+
+        Point::new(
+            cursor_position.x + offset.x as f32,
+            cursor_position.y + offset.y as f32,
+        )
+    } else {
+        // TODO: Make `cursor_position` an `Option<Point>` so we can encode
+        // cursor availability.
+        // This will probably happen naturally once we add multi-window
+        // support.
+        Point::new(cursor_position.x, -1.0)
     }
 }
 
@@ -341,20 +585,25 @@ where
 /// [`Scrollable`]: struct.Scrollable.html
 #[derive(Debug, Clone, Copy, Default)]
 pub struct State {
-    scroller_grabbed_at: Option<f32>,
-    offset: f32,
+    y_scroller_grabbed_at: Option<f32>,
+    x_scroller_grabbed_at: Option<f32>,
+    offset_y: Offset,
+    offset_x: Offset,
+    visible_child: Option<usize>,
+    sticks_to_bottom: bool,
+    keyboard_modifiers: keyboard::ModifiersState,
 }
 
 impl State {
-    /// Creates a new [`State`] with the scrollbar located at the top.
+    /// Creates a new [`State`] with the scrollbars located at the top left.
     ///
     /// [`State`]: struct.State.html
     pub fn new() -> Self {
         State::default()
     }
 
-    /// Apply a scrolling offset to the current [`State`], given the bounds of
-    /// the [`Scrollable`] and its contents.
+    /// Apply a vertical scrolling offset to the current [`State`], given the
+    /// bounds of the [`Scrollable`] and its contents.
     ///
     /// [`Scrollable`]: struct.Scrollable.html
     /// [`State`]: struct.State.html
@@ -368,13 +617,45 @@ impl State {
             return;
         }
 
-        self.offset = (self.offset - delta_y)
-            .max(0.0)
-            .min((content_bounds.height - bounds.height) as f32);
+        let offset =
+            self.offset_y.absolute(bounds.height, content_bounds.height);
+
+        self.set_offset_y(
+            (offset - delta_y)
+                .max(0.0)
+                .min(content_bounds.height - bounds.height),
+            bounds,
+            content_bounds,
+        );
     }
 
-    /// Moves the scroll position to a relative amount, given the bounds of
-    /// the [`Scrollable`] and its contents.
+    /// Apply a horizontal scrolling offset to the current [`State`], given
+    /// the bounds of the [`Scrollable`] and its contents.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    /// [`State`]: struct.State.html
+    pub fn scroll_x(
+        &mut self,
+        delta_x: f32,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        if bounds.width >= content_bounds.width {
+            return;
+        }
+
+        let offset = self.offset_x.absolute(bounds.width, content_bounds.width);
+
+        self.offset_x = Offset::Absolute(
+            (offset - delta_x)
+                .max(0.0)
+                .min(content_bounds.width - bounds.width),
+        );
+        self.visible_child = None;
+    }
+
+    /// Moves the vertical scroll position to a relative amount, given the
+    /// bounds of the [`Scrollable`] and its contents.
     ///
     /// `0` represents scrollbar at the top, while `1` represents scrollbar at
     /// the bottom.
@@ -387,25 +668,352 @@ impl State {
         bounds: Rectangle,
         content_bounds: Rectangle,
     ) {
-        self.offset =
-            ((content_bounds.height - bounds.height) * percentage).max(0.0);
+        self.set_offset_y(
+            ((content_bounds.height - bounds.height) * percentage).max(0.0),
+            bounds,
+            content_bounds,
+        );
     }
 
-    /// Returns the current scrolling offset of the [`State`], given the bounds
-    /// of the [`Scrollable`] and its contents.
+    /// Moves the horizontal scroll position to a relative amount, given the
+    /// bounds of the [`Scrollable`] and its contents.
+    ///
+    /// `0` represents scrollbar at the left, while `1` represents scrollbar at
+    /// the right.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    /// [`State`]: struct.State.html
+    pub fn scroll_x_to(
+        &mut self,
+        percentage: f32,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        self.offset_x = Offset::Absolute(
+            ((content_bounds.width - bounds.width) * percentage).max(0.0),
+        );
+        self.visible_child = None;
+    }
+
+    /// Snaps the vertical scroll position to the given [`Offset`].
+    ///
+    /// Unlike [`scroll_to`], it does not need the bounds of the
+    /// [`Scrollable`]. A relative [`Offset`] is kept as the contents of the
+    /// [`Scrollable`] change; for instance, `Offset::Relative(1.0)` stays at
+    /// the bottom.
+    ///
+    /// [`Offset`]: enum.Offset.html
+    /// [`scroll_to`]: #method.scroll_to
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn snap_to(&mut self, offset: Offset) {
+        self.offset_y = offset;
+        self.visible_child = None;
+    }
+
+    /// Snaps the horizontal scroll position to the given [`Offset`].
+    ///
+    /// See [`snap_to`] for more details.
+    ///
+    /// [`Offset`]: enum.Offset.html
+    /// [`snap_to`]: #method.snap_to
+    pub fn snap_x_to(&mut self, offset: Offset) {
+        self.offset_x = offset;
+        self.visible_child = None;
+    }
+
+    /// Scrolls the least amount needed to make the child of the
+    /// [`Scrollable`] with the given index visible.
+    ///
+    /// The child is scrolled into view the next time the [`Scrollable`] is
+    /// processed, once its layout is known.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn scroll_to_child(&mut self, index: usize) {
+        self.visible_child = Some(index);
+    }
+
+    /// Returns the current vertical scrolling offset of the [`State`], given
+    /// the bounds of the [`Scrollable`] and its contents.
     ///
     /// [`Scrollable`]: struct.Scrollable.html
     /// [`State`]: struct.State.html
     pub fn offset(&self, bounds: Rectangle, content_bounds: Rectangle) -> u32 {
-        let hidden_content =
-            (content_bounds.height - bounds.height).max(0.0).round() as u32;
+        self.offset_y
+            .absolute(bounds.height, content_bounds.height)
+            .round() as u32
+    }
+
+    /// Returns the current horizontal scrolling offset of the [`State`],
+    /// given the bounds of the [`Scrollable`] and its contents.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    /// [`State`]: struct.State.html
+    pub fn offset_x(
+        &self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> u32 {
+        self.offset_x
+            .absolute(bounds.width, content_bounds.width)
+            .round() as u32
+    }
+
+    /// Returns the current scrolling offset in both directions.
+    pub(crate) fn translation(
+        &self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> Vector<u32> {
+        Vector::new(
+            self.offset_x(bounds, content_bounds),
+            self.offset(bounds, content_bounds),
+        )
+    }
+
+    /// Returns the [`Viewport`] of the [`Scrollable`], given its bounds and
+    /// the bounds of its contents.
+    ///
+    /// [`Viewport`]: struct.Viewport.html
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn viewport(
+        &self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> Viewport {
+        let offset = self.translation(bounds, content_bounds);
+
+        let relative = |offset: u32, length: f32, content_length: f32| {
+            let hidden = content_length - length;
+
+            if hidden > 0.0 {
+                (offset as f32 / hidden).min(1.0)
+            } else {
+                0.0
+            }
+        };
 
-        self.offset.min(hidden_content as f32) as u32
+        Viewport {
+            offset: Vector::new(offset.x as f32, offset.y as f32),
+            relative_offset: Vector::new(
+                relative(offset.x, bounds.width, content_bounds.width),
+                relative(offset.y, bounds.height, content_bounds.height),
+            ),
+            size: Size::new(bounds.width, bounds.height),
+            content_size: Size::new(
+                content_bounds.width,
+                content_bounds.height,
+            ),
+        }
     }
 
-    /// Returns whether the scroller is currently grabbed or not.
+    /// Returns whether any scroller is currently grabbed or not.
     pub fn is_scroller_grabbed(&self) -> bool {
-        self.scroller_grabbed_at.is_some()
+        self.is_y_scroller_grabbed() || self.is_x_scroller_grabbed()
+    }
+
+    /// Returns whether the scroller of the vertical scrollbar is currently
+    /// grabbed or not.
+    pub fn is_y_scroller_grabbed(&self) -> bool {
+        self.y_scroller_grabbed_at.is_some()
+    }
+
+    /// Returns whether the scroller of the horizontal scrollbar is currently
+    /// grabbed or not.
+    pub fn is_x_scroller_grabbed(&self) -> bool {
+        self.x_scroller_grabbed_at.is_some()
+    }
+
+    fn stick_to_bottom(&mut self, stick: bool) {
+        if stick && !self.sticks_to_bottom {
+            self.offset_y = Offset::Relative(1.0);
+        }
+
+        self.sticks_to_bottom = stick;
+    }
+
+    fn set_offset_y(
+        &mut self,
+        offset: f32,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        let is_at_bottom = offset >= content_bounds.height - bounds.height;
+
+        self.offset_y = if self.sticks_to_bottom && is_at_bottom {
+            Offset::Relative(1.0)
+        } else {
+            Offset::Absolute(offset)
+        };
+
+        self.visible_child = None;
+    }
+
+    /// Scrolls the child requested by [`scroll_to_child`] into view, given
+    /// the bounds of the [`Scrollable`] and the [`Layout`] of its contents.
+    ///
+    /// [`scroll_to_child`]: #method.scroll_to_child
+    /// [`Scrollable`]: struct.Scrollable.html
+    /// [`Layout`]: ../../struct.Layout.html
+    fn reveal_child(&mut self, bounds: Rectangle, content: Layout<'_>) {
+        let child = match self.visible_child.and_then(|index| {
+            content.children().nth(index).map(|child| child.bounds())
+        }) {
+            Some(child) => child,
+            None => return,
+        };
+
+        let content_bounds = content.bounds();
+
+        // Scroll the least amount, showing the start of the child when it
+        // does not fit
+        let reveal = |offset: f32, length: f32, start: f32, end: f32| {
+            if start < offset || end - start > length {
+                start
+            } else if end > offset + length {
+                end - length
+            } else {
+                offset
+            }
+        };
+
+        let offset_x = reveal(
+            self.offset_x.absolute(bounds.width, content_bounds.width),
+            bounds.width,
+            child.x - content_bounds.x,
+            child.x + child.width - content_bounds.x,
+        );
+
+        let offset_y = reveal(
+            self.offset_y.absolute(bounds.height, content_bounds.height),
+            bounds.height,
+            child.y - content_bounds.y,
+            child.y + child.height - content_bounds.y,
+        );
+
+        self.offset_x = Offset::Absolute(offset_x);
+        self.set_offset_y(offset_y, bounds, content_bounds);
+    }
+}
+
+/// The scrolling position of a [`Scrollable`] in one direction.
+///
+/// [`Scrollable`]: struct.Scrollable.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Offset {
+    /// An amount of pixels from the start of the contents.
+    Absolute(f32),
+
+    /// A percentage of the hidden contents, `0.0` being the start and `1.0`
+    /// the end.
+    Relative(f32),
+}
+
+impl Offset {
+    /// Returns the amount of pixels from the start of the contents, given the
+    /// length of the viewport and the contents.
+    fn absolute(self, length: f32, content_length: f32) -> f32 {
+        let hidden = (content_length - length).max(0.0);
+
+        match self {
+            Offset::Absolute(offset) => offset.clamp(0.0, hidden),
+            Offset::Relative(percentage) => hidden * percentage.clamp(0.0, 1.0),
+        }
+    }
+}
+
+impl Default for Offset {
+    fn default() -> Self {
+        Offset::Absolute(0.0)
+    }
+}
+
+/// The visible area of a [`Scrollable`].
+///
+/// [`Scrollable`]: struct.Scrollable.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Viewport {
+    /// The scrolling offset, in pixels.
+    pub offset: Vector,
+
+    /// The scrolling offset relative to the hidden contents, from `0.0` at
+    /// the start to `1.0` at the end.
+    pub relative_offset: Vector,
+
+    /// The size of the visible area.
+    pub size: Size,
+
+    /// The size of the contents.
+    pub content_size: Size,
+}
+
+impl Viewport {
+    /// Returns whether the [`Viewport`] is scrolled to the bottom of the
+    /// contents.
+    ///
+    /// [`Viewport`]: struct.Viewport.html
+    pub fn is_at_bottom(&self) -> bool {
+        self.offset.y + self.size.height >= self.content_size.height
+    }
+}
+
+/// The scrollbars of a [`Scrollable`].
+///
+/// [`Scrollable`]: struct.Scrollable.html
+#[derive(Debug, Default)]
+pub struct Scrollbars {
+    /// The vertical [`Scrollbar`], if the contents are taller than the
+    /// [`Scrollable`].
+    ///
+    /// [`Scrollbar`]: struct.Scrollbar.html
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub vertical: Option<Scrollbar>,
+
+    /// The horizontal [`Scrollbar`], if the contents are wider than the
+    /// [`Scrollable`].
+    ///
+    /// [`Scrollbar`]: struct.Scrollbar.html
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub horizontal: Option<Scrollbar>,
+}
+
+impl Scrollbars {
+    /// Returns whether the cursor is over any of the [`Scrollbars`].
+    ///
+    /// [`Scrollbars`]: struct.Scrollbars.html
+    pub fn is_mouse_over(&self, cursor_position: Point) -> bool {
+        self.vertical
+            .iter()
+            .chain(self.horizontal.iter())
+            .any(|scrollbar| scrollbar.is_mouse_over(cursor_position))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Axis {
+    Vertical,
+    Horizontal,
+}
+
+impl Axis {
+    fn coordinate(self, point: Point) -> f32 {
+        match self {
+            Axis::Vertical => point.y,
+            Axis::Horizontal => point.x,
+        }
+    }
+
+    fn start(self, bounds: Rectangle) -> f32 {
+        match self {
+            Axis::Vertical => bounds.y,
+            Axis::Horizontal => bounds.x,
+        }
+    }
+
+    fn length(self, bounds: Rectangle) -> f32 {
+        match self {
+            Axis::Vertical => bounds.height,
+            Axis::Horizontal => bounds.width,
+        }
     }
 }
 
@@ -426,15 +1034,19 @@ pub struct Scrollbar {
 }
 
 impl Scrollbar {
-    fn is_mouse_over(&self, cursor_position: Point) -> bool {
+    /// Returns whether the cursor is over the [`Scrollbar`].
+    ///
+    /// [`Scrollbar`]: struct.Scrollbar.html
+    pub fn is_mouse_over(&self, cursor_position: Point) -> bool {
         self.bounds.contains(cursor_position)
     }
 
-    fn grab_scroller(&self, cursor_position: Point) -> Option<f32> {
+    fn grab_scroller(&self, axis: Axis, cursor_position: Point) -> Option<f32> {
         if self.bounds.contains(cursor_position) {
             Some(if self.scroller.bounds.contains(cursor_position) {
-                (cursor_position.y - self.scroller.bounds.y)
-                    / self.scroller.bounds.height
+                (axis.coordinate(cursor_position)
+                    - axis.start(self.scroller.bounds))
+                    / axis.length(self.scroller.bounds)
             } else {
                 0.5
             })
@@ -445,13 +1057,14 @@ impl Scrollbar {
 
     fn scroll_percentage(
         &self,
+        axis: Axis,
         grabbed_at: f32,
         cursor_position: Point,
     ) -> f32 {
-        (cursor_position.y
-            - self.bounds.y
-            - self.scroller.bounds.height * grabbed_at)
-            / (self.bounds.height - self.scroller.bounds.height)
+        (axis.coordinate(cursor_position)
+            - axis.start(self.bounds)
+            - axis.length(self.scroller.bounds) * grabbed_at)
+            / (axis.length(self.bounds) - axis.length(self.scroller.bounds))
     }
 }
 
@@ -477,17 +1090,17 @@ pub trait Renderer: column::Renderer + Sized {
     /// The style supported by this renderer.
     type Style: Default;
 
-    /// Returns the [`Scrollbar`] given the bounds and content bounds of a
-    /// [`Scrollable`].
+    /// Returns the [`Scrollbars`] given the bounds, the content bounds and
+    /// the scrolling offset of a [`Scrollable`].
     ///
-    /// [`Scrollbar`]: struct.Scrollbar.html
+    /// [`Scrollbars`]: struct.Scrollbars.html
     /// [`Scrollable`]: struct.Scrollable.html
-    fn scrollbar(
+    fn scrollbars(
         &self,
         bounds: Rectangle,
         content_bounds: Rectangle,
-        offset: u32,
-    ) -> Option<Scrollbar>;
+        offset: Vector<u32>,
+    ) -> Scrollbars;
 
     /// Draws the [`Scrollable`].
     ///
@@ -496,12 +1109,12 @@ pub trait Renderer: column::Renderer + Sized {
     /// - the bounds of the [`Scrollable`] widget
     /// - the bounds of the [`Scrollable`] content
     /// - whether the mouse is over the [`Scrollable`] or not
-    /// - whether the mouse is over the [`Scrollbar`] or not
-    /// - a optional [`Scrollbar`] to be rendered
-    /// - the scrolling offset
+    /// - the cursor position
+    /// - the [`Scrollbars`] to be rendered
+    /// - the scrolling offset in both directions
     /// - the drawn content
     ///
-    /// [`Scrollbar`]: struct.Scrollbar.html
+    /// [`Scrollbars`]: struct.Scrollbars.html
     /// [`Scrollable`]: struct.Scrollable.html
     /// [`State`]: struct.State.html
     fn draw(
@@ -510,9 +1123,9 @@ pub trait Renderer: column::Renderer + Sized {
         bounds: Rectangle,
         content_bounds: Rectangle,
         is_mouse_over: bool,
-        is_mouse_over_scrollbar: bool,
-        scrollbar: Option<Scrollbar>,
-        offset: u32,
+        cursor_position: Point,
+        scrollbars: Scrollbars,
+        offset: Vector<u32>,
         style: &Self::Style,
         content: Self::Output,
     ) -> Self::Output;
@@ -530,3 +1143,182 @@ where
         Element::new(scrollable)
     }
 }
+
+/// A scrolling operation performed by a [`UserInterface`].
+///
+/// [`UserInterface`]: ../../struct.UserInterface.html
+#[derive(Debug, Clone, PartialEq)]
+pub enum Action {
+    /// Snap the vertical scroll position of the [`Scrollable`] with the given
+    /// [`Id`].
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    /// [`Id`]: ../struct.Id.html
+    SnapTo(Id, Offset),
+
+    /// Snap the horizontal scroll position of the [`Scrollable`] with the
+    /// given [`Id`].
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    /// [`Id`]: ../struct.Id.html
+    SnapXTo(Id, Offset),
+
+    /// Scroll a child of the [`Scrollable`] with the given [`Id`] into view.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    /// [`Id`]: ../struct.Id.html
+    ScrollToChild(Id, usize),
+}
+
+impl Action {
+    /// Returns the [`Id`] of the [`Scrollable`] targeted by the [`Action`].
+    ///
+    /// [`Id`]: ../struct.Id.html
+    /// [`Scrollable`]: struct.Scrollable.html
+    /// [`Action`]: enum.Action.html
+    pub fn id(&self) -> &Id {
+        match self {
+            Action::SnapTo(id, _)
+            | Action::SnapXTo(id, _)
+            | Action::ScrollToChild(id, _) => id,
+        }
+    }
+
+    /// Applies the [`Action`] to the [`State`] of the targeted
+    /// [`Scrollable`].
+    ///
+    /// [`Action`]: enum.Action.html
+    /// [`State`]: struct.State.html
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn perform(self, state: &mut State) {
+        match self {
+            Action::SnapTo(_, offset) => state.snap_to(offset),
+            Action::SnapXTo(_, offset) => state.snap_x_to(offset),
+            Action::ScrollToChild(_, index) => state.scroll_to_child(index),
+        }
+    }
+}
+
+/// Produces a [`Command`] that snaps the vertical scroll position of the
+/// [`Scrollable`] with the given [`Id`] to an [`Offset`].
+///
+/// It is equivalent to [`State::snap_to`], but it does not need access to the
+/// [`State`].
+///
+/// [`Command`]: ../../struct.Command.html
+/// [`Scrollable`]: struct.Scrollable.html
+/// [`Id`]: ../struct.Id.html
+/// [`Offset`]: enum.Offset.html
+/// [`State::snap_to`]: struct.State.html#method.snap_to
+/// [`State`]: struct.State.html
+pub fn snap_to<T>(id: Id, offset: Offset) -> Command<T> {
+    Command::single(command::Action::Scroll(Action::SnapTo(id, offset)))
+}
+
+/// Produces a [`Command`] that snaps the horizontal scroll position of the
+/// [`Scrollable`] with the given [`Id`] to an [`Offset`].
+///
+/// It is equivalent to [`State::snap_x_to`].
+///
+/// [`Command`]: ../../struct.Command.html
+/// [`Scrollable`]: struct.Scrollable.html
+/// [`Id`]: ../struct.Id.html
+/// [`Offset`]: enum.Offset.html
+/// [`State::snap_x_to`]: struct.State.html#method.snap_x_to
+pub fn snap_x_to<T>(id: Id, offset: Offset) -> Command<T> {
+    Command::single(command::Action::Scroll(Action::SnapXTo(id, offset)))
+}
+
+/// Produces a [`Command`] that scrolls the [`Scrollable`] with the given
+/// [`Id`] to the bottom of its contents.
+///
+/// [`Command`]: ../../struct.Command.html
+/// [`Scrollable`]: struct.Scrollable.html
+/// [`Id`]: ../struct.Id.html
+pub fn snap_to_end<T>(id: Id) -> Command<T> {
+    snap_to(id, Offset::Relative(1.0))
+}
+
+/// Produces a [`Command`] that scrolls the least amount needed to make the
+/// child with the given index of the [`Scrollable`] with the given [`Id`]
+/// visible.
+///
+/// It is equivalent to [`State::scroll_to_child`].
+///
+/// [`Command`]: ../../struct.Command.html
+/// [`Scrollable`]: struct.Scrollable.html
+/// [`Id`]: ../struct.Id.html
+/// [`State::scroll_to_child`]: struct.State.html#method.scroll_to_child
+pub fn scroll_to_child<T>(id: Id, index: usize) -> Command<T> {
+    Command::single(command::Action::Scroll(Action::ScrollToChild(id, index)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bounds(height: f32) -> Rectangle {
+        Rectangle {
+            x: 0.0,
+            y: 0.0,
+            width: 100.0,
+            height,
+        }
+    }
+
+    #[test]
+    fn sticks_to_bottom_until_scrolled_up() {
+        let mut state = State::new();
+        state.stick_to_bottom(true);
+
+        assert_eq!(state.offset(bounds(100.0), bounds(300.0)), 200);
+        assert_eq!(state.offset(bounds(100.0), bounds(500.0)), 400);
+
+        state.scroll(50.0, bounds(100.0), bounds(500.0));
+        assert_eq!(state.offset(bounds(100.0), bounds(600.0)), 350);
+
+        state.scroll(-150.0, bounds(100.0), bounds(600.0));
+        assert_eq!(state.offset(bounds(100.0), bounds(700.0)), 600);
+    }
+
+    #[test]
+    fn scrolls_horizontally_with_a_vertical_wheel() {
+        let wheel = Event::Mouse(mouse::Event::WheelScrolled {
+            delta: mouse::ScrollDelta::Lines { x: 0.0, y: -1.0 },
+        });
+
+        let content_bounds = Rectangle {
+            width: 500.0,
+            ..bounds(100.0)
+        };
+
+        let mut state = State::new();
+
+        let _ = update(
+            &mut state,
+            Direction::Horizontal,
+            &wheel,
+            bounds(100.0),
+            content_bounds,
+            Point::new(50.0, 50.0),
+            &crate::renderer::Null,
+        );
+
+        assert_eq!(state.offset_x(bounds(100.0), content_bounds), 60);
+
+        // Both directions need shift to turn the wheel
+        let mut state = State::new();
+
+        let _ = update(
+            &mut state,
+            Direction::Both,
+            &wheel,
+            bounds(100.0),
+            content_bounds,
+            Point::new(50.0, 50.0),
+            &crate::renderer::Null,
+        );
+
+        assert_eq!(state.offset_x(bounds(100.0), content_bounds), 0);
+    }
+}
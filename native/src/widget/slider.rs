@@ -4,12 +4,14 @@
 //!
 //! [`Slider`]: struct.Slider.html
 //! [`State`]: struct.State.html
+use crate::animation::{Animated, Frame, Transition};
 use crate::{
-    layout, mouse, Clipboard, Element, Event, EventInteraction, Hasher, Layout,
-    Length, Point, Rectangle, Size, Widget,
+    focus, keyboard, layout, mouse, Clipboard, Element, Event,
+    EventInteraction, Hasher, Id, Layout, Length, Point, Rectangle, Size,
+    Widget,
 };
 
-use std::{hash::Hash, ops::RangeInclusive};
+use std::{hash::Hash, ops::RangeInclusive, time::Instant};
 
 /// An horizontal bar and a handle that selects a single value from a range of
 /// values.
@@ -39,6 +41,7 @@ use std::{hash::Hash, ops::RangeInclusive};
 /// ![Slider drawn by Coffee's renderer](https://github.com/hecrj/coffee/blob/bda9818f823dfcb8a7ad0ff4940b4d4b387b5208/images/ui/slider.png?raw=true)
 #[allow(missing_debug_implementations)]
 pub struct Slider<'a, T, Message, Renderer: self::Renderer> {
+    id: Option<Id>,
     state: &'a mut State,
     range: RangeInclusive<T>,
     step: T,
@@ -47,6 +50,7 @@ pub struct Slider<'a, T, Message, Renderer: self::Renderer> {
     on_release: Option<Message>,
     width: Length,
     height: u16,
+    transition: Transition,
     style: Renderer::Style,
 }
 
@@ -89,6 +93,7 @@ where
         };
 
         Slider {
+            id: None,
             state,
             value,
             range,
@@ -97,6 +102,7 @@ where
             on_release: None,
             width: Length::Fill,
             height: Renderer::DEFAULT_HEIGHT,
+            transition: Transition::default(),
             style: Renderer::Style::default(),
         }
     }
@@ -114,6 +120,20 @@ where
         self
     }
 
+    /// Sets the [`Id`] of the [`Slider`].
+    ///
+    /// It allows you to focus the [`Slider`] with a [`Command`]. See
+    /// [`focus::focus`].
+    ///
+    /// [`Id`]: ../struct.Id.html
+    /// [`Slider`]: struct.Slider.html
+    /// [`Command`]: ../../struct.Command.html
+    /// [`focus::focus`]: ../../focus/fn.focus.html
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Sets the width of the [`Slider`].
     ///
     /// [`Slider`]: struct.Slider.html
@@ -138,6 +158,19 @@ where
         self
     }
 
+    /// Sets the [`Transition`] used to blend the styles of the [`Slider`]
+    /// when its [`Status`] changes.
+    ///
+    /// By default, styles change instantly.
+    ///
+    /// [`Transition`]: ../../animation/struct.Transition.html
+    /// [`Slider`]: struct.Slider.html
+    /// [`Status`]: enum.Status.html
+    pub fn transition(mut self, transition: Transition) -> Self {
+        self.transition = transition;
+        self
+    }
+
     /// Sets the step size of the [`Slider`].
     ///
     /// [`Slider`]: struct.Slider.html
@@ -147,12 +180,31 @@ where
     }
 }
 
+impl<'a, T, Message, Renderer> Slider<'a, T, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn status(&self, bounds: Rectangle, cursor_position: Point) -> Status {
+        if self.state.is_dragging {
+            Status::Dragging
+        } else if bounds.contains(cursor_position) {
+            Status::Hovered
+        } else if self.state.is_focused {
+            Status::Focused
+        } else {
+            Status::Active
+        }
+    }
+}
+
 /// The local state of a [`Slider`].
 ///
 /// [`Slider`]: struct.Slider.html
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct State {
     is_dragging: bool,
+    is_focused: bool,
+    status: Animated<Status>,
 }
 
 impl State {
@@ -162,6 +214,40 @@ impl State {
     pub fn new() -> State {
         State::default()
     }
+
+    /// Returns whether the [`Slider`] is currently focused or not.
+    ///
+    /// [`Slider`]: struct.Slider.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+}
+
+/// The possible statuses of a [`Slider`].
+///
+/// [`Slider`]: struct.Slider.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Status {
+    /// The [`Slider`] is idle.
+    ///
+    /// [`Slider`]: struct.Slider.html
+    #[default]
+    Active,
+
+    /// The [`Slider`] is being hovered.
+    ///
+    /// [`Slider`]: struct.Slider.html
+    Hovered,
+
+    /// The handle of the [`Slider`] is being dragged.
+    ///
+    /// [`Slider`]: struct.Slider.html
+    Dragging,
+
+    /// The [`Slider`] is focused and it is not being hovered.
+    ///
+    /// [`Slider`]: struct.Slider.html
+    Focused,
 }
 
 impl<'a, T, Message, Renderer> Widget<Message, Renderer>
@@ -224,7 +310,7 @@ where
             }
         };
 
-        match event {
+        let interaction = match event {
             Event::Mouse(mouse_event) => match mouse_event {
                 mouse::Event::ButtonPressed(mouse::Button::Left) => {
                     let mut consumed = false;
@@ -233,6 +319,7 @@ where
                         self.state.is_dragging = true;
                         consumed = true;
                     }
+                    self.state.is_focused = false;
                     EventInteraction { consumed }
                 }
                 mouse::Event::ButtonReleased(mouse::Button::Left) => {
@@ -254,8 +341,44 @@ where
                 }
                 _ => EventInteraction::default(),
             },
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) if self.state.is_focused => {
+                let step = self.step.into();
+                let start = (*self.range.start()).into();
+                let end = (*self.range.end()).into();
+                let current = self.value.into();
+
+                let value = match key_code {
+                    keyboard::KeyCode::Left | keyboard::KeyCode::Down => {
+                        Some((current - step).max(start))
+                    }
+                    keyboard::KeyCode::Right | keyboard::KeyCode::Up => {
+                        Some((current + step).min(end))
+                    }
+                    keyboard::KeyCode::Home => Some(start),
+                    keyboard::KeyCode::End => Some(end),
+                    _ => None,
+                };
+
+                match value.and_then(T::from_f64) {
+                    Some(value) => {
+                        messages.push((self.on_change)(value));
+                        EventInteraction { consumed: true }
+                    }
+                    None => EventInteraction::default(),
+                }
+            }
             _ => EventInteraction::default(),
-        }
+        };
+
+        let status = self.status(layout.bounds(), cursor_position);
+
+        self.state
+            .status
+            .transition(status, self.transition, Instant::now());
+
+        interaction
     }
 
     fn draw(
@@ -268,12 +391,22 @@ where
         let start = *self.range.start();
         let end = *self.range.end();
 
+        let bounds = layout.bounds();
+        let status = self.status(bounds, cursor_position);
+
+        // The status may have changed without any events, like when focusing
+        let status = if *self.state.status.target() == status {
+            self.state.status.at(Instant::now())
+        } else {
+            Frame::settled(status)
+        };
+
         renderer.draw(
-            layout.bounds(),
+            bounds,
             cursor_position,
             start.into() as f32..=end.into() as f32,
             self.value.into() as f32,
-            self.state.is_dragging,
+            status,
             &self.style,
         )
     }
@@ -284,6 +417,39 @@ where
 
         self.width.hash(state);
     }
+
+    fn focusables<'b>(
+        &'b mut self,
+        focusables: &mut Vec<&'b mut dyn focus::Focusable>,
+    ) {
+        focusables.push(self);
+    }
+
+    fn redraw_request(&self) -> Option<Instant> {
+        self.state.status.redraw_request(Instant::now())
+    }
+}
+
+impl<'a, T, Message, Renderer> focus::Focusable
+    for Slider<'a, T, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn is_focused(&self) -> bool {
+        self.state.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.state.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.state.is_focused = false;
+    }
+
+    fn id(&self) -> Option<&Id> {
+        self.id.as_ref()
+    }
 }
 
 /// The renderer of a [`Slider`].
@@ -310,17 +476,20 @@ pub trait Renderer: crate::Renderer {
     ///   * the local state of the [`Slider`]
     ///   * the range of values of the [`Slider`]
     ///   * the current value of the [`Slider`]
+    ///   * the current [`Frame`] of the [`Status`] of the [`Slider`]
     ///
     /// [`Slider`]: struct.Slider.html
     /// [`State`]: struct.State.html
     /// [`Class`]: enum.Class.html
+    /// [`Frame`]: ../../animation/struct.Frame.html
+    /// [`Status`]: enum.Status.html
     fn draw(
         &mut self,
         bounds: Rectangle,
         cursor_position: Point,
         range: RangeInclusive<f32>,
         value: f32,
-        is_dragging: bool,
+        status: Frame<Status>,
         style: &Self::Style,
     ) -> Self::Output;
 }
@@ -0,0 +1,449 @@
+//! Layer content on top of each other.
+use std::hash::Hash;
+
+use crate::{
+    focus, keyboard, layout, overlay, Align, Clipboard, Element, Event,
+    EventInteraction, Hasher, Id, Layout, Length, Point, Size, Widget,
+};
+
+use std::any::Any;
+use std::time::Instant;
+
+/// A container that lays out its contents in the same bounds, drawing each
+/// element on top of the previous ones.
+///
+/// Events are processed front-to-back: an element that consumes an event
+/// captures it and the elements behind it will not receive it. The elements
+/// behind an element under the cursor do not see the cursor either.
+///
+/// Elements with a [`Length::Fill`] width or height fill the size of the
+/// other elements of the [`Stack`], which is useful for veils and
+/// backgrounds.
+///
+/// [`Stack`]: struct.Stack.html
+/// [`Length::Fill`]: ../../enum.Length.html#variant.Fill
+#[allow(missing_debug_implementations)]
+pub struct Stack<'a, Message, Renderer> {
+    width: Length,
+    height: Length,
+    max_width: u32,
+    max_height: u32,
+    horizontal_alignment: Align,
+    vertical_alignment: Align,
+    children: Vec<Element<'a, Message, Renderer>>,
+}
+
+impl<'a, Message, Renderer> Stack<'a, Message, Renderer> {
+    /// Creates an empty [`Stack`].
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn new() -> Self {
+        Self::with_children(Vec::new())
+    }
+
+    /// Creates a [`Stack`] with the given elements, from back to front.
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn with_children(
+        children: Vec<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        Stack {
+            width: Length::Shrink,
+            height: Length::Shrink,
+            max_width: u32::MAX,
+            max_height: u32::MAX,
+            horizontal_alignment: Align::Start,
+            vertical_alignment: Align::Start,
+            children,
+        }
+    }
+
+    /// Sets the width of the [`Stack`].
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Stack`].
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the maximum width of the [`Stack`].
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn max_width(mut self, max_width: u32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the maximum height of the [`Stack`].
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn max_height(mut self, max_height: u32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// Sets the horizontal alignment of the contents of the [`Stack`].
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn align_x(mut self, alignment: Align) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets the vertical alignment of the contents of the [`Stack`].
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn align_y(mut self, alignment: Align) -> Self {
+        self.vertical_alignment = alignment;
+        self
+    }
+
+    /// Adds an [`Element`] on top of the contents of the [`Stack`].
+    ///
+    /// [`Element`]: ../struct.Element.html
+    /// [`Stack`]: struct.Stack.html
+    pub fn push<E>(mut self, child: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.children.push(child.into());
+        self
+    }
+
+    /// Returns the cursor position seen by every element of the [`Stack`].
+    ///
+    /// [`Stack`]: struct.Stack.html
+    fn cursor_positions(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Vec<Point> {
+        let layouts: Vec<_> = layout.children().collect();
+        let mut cursor_position = Some(cursor_position);
+
+        let mut cursor_positions: Vec<Point> = layouts
+            .iter()
+            .rev()
+            .map(|layout| {
+                let current =
+                    cursor_position.unwrap_or(overlay::UNAVAILABLE_CURSOR);
+
+                cursor_position = cursor_position
+                    .filter(|position| !layout.bounds().contains(*position));
+
+                current
+            })
+            .collect();
+
+        cursor_positions.reverse();
+        cursor_positions
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Stack<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits
+            .loose()
+            .max_width(self.max_width)
+            .max_height(self.max_height)
+            .width(self.width)
+            .height(self.height);
+
+        // Filling elements take the size of the others
+        let intrinsic_size = self
+            .children
+            .iter()
+            .filter(|child| {
+                child.width().fill_factor() == 0
+                    && child.height().fill_factor() == 0
+            })
+            .map(|child| child.layout(renderer, &limits.loose()).size())
+            .fold(Size::ZERO, |intrinsic_size, size| {
+                Size::new(
+                    intrinsic_size.width.max(size.width),
+                    intrinsic_size.height.max(size.height),
+                )
+            });
+
+        let size = limits.resolve(intrinsic_size);
+        let content_limits = layout::Limits::new(Size::ZERO, size);
+
+        let children = self
+            .children
+            .iter()
+            .map(|child| {
+                let mut node = child.layout(renderer, &content_limits);
+
+                node.align(
+                    self.horizontal_alignment,
+                    self.vertical_alignment,
+                    size,
+                );
+
+                node
+            })
+            .collect();
+
+        layout::Node::with_children(size, children)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> EventInteraction {
+        let cursor_positions = self.cursor_positions(layout, cursor_position);
+        let layouts: Vec<_> = layout.children().collect();
+
+        // The elements on top capture events first
+        for ((child, layout), cursor_position) in self
+            .children
+            .iter_mut()
+            .zip(layouts)
+            .zip(cursor_positions)
+            .rev()
+        {
+            let interaction = child.widget.on_event(
+                event.clone(),
+                layout,
+                cursor_position,
+                messages,
+                renderer,
+                clipboard,
+            );
+
+            if interaction.consumed {
+                return interaction;
+            }
+        }
+
+        EventInteraction::default()
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        renderer.draw(
+            defaults,
+            &self.children,
+            layout,
+            &self.cursor_positions(layout, cursor_position),
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.max_width.hash(state);
+        self.max_height.hash(state);
+        self.horizontal_alignment.hash(state);
+        self.vertical_alignment.hash(state);
+
+        for child in &self.children {
+            child.widget.hash_layout(state);
+        }
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        overlay::Group::with_children(
+            self.children
+                .iter_mut()
+                .zip(layout.children())
+                .filter_map(|(child, layout)| child.widget.overlay(layout))
+                .collect(),
+        )
+        .overlay()
+    }
+
+    fn on_shortcut(
+        &mut self,
+        key_code: keyboard::KeyCode,
+        modifiers: keyboard::ModifiersState,
+        messages: &mut Vec<Message>,
+    ) -> EventInteraction {
+        self.children.iter_mut().fold(
+            EventInteraction::default(),
+            |interaction, child| {
+                child
+                    .widget
+                    .on_shortcut(key_code, modifiers, messages)
+                    .union(&interaction)
+            },
+        )
+    }
+
+    fn focusables<'b>(
+        &'b mut self,
+        focusables: &mut Vec<&'b mut dyn focus::Focusable>,
+    ) {
+        for child in &mut self.children {
+            child.widget.focusables(focusables);
+        }
+    }
+
+    fn targets<'b>(&'b mut self, targets: &mut Vec<(&'b Id, &'b mut dyn Any)>) {
+        for child in &mut self.children {
+            child.widget.targets(targets);
+        }
+    }
+
+    fn redraw_request(&self) -> Option<Instant> {
+        self.children
+            .iter()
+            .filter_map(|child| child.widget.redraw_request())
+            .min()
+    }
+}
+
+/// The renderer of a [`Stack`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Stack`] in your user interface.
+///
+/// [`Stack`]: struct.Stack.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer + Sized {
+    /// Draws a [`Stack`].
+    ///
+    /// It receives:
+    /// - the children of the [`Stack`], from back to front
+    /// - the [`Layout`] of the [`Stack`] and its children
+    /// - the cursor position seen by each child
+    ///
+    /// Every child must be drawn on top of the previous ones.
+    ///
+    /// [`Stack`]: struct.Stack.html
+    /// [`Layout`]: ../layout/struct.Layout.html
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        children: &[Element<'_, Message, Self>],
+        layout: Layout<'_>,
+        cursor_positions: &[Point],
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Stack<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        stack: Stack<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(stack)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{button, mouse, renderer::Null, Button, Column, Text};
+
+    #[test]
+    fn fills_the_size_of_the_other_children() {
+        let stack: Stack<'_, (), Null> = Stack::new()
+            .align_x(Align::End)
+            .push(
+                Column::new()
+                    .width(Length::Units(100))
+                    .height(Length::Units(50)),
+            )
+            .push(Column::new().width(Length::Fill).height(Length::Fill))
+            .push(
+                Column::new()
+                    .width(Length::Units(20))
+                    .height(Length::Units(20)),
+            );
+
+        let node = stack.layout(
+            &Null,
+            &layout::Limits::new(Size::ZERO, Size::new(500.0, 500.0)),
+        );
+
+        let bounds: Vec<_> =
+            node.children().iter().map(|child| child.bounds()).collect();
+
+        assert_eq!(node.size(), Size::new(100.0, 50.0));
+        assert_eq!(bounds[1].width, 100.0);
+        assert_eq!(bounds[1].height, 50.0);
+        assert_eq!(bounds[2].x, 80.0);
+    }
+
+    #[test]
+    fn front_elements_capture_the_cursor() {
+        let mut state = button::State::new();
+
+        let mut stack: Stack<'_, (), Null> = Stack::new()
+            .push(
+                Button::new(&mut state, Text::new("Press me"))
+                    .width(Length::Units(100))
+                    .padding(10)
+                    .on_press(()),
+            )
+            .push(Column::new().width(Length::Fill).height(Length::Fill));
+
+        let node = stack.layout(
+            &Null,
+            &layout::Limits::new(Size::ZERO, Size::new(500.0, 500.0)),
+        );
+
+        let mut messages = Vec::new();
+        let cursor_position = Point::new(5.0, 5.0);
+
+        for event in &[
+            mouse::Event::ButtonPressed(mouse::Button::Left),
+            mouse::Event::ButtonReleased(mouse::Button::Left),
+        ] {
+            let _ = stack.on_event(
+                Event::Mouse(*event),
+                Layout::new(&node),
+                cursor_position,
+                &mut messages,
+                &Null,
+                None,
+            );
+        }
+
+        assert!(messages.is_empty());
+    }
+}
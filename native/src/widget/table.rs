@@ -0,0 +1,1323 @@
+//! Display tabular data with sortable and resizable columns.
+//!
+//! A [`Table`] has some local [`State`].
+//!
+//! [`Table`]: struct.Table.html
+//! [`State`]: struct.State.html
+use crate::{
+    focus, keyboard, layout, mouse, overlay, scrollable, text, Clipboard,
+    Element, Event, EventInteraction, Hasher, Id, Layout, Length, Padding,
+    Point, Rectangle, Size, Vector, Widget,
+};
+
+use std::any::Any;
+use std::cell::{RefCell, RefMut};
+use std::hash::Hash;
+use std::ops::Range;
+use std::time::Instant;
+
+/// The distance from a column border where it can be grabbed for resizing.
+const BORDER_GRAB_DISTANCE: f32 = 4.0;
+
+/// The minimum width of a resized column.
+const MIN_COLUMN_WIDTH: f32 = 20.0;
+
+/// A table displaying a header row and a very large amount of rows, aligned
+/// in columns.
+///
+/// Only the rows intersecting the viewport are built and laid out. The cells
+/// are produced on demand by a closure receiving the index of the row and the
+/// column.
+///
+/// Columns can be resized by dragging the borders of their header and sorted
+/// by clicking it. Rows can be selected by clicking them, holding `Shift` to
+/// select a range and `Ctrl` to toggle a row.
+///
+/// ```
+/// # use iced_native::{renderer::Null, table, Text};
+/// #
+/// # pub type Table<'a, Message> = iced_native::Table<'a, Message, Null>;
+/// #
+/// use table::{Column, Order};
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Sort(usize, Order),
+///     Select(Vec<usize>),
+/// }
+///
+/// let files = vec![("README.md", 4_096), ("LICENSE", 1_024)];
+/// let mut state = table::State::new();
+///
+/// let table: Table<'_, Message> = Table::new(
+///     &mut state,
+///     vec![Column::new("Name"), Column::new("Size")],
+///     files.len(),
+///     |row, column| match column {
+///         0 => Text::new(files[row].0).into(),
+///         _ => Text::new(files[row].1.to_string()).into(),
+///     },
+/// )
+/// .on_sort(Message::Sort)
+/// .on_selection(Message::Select);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Table<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    columns: Vec<Column>,
+    rows: usize,
+    view: Box<dyn Fn(usize, usize) -> Element<'a, Message, Renderer> + 'a>,
+    width: Length,
+    height: Length,
+    row_height: u16,
+    padding: Padding,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    sort: Option<(usize, Order)>,
+    on_sort: Option<Box<dyn Fn(usize, Order) -> Message + 'a>>,
+    selection_mode: SelectionMode,
+    on_selection: Option<Box<dyn Fn(Vec<usize>) -> Message + 'a>>,
+    style: <Renderer as self::Renderer>::Style,
+    cells: RefCell<Option<Cells<'a, Message, Renderer>>>,
+}
+
+/// A column of a [`Table`].
+///
+/// [`Table`]: struct.Table.html
+#[derive(Debug, Clone)]
+pub struct Column {
+    label: String,
+    width: Length,
+    is_sortable: bool,
+    is_resizable: bool,
+}
+
+impl Column {
+    /// Creates a new [`Column`] with the given label in its header.
+    ///
+    /// [`Column`]: struct.Column.html
+    pub fn new(label: impl Into<String>) -> Self {
+        Column {
+            label: label.into(),
+            width: Length::Fill,
+            is_sortable: true,
+            is_resizable: true,
+        }
+    }
+
+    /// Sets the width of the [`Column`].
+    ///
+    /// A [`Length::Shrink`] column fits its label.
+    ///
+    /// [`Column`]: struct.Column.html
+    /// [`Length::Shrink`]: ../../enum.Length.html#variant.Shrink
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets whether clicking the header of the [`Column`] sorts the
+    /// [`Table`].
+    ///
+    /// [`Column`]: struct.Column.html
+    /// [`Table`]: struct.Table.html
+    pub fn sortable(mut self, is_sortable: bool) -> Self {
+        self.is_sortable = is_sortable;
+        self
+    }
+
+    /// Sets whether the [`Column`] can be resized by dragging its border.
+    ///
+    /// [`Column`]: struct.Column.html
+    pub fn resizable(mut self, is_resizable: bool) -> Self {
+        self.is_resizable = is_resizable;
+        self
+    }
+}
+
+/// The order of a sorted [`Column`].
+///
+/// [`Column`]: struct.Column.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Order {
+    /// From the smallest value to the biggest one.
+    Ascending,
+
+    /// From the biggest value to the smallest one.
+    Descending,
+}
+
+impl Order {
+    /// Returns the opposite [`Order`].
+    ///
+    /// [`Order`]: enum.Order.html
+    pub fn reverse(self) -> Self {
+        match self {
+            Order::Ascending => Order::Descending,
+            Order::Descending => Order::Ascending,
+        }
+    }
+}
+
+/// How rows of a [`Table`] can be selected.
+///
+/// [`Table`]: struct.Table.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SelectionMode {
+    /// Rows cannot be selected.
+    None,
+
+    /// A single row can be selected.
+    #[default]
+    Single,
+
+    /// Many rows can be selected, using `Shift` and `Ctrl`.
+    Multiple,
+}
+
+/// The cells of a [`Table`] that are currently built.
+///
+/// [`Table`]: struct.Table.html
+struct Cells<'a, Message, Renderer> {
+    range: Range<usize>,
+    elements: Vec<Element<'a, Message, Renderer>>,
+
+    // The widths of the columns and the layout of the cells, once they are
+    // laid out
+    content: Option<(Vec<f32>, layout::Node)>,
+}
+
+impl<'a, Message, Renderer> Table<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`Table`] with the given [`State`], columns, amount of
+    /// rows and a closure producing the cell of a row and a column.
+    ///
+    /// [`Table`]: struct.Table.html
+    /// [`State`]: struct.State.html
+    pub fn new<F>(
+        state: &'a mut State,
+        columns: Vec<Column>,
+        rows: usize,
+        view: F,
+    ) -> Self
+    where
+        F: 'a + Fn(usize, usize) -> Element<'a, Message, Renderer>,
+    {
+        Table {
+            state,
+            columns,
+            rows,
+            view: Box::new(view),
+            width: Length::Fill,
+            height: Length::Fill,
+            row_height: Renderer::DEFAULT_ROW_HEIGHT,
+            padding: <Renderer as self::Renderer>::DEFAULT_PADDING,
+            text_size: None,
+            font: Default::default(),
+            sort: None,
+            on_sort: None,
+            selection_mode: SelectionMode::default(),
+            on_selection: None,
+            style: Default::default(),
+            cells: RefCell::new(None),
+        }
+    }
+
+    /// Sets the width of the [`Table`].
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Table`].
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the height of the rows of the [`Table`], including its header.
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn row_height(mut self, row_height: u16) -> Self {
+        self.row_height = row_height;
+        self
+    }
+
+    /// Sets the padding of the cells of the [`Table`].
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the header of the [`Table`].
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the font of the header of the [`Table`].
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the [`Column`] the [`Table`] is currently sorted by, showing an
+    /// indicator in its header.
+    ///
+    /// [`Column`]: struct.Column.html
+    /// [`Table`]: struct.Table.html
+    pub fn sorted_by(mut self, column: usize, order: Order) -> Self {
+        self.sort = Some((column, order));
+        self
+    }
+
+    /// Sets the message that will be produced when the header of a sortable
+    /// [`Column`] is clicked.
+    ///
+    /// The closure receives the index of the [`Column`] and the requested
+    /// [`Order`]: the reverse of the current one if the [`Table`] is already
+    /// sorted by the [`Column`], or [`Order::Ascending`] otherwise.
+    ///
+    /// [`Column`]: struct.Column.html
+    /// [`Order`]: enum.Order.html
+    /// [`Order::Ascending`]: enum.Order.html#variant.Ascending
+    /// [`Table`]: struct.Table.html
+    pub fn on_sort(mut self, f: impl Fn(usize, Order) -> Message + 'a) -> Self {
+        self.on_sort = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the [`SelectionMode`] of the [`Table`].
+    ///
+    /// [`SelectionMode`]: enum.SelectionMode.html
+    /// [`Table`]: struct.Table.html
+    pub fn selection_mode(mut self, selection_mode: SelectionMode) -> Self {
+        self.selection_mode = selection_mode;
+        self
+    }
+
+    /// Sets the message that will be produced when the selected rows of the
+    /// [`Table`] change.
+    ///
+    /// The closure receives the indices of the selected rows, in ascending
+    /// order.
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn on_selection(
+        mut self,
+        f: impl Fn(Vec<usize>) -> Message + 'a,
+    ) -> Self {
+        self.on_selection = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the style of the [`Table`].
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as self::Renderer>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the width of every column, given the width of the [`Table`].
+    ///
+    /// [`Table`]: struct.Table.html
+    fn widths(&self, renderer: &Renderer, width: f32) -> Vec<f32> {
+        let text_size = self.text_size.unwrap_or(renderer.default_size());
+        let padding = self.padding;
+
+        let fixed: Vec<Option<f32>> = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(i, column)| match (self.state.width(i), column.width) {
+                (Some(width), _) => Some(width),
+                (None, Length::Units(units)) => Some(f32::from(units)),
+                (None, Length::Shrink) => {
+                    let (label_width, _) = renderer.measure(
+                        &column.label,
+                        text_size,
+                        self.font,
+                        Size::INFINITY,
+                    );
+
+                    // Leave room for the sort indicator
+                    Some(
+                        label_width
+                            + f32::from(padding.horizontal())
+                            + f32::from(text_size),
+                    )
+                }
+                (None, Length::Fill) | (None, Length::FillPortion(_)) => None,
+            })
+            .collect();
+
+        let remaining = (width
+            - fixed.iter().filter_map(|width| *width).sum::<f32>())
+        .max(0.0);
+
+        let fill_factors: u16 = self
+            .columns
+            .iter()
+            .zip(&fixed)
+            .filter(|(_, width)| width.is_none())
+            .map(|(column, _)| column.width.fill_factor())
+            .sum();
+
+        self.columns
+            .iter()
+            .zip(fixed)
+            .map(|(column, width)| {
+                width.unwrap_or_else(|| {
+                    remaining * f32::from(column.width.fill_factor())
+                        / f32::from(fill_factors.max(1))
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the bounds of the header and the body of the [`Table`].
+    ///
+    /// [`Table`]: struct.Table.html
+    fn split(&self, bounds: Rectangle) -> (Rectangle, Rectangle) {
+        let header_height = f32::from(self.row_height).min(bounds.height);
+
+        (
+            Rectangle {
+                height: header_height,
+                ..bounds
+            },
+            Rectangle {
+                y: bounds.y + header_height,
+                height: bounds.height - header_height,
+                ..bounds
+            },
+        )
+    }
+
+    /// Returns the bounds of the contents of the body of the [`Table`],
+    /// holding every row.
+    ///
+    /// [`Table`]: struct.Table.html
+    fn content_bounds(&self, body: Rectangle) -> Rectangle {
+        Rectangle {
+            height: self.rows as f32 * f32::from(self.row_height),
+            ..body
+        }
+    }
+
+    /// Returns the indices of the rows visible in the given body bounds.
+    fn visible_range(&self, body: Rectangle) -> Range<usize> {
+        let row_height = f32::from(self.row_height.max(1));
+
+        let offset =
+            self.state
+                .scrollable
+                .offset(body, self.content_bounds(body)) as f32;
+
+        let start = (offset / row_height) as usize;
+        let end = (((offset + body.height) / row_height).ceil() as usize)
+            .min(self.rows);
+
+        start.min(end)..end
+    }
+
+    /// Returns the cells of the rows visible in the given body bounds,
+    /// building them if the visible rows have changed.
+    fn elements(
+        &self,
+        body: Rectangle,
+    ) -> RefMut<'_, Cells<'a, Message, Renderer>> {
+        let range = self.visible_range(body);
+        let mut cells = self.cells.borrow_mut();
+
+        let is_outdated = match cells.as_ref() {
+            Some(cells) => cells.range != range,
+            None => true,
+        };
+
+        if is_outdated {
+            let columns = self.columns.len();
+
+            *cells = Some(Cells {
+                elements: range
+                    .clone()
+                    .flat_map(|row| {
+                        (0..columns).map(move |column| (row, column))
+                    })
+                    .map(|(row, column)| (self.view)(row, column))
+                    .collect(),
+                range,
+                content: None,
+            });
+        }
+
+        RefMut::map(cells, |cells| cells.as_mut().unwrap())
+    }
+
+    /// Returns the cells of the rows visible the last time the [`Table`] was
+    /// processed, building them if needed.
+    ///
+    /// This lets focus, targets and redraw requests reach the cells before
+    /// the [`Table`] is processed again.
+    ///
+    /// [`Table`]: struct.Table.html
+    fn visible_cells(
+        &self,
+    ) -> Option<RefMut<'_, Cells<'a, Message, Renderer>>> {
+        let body = self.state.viewport?;
+
+        Some(self.elements(body))
+    }
+
+    /// Returns the cells of the rows visible in the given body bounds,
+    /// building and laying them out if the visible rows or the column widths
+    /// have changed.
+    fn cells(
+        &self,
+        renderer: &Renderer,
+        body: Rectangle,
+    ) -> RefMut<'_, Cells<'a, Message, Renderer>> {
+        let widths = self.widths(renderer, body.width);
+        let mut cells = self.elements(body);
+
+        let is_outdated = match &cells.content {
+            Some((current, _)) => *current != widths,
+            None => true,
+        };
+
+        if is_outdated {
+            let content = self.layout_cells(renderer, &cells, &widths);
+            cells.content = Some((widths, content));
+        }
+
+        cells
+    }
+
+    fn layout_cells(
+        &self,
+        renderer: &Renderer,
+        cells: &Cells<'a, Message, Renderer>,
+        widths: &[f32],
+    ) -> layout::Node {
+        let row_height = f32::from(self.row_height);
+        let padding = self.padding;
+
+        let mut elements = cells.elements.iter();
+        let mut nodes = Vec::new();
+
+        for row in cells.range.clone() {
+            let mut x = 0.0;
+
+            for (width, element) in widths.iter().zip(&mut elements) {
+                let limits = layout::Limits::new(
+                    Size::ZERO,
+                    Size::new(
+                        (width - f32::from(padding.horizontal())).max(0.0),
+                        (row_height - f32::from(padding.vertical())).max(0.0),
+                    ),
+                );
+
+                let mut node = element.layout(renderer, &limits);
+                node.move_to(Point::new(
+                    x + f32::from(padding.left),
+                    row as f32 * row_height + f32::from(padding.top),
+                ));
+
+                nodes.push(node);
+
+                x += width;
+            }
+        }
+
+        let size =
+            Size::new(widths.iter().sum(), self.rows as f32 * row_height);
+
+        layout::Node::with_children(size, nodes)
+    }
+
+    /// Returns the index of the column whose right border is under the
+    /// cursor, if it can be resized.
+    fn border_at(
+        columns: &[Column],
+        widths: &[f32],
+        header: Rectangle,
+        cursor_position: Point,
+    ) -> Option<usize> {
+        if !header.contains(cursor_position) {
+            return None;
+        }
+
+        let mut x = header.x;
+
+        widths.iter().zip(columns).position(|(width, column)| {
+            x += width;
+
+            column.is_resizable
+                && (cursor_position.x - x).abs() <= BORDER_GRAB_DISTANCE
+        })
+    }
+
+    /// Returns the index of the column under the cursor in the header.
+    fn column_at(
+        widths: &[f32],
+        header: Rectangle,
+        cursor_position: Point,
+    ) -> Option<usize> {
+        if !header.contains(cursor_position) {
+            return None;
+        }
+
+        let mut x = header.x;
+
+        widths.iter().position(|width| {
+            x += width;
+
+            cursor_position.x < x
+        })
+    }
+
+    /// Returns the index of the row under the given position, relative to the
+    /// contents of the body.
+    fn row_at(
+        &self,
+        content_bounds: Rectangle,
+        position: Point,
+    ) -> Option<usize> {
+        if !content_bounds.contains(position) {
+            return None;
+        }
+
+        let row = ((position.y - content_bounds.y)
+            / f32::from(self.row_height.max(1))) as usize;
+
+        if row < self.rows {
+            Some(row)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Table<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        // The rows are laid out lazily, when the viewport is known
+        let size = limits.resolve(Size::new(
+            0.0,
+            (self.rows + 1) as f32 * f32::from(self.row_height),
+        ));
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> EventInteraction {
+        let bounds = layout.bounds();
+        let (header, body) = self.split(bounds);
+        let content_bounds = self.content_bounds(body);
+
+        self.state.viewport = Some(body);
+
+        // Build the visible cells before borrowing them along with the state
+        let _ = self.cells(renderer, body);
+        let cells = self.cells.get_mut().as_mut().unwrap();
+        let (widths, content) = cells.content.as_ref().unwrap();
+
+        match event {
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                self.state.keyboard_modifiers = modifiers;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some(resizing) = self.state.resizing {
+                    let width = (resizing.width + cursor_position.x
+                        - resizing.origin)
+                        .max(MIN_COLUMN_WIDTH);
+
+                    self.state.resize(resizing.column, width);
+
+                    return EventInteraction { consumed: true };
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                if self.state.resizing.is_some() =>
+            {
+                self.state.resizing = None;
+
+                return EventInteraction { consumed: true };
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if header.contains(cursor_position) =>
+            {
+                if let Some(column) = Self::border_at(
+                    &self.columns,
+                    widths,
+                    header,
+                    cursor_position,
+                ) {
+                    self.state.resizing = Some(Resizing {
+                        column,
+                        origin: cursor_position.x,
+                        width: widths[column],
+                    });
+                } else if let Some(column) =
+                    Self::column_at(widths, header, cursor_position)
+                {
+                    if let Some(on_sort) = &self.on_sort {
+                        if self.columns[column].is_sortable {
+                            let order = match self.sort {
+                                Some((sorted, order)) if sorted == column => {
+                                    order.reverse()
+                                }
+                                _ => Order::Ascending,
+                            };
+
+                            messages.push(on_sort(column, order));
+                        }
+                    }
+                }
+
+                return EventInteraction { consumed: true };
+            }
+            _ => {}
+        }
+
+        let cursor_position = scrollable::update(
+            &mut self.state.scrollable,
+            scrollable::Direction::Vertical,
+            &event,
+            body,
+            content_bounds,
+            cursor_position,
+            renderer,
+        );
+
+        let content = layout_at(content, body);
+
+        let interaction =
+            cells.elements.iter_mut().zip(content.children()).fold(
+                EventInteraction::default(),
+                |interaction, (cell, layout)| {
+                    cell.widget
+                        .on_event(
+                            event.clone(),
+                            layout,
+                            cursor_position,
+                            messages,
+                            renderer,
+                            clipboard,
+                        )
+                        .union(&interaction)
+                },
+            );
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) =
+            event
+        {
+            if interaction.consumed {
+                return interaction;
+            }
+
+            if let Some(row) = self.row_at(content_bounds, cursor_position) {
+                let is_changed = self.state.select(row, self.selection_mode);
+
+                if is_changed {
+                    if let Some(on_selection) = &self.on_selection {
+                        messages
+                            .push(on_selection(self.state.selected.clone()));
+                    }
+                }
+
+                return EventInteraction { consumed: true };
+            }
+        }
+
+        interaction
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+        let (header, body) = self.split(bounds);
+        let content_bounds = self.content_bounds(body);
+        let cells = self.cells(renderer, body);
+        let (widths, content) = cells.content.as_ref().unwrap();
+
+        let scrollable = &self.state.scrollable;
+        let offset = scrollable.offset(body, content_bounds);
+        let offset = Vector::new(0, offset);
+        let scrollbars = renderer.scrollbars(body, content_bounds, offset);
+
+        let is_mouse_over = body.contains(cursor_position);
+        let is_mouse_over_scrollbar = scrollbars.is_mouse_over(cursor_position);
+
+        let content_cursor = if is_mouse_over && !is_mouse_over_scrollbar {
+            Point::new(cursor_position.x, cursor_position.y + offset.y as f32)
+        } else {
+            Point::new(cursor_position.x, -1.0)
+        };
+
+        let row_height = f32::from(self.row_height);
+        let hovered = self.row_at(content_bounds, content_cursor);
+
+        let rows: Vec<_> = cells
+            .range
+            .clone()
+            .map(|index| VisibleRow {
+                index,
+                bounds: Rectangle {
+                    y: content_bounds.y + index as f32 * row_height,
+                    height: row_height,
+                    ..content_bounds
+                },
+                is_selected: self.state.is_selected(index),
+                is_hovered: hovered == Some(index),
+            })
+            .collect();
+
+        let content = self::Renderer::draw_rows(
+            renderer,
+            defaults,
+            &rows,
+            &cells.elements,
+            layout_at(content, body),
+            content_cursor,
+            &self.style,
+        );
+
+        let body_output = scrollable::Renderer::draw(
+            renderer,
+            scrollable,
+            body,
+            content_bounds,
+            is_mouse_over,
+            cursor_position,
+            scrollbars,
+            offset,
+            &Default::default(),
+            content,
+        );
+
+        let mut x = header.x;
+
+        let headers: Vec<_> = self
+            .columns
+            .iter()
+            .zip(widths)
+            .enumerate()
+            .map(|(index, (column, width))| {
+                let bounds = Rectangle {
+                    x,
+                    width: *width,
+                    ..header
+                };
+
+                x += width;
+
+                Header {
+                    label: &column.label,
+                    bounds,
+                    sort: self
+                        .sort
+                        .filter(|(sorted, _)| *sorted == index)
+                        .map(|(_, order)| order),
+                    is_hovered: bounds.contains(cursor_position),
+                }
+            })
+            .collect();
+
+        let is_resizing = self.state.resizing.is_some()
+            || Self::border_at(&self.columns, widths, header, cursor_position)
+                .is_some();
+
+        self::Renderer::draw(
+            renderer,
+            bounds,
+            &headers,
+            body_output,
+            is_resizing,
+            self.padding,
+            self.text_size.unwrap_or(renderer.default_size()),
+            self.font,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.rows.hash(state);
+        self.row_height.hash(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let (_, body) = self.split(layout.bounds());
+        self.state.viewport = Some(body);
+
+        let offset = self
+            .state
+            .scrollable
+            .offset(body, self.content_bounds(body));
+
+        // Overlays need the layout of the cells, which is only known once
+        // they have been processed with a renderer
+        let cells = self.cells.get_mut().as_mut()?;
+        let (_, content) = cells.content.as_ref()?;
+        let content = layout_at(content, body);
+
+        let overlay = overlay::Group::with_children(
+            cells
+                .elements
+                .iter_mut()
+                .zip(content.children())
+                .filter_map(|(cell, layout)| cell.widget.overlay(layout))
+                .collect(),
+        )
+        .overlay();
+
+        overlay.map(|overlay| {
+            overlay.translate(Vector::new(0.0, -(offset as f32)))
+        })
+    }
+
+    fn on_shortcut(
+        &mut self,
+        key_code: keyboard::KeyCode,
+        modifiers: keyboard::ModifiersState,
+        messages: &mut Vec<Message>,
+    ) -> EventInteraction {
+        let _ = self.visible_cells();
+
+        match self.cells.get_mut() {
+            Some(cells) => cells.elements.iter_mut().fold(
+                EventInteraction::default(),
+                |interaction, cell| {
+                    cell.widget
+                        .on_shortcut(key_code, modifiers, messages)
+                        .union(&interaction)
+                },
+            ),
+            None => EventInteraction::default(),
+        }
+    }
+
+    fn focusables<'b>(
+        &'b mut self,
+        focusables: &mut Vec<&'b mut dyn focus::Focusable>,
+    ) {
+        let _ = self.visible_cells();
+
+        if let Some(cells) = self.cells.get_mut() {
+            for cell in &mut cells.elements {
+                cell.widget.focusables(focusables);
+            }
+        }
+    }
+
+    fn targets<'b>(&'b mut self, targets: &mut Vec<(&'b Id, &'b mut dyn Any)>) {
+        let _ = self.visible_cells();
+
+        if let Some(cells) = self.cells.get_mut() {
+            for cell in &mut cells.elements {
+                cell.widget.targets(targets);
+            }
+        }
+    }
+
+    fn redraw_request(&self) -> Option<Instant> {
+        let _ = self.visible_cells();
+
+        self.cells
+            .borrow()
+            .as_ref()?
+            .elements
+            .iter()
+            .filter_map(|cell| cell.widget.redraw_request())
+            .min()
+    }
+}
+
+/// Positions the cells at the origin of the given body bounds.
+fn layout_at(content: &layout::Node, body: Rectangle) -> Layout<'_> {
+    Layout::with_offset(Vector::new(body.x, body.y), content)
+}
+
+/// The local state of a [`Table`].
+///
+/// [`Table`]: struct.Table.html
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    scrollable: scrollable::State,
+    widths: Vec<Option<f32>>,
+    resizing: Option<Resizing>,
+    selected: Vec<usize>,
+    anchor: Option<usize>,
+    keyboard_modifiers: keyboard::ModifiersState,
+
+    // The bounds of the body the last time the table was processed, which
+    // allow building the visible cells before the table is drawn again
+    viewport: Option<Rectangle>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Resizing {
+    column: usize,
+    origin: f32,
+    width: f32,
+}
+
+impl State {
+    /// Creates a new [`State`], without selected rows.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> Self {
+        State::default()
+    }
+
+    /// Returns the indices of the selected rows, in ascending order.
+    pub fn selection(&self) -> &[usize] {
+        &self.selected
+    }
+
+    /// Returns whether the row with the given index is selected.
+    pub fn is_selected(&self, row: usize) -> bool {
+        self.selected.binary_search(&row).is_ok()
+    }
+
+    /// Replaces the selected rows.
+    ///
+    /// This is useful to keep the selection in sync after sorting the rows.
+    pub fn set_selection(&mut self, rows: impl IntoIterator<Item = usize>) {
+        self.selected = rows.into_iter().collect();
+        self.selected.sort_unstable();
+        self.selected.dedup();
+        self.anchor = None;
+    }
+
+    /// Unselects every row.
+    pub fn clear_selection(&mut self) {
+        self.set_selection(None);
+    }
+
+    /// Returns the width of a resized column, if any.
+    fn width(&self, column: usize) -> Option<f32> {
+        self.widths.get(column).copied().flatten()
+    }
+
+    fn resize(&mut self, column: usize, width: f32) {
+        if self.widths.len() <= column {
+            self.widths.resize(column + 1, None);
+        }
+
+        self.widths[column] = Some(width);
+    }
+
+    /// Selects the given row after a click, taking into account the pressed
+    /// modifiers.
+    ///
+    /// Returns whether the selection has changed.
+    fn select(&mut self, row: usize, mode: SelectionMode) -> bool {
+        let modifiers = self.keyboard_modifiers;
+        let is_toggling = modifiers.control || modifiers.logo;
+
+        let selected = match (mode, self.anchor) {
+            (SelectionMode::None, _) => return false,
+            (SelectionMode::Multiple, Some(anchor)) if modifiers.shift => {
+                let range = anchor.min(row)..=anchor.max(row);
+
+                if is_toggling {
+                    let mut selected = self.selected.clone();
+                    selected.extend(range);
+                    selected
+                } else {
+                    range.collect()
+                }
+            }
+            (SelectionMode::Multiple, _) if is_toggling => {
+                self.anchor = Some(row);
+
+                let mut selected = self.selected.clone();
+
+                match selected.binary_search(&row) {
+                    Ok(index) => {
+                        let _ = selected.remove(index);
+                    }
+                    Err(index) => selected.insert(index, row),
+                }
+
+                selected
+            }
+            _ => {
+                self.anchor = Some(row);
+
+                vec![row]
+            }
+        };
+
+        let mut selected = selected;
+        selected.sort_unstable();
+        selected.dedup();
+
+        if selected == self.selected {
+            false
+        } else {
+            self.selected = selected;
+            true
+        }
+    }
+}
+
+/// The header of a [`Column`], given to the [`Renderer`] of a [`Table`].
+///
+/// [`Column`]: struct.Column.html
+/// [`Renderer`]: trait.Renderer.html
+/// [`Table`]: struct.Table.html
+#[derive(Debug, Clone, Copy)]
+pub struct Header<'a> {
+    /// The label of the [`Column`].
+    ///
+    /// [`Column`]: struct.Column.html
+    pub label: &'a str,
+
+    /// The bounds of the header.
+    pub bounds: Rectangle,
+
+    /// The [`Order`] of the [`Column`], if the [`Table`] is sorted by it.
+    ///
+    /// [`Order`]: enum.Order.html
+    /// [`Column`]: struct.Column.html
+    /// [`Table`]: struct.Table.html
+    pub sort: Option<Order>,
+
+    /// Whether the cursor is over the header.
+    pub is_hovered: bool,
+}
+
+/// A row of a [`Table`] intersecting its viewport, given to its
+/// [`Renderer`].
+///
+/// [`Table`]: struct.Table.html
+/// [`Renderer`]: trait.Renderer.html
+#[derive(Debug, Clone, Copy)]
+pub struct VisibleRow {
+    /// The index of the row.
+    pub index: usize,
+
+    /// The bounds of the row, before scrolling.
+    pub bounds: Rectangle,
+
+    /// Whether the row is selected.
+    pub is_selected: bool,
+
+    /// Whether the cursor is over the row.
+    pub is_hovered: bool,
+}
+
+/// The renderer of a [`Table`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Table`] in your user interface.
+///
+/// [`Table`]: struct.Table.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: scrollable::Renderer + text::Renderer {
+    /// The default padding of the cells of a [`Table`].
+    ///
+    /// [`Table`]: struct.Table.html
+    const DEFAULT_PADDING: Padding;
+
+    /// The default height of the rows of a [`Table`].
+    ///
+    /// [`Table`]: struct.Table.html
+    const DEFAULT_ROW_HEIGHT: u16;
+
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws the visible rows of a [`Table`] and their cells.
+    ///
+    /// It receives:
+    ///   * the [`VisibleRow`] list
+    ///   * the cells of the rows, from left to right and top to bottom
+    ///   * the [`Layout`] of the cells
+    ///   * the cursor position, relative to the rows
+    ///   * the style of the [`Table`]
+    ///
+    /// [`Table`]: struct.Table.html
+    /// [`VisibleRow`]: struct.VisibleRow.html
+    /// [`Layout`]: ../../struct.Layout.html
+    fn draw_rows<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        rows: &[VisibleRow],
+        cells: &[Element<'_, Message, Self>],
+        layout: Layout<'_>,
+        cursor_position: Point,
+        style: &<Self as Renderer>::Style,
+    ) -> Self::Output;
+
+    /// Draws a [`Table`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`Table`]
+    ///   * the [`Header`] of every column
+    ///   * the scrollable body of the [`Table`], already drawn
+    ///   * whether a column is being resized or its border is hovered
+    ///   * the padding of the cells
+    ///   * the text size and font of the headers
+    ///   * the style of the [`Table`]
+    ///
+    /// [`Table`]: struct.Table.html
+    /// [`Header`]: struct.Header.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        headers: &[Header<'_>],
+        body: Self::Output,
+        is_resizing: bool,
+        padding: Padding,
+        text_size: u16,
+        font: Self::Font,
+        style: &<Self as Renderer>::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Table<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        table: Table<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(table)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{renderer::Null, Cache, Checkbox, UserInterface};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Toggled(bool),
+        Focused(Option<Id>),
+    }
+
+    #[test]
+    fn selects_ranges_and_toggles_rows() {
+        let mut state = State::new();
+
+        assert!(state.select(2, SelectionMode::Multiple));
+        assert_eq!(state.selection(), &[2]);
+
+        state.keyboard_modifiers.shift = true;
+        assert!(state.select(5, SelectionMode::Multiple));
+        assert_eq!(state.selection(), &[2, 3, 4, 5]);
+
+        state.keyboard_modifiers.shift = false;
+        state.keyboard_modifiers.control = true;
+        assert!(state.select(3, SelectionMode::Multiple));
+        assert_eq!(state.selection(), &[2, 4, 5]);
+
+        state.keyboard_modifiers.control = false;
+        assert!(state.select(4, SelectionMode::Single));
+        assert_eq!(state.selection(), &[4]);
+        assert!(!state.select(4, SelectionMode::Single));
+    }
+
+    #[test]
+    fn focuses_widgets_inside_cells() {
+        fn view(state: &mut State) -> Table<'_, Message, Null> {
+            Table::new(
+                state,
+                vec![Column::new("Name"), Column::new("Done")],
+                1_000,
+                |row, column| {
+                    Checkbox::new(false, "Cell", Message::Toggled)
+                        .id(Id::new(format!("cell-{}-{}", row, column)))
+                        .into()
+                },
+            )
+        }
+
+        let mut state = State::new();
+        let bounds = Size::new(100.0, 100.0);
+
+        let mut user_interface = UserInterface::build(
+            view(&mut state),
+            bounds,
+            Cache::new(),
+            &mut Null,
+        );
+        user_interface.draw(&mut Null, Point::ORIGIN);
+
+        let cache = user_interface.into_cache();
+        let mut user_interface =
+            UserInterface::build(view(&mut state), bounds, cache, &mut Null);
+        let _ = user_interface.focus(focus::Action::Focus(Id::new("cell-2-1")));
+
+        // The focus is restored when the cells are built again
+        let cache = user_interface.into_cache();
+        let mut user_interface =
+            UserInterface::build(view(&mut state), bounds, cache, &mut Null);
+
+        assert_eq!(
+            user_interface
+                .focus(focus::Action::Focused(Box::new(Message::Focused))),
+            Some(Message::Focused(Some(Id::new("cell-2-1"))))
+        );
+    }
+}
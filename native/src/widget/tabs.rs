@@ -0,0 +1,961 @@
+//! Show one of many contents, switching between them with a tab bar.
+//!
+//! A [`Tabs`] widget has some local [`State`].
+//!
+//! [`Tabs`]: struct.Tabs.html
+//! [`State`]: struct.State.html
+use crate::{
+    focus, keyboard, layout, mouse, overlay, text, Clipboard, Element, Event,
+    EventInteraction, Hasher, Id, Layout, Length, Padding, Point, Rectangle,
+    Size, Widget,
+};
+
+use std::any::Any;
+use std::hash::Hash;
+use std::time::Instant;
+
+/// A tab bar on top of the content of its active tab.
+///
+/// Tabs can be selected by clicking them or by pressing `Ctrl+Tab` and
+/// `Ctrl+Shift+Tab` while the tab bar or a widget in the active tab has
+/// keyboard focus. The tab bar is focused by clicking it. Tabs can be
+/// reordered by dragging them along the tab bar and closed with their close
+/// button.
+///
+/// When the tabs do not fit in the tab bar, it can be scrolled with the mouse
+/// wheel. The active tab is always scrolled into view.
+///
+/// ```
+/// # use iced_native::{renderer::Null, tabs, Text};
+/// #
+/// # pub type Tabs<'a, Message> = iced_native::Tabs<'a, Message, Null>;
+/// #
+/// use tabs::Tab;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Select(usize),
+///     Close(usize),
+///     Reorder(usize, usize),
+/// }
+///
+/// let mut state = tabs::State::new();
+///
+/// let tabs: Tabs<'_, Message> = Tabs::new(
+///     &mut state,
+///     vec![Tab::new("main.rs").closable(true), Tab::new("lib.rs")],
+///     0,
+///     Text::new("fn main() {}"),
+/// )
+/// .on_select(Message::Select)
+/// .on_close(Message::Close)
+/// .on_reorder(Message::Reorder);
+/// ```
+///
+/// [`Tabs`]: struct.Tabs.html
+#[allow(missing_debug_implementations)]
+pub struct Tabs<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    tabs: Vec<Tab>,
+    active: usize,
+    content: Element<'a, Message, Renderer>,
+    on_select: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_close: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    on_reorder: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    icon_font: Renderer::Font,
+    style: <Renderer as self::Renderer>::Style,
+}
+
+/// A tab of a [`Tabs`] widget.
+///
+/// [`Tabs`]: struct.Tabs.html
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Tab {
+    label: Label,
+    is_closable: bool,
+}
+
+impl Tab {
+    /// Creates a new [`Tab`] with the given [`Label`].
+    ///
+    /// [`Tab`]: struct.Tab.html
+    /// [`Label`]: enum.Label.html
+    pub fn new(label: impl Into<Label>) -> Self {
+        Tab {
+            label: label.into(),
+            is_closable: false,
+        }
+    }
+
+    /// Sets whether the [`Tab`] shows a close button.
+    ///
+    /// [`Tab`]: struct.Tab.html
+    pub fn closable(mut self, is_closable: bool) -> Self {
+        self.is_closable = is_closable;
+        self
+    }
+}
+
+/// The label of a [`Tab`].
+///
+/// [`Tab`]: struct.Tab.html
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Label {
+    /// A text label.
+    Text(String),
+
+    /// An icon, drawn with the icon font of the [`Tabs`].
+    ///
+    /// [`Tabs`]: struct.Tabs.html
+    Icon(char),
+
+    /// An icon followed by some text.
+    IconText(char, String),
+}
+
+impl From<String> for Label {
+    fn from(text: String) -> Label {
+        Label::Text(text)
+    }
+}
+
+impl<'a> From<&'a str> for Label {
+    fn from(text: &'a str) -> Label {
+        Label::Text(text.to_owned())
+    }
+}
+
+impl From<char> for Label {
+    fn from(icon: char) -> Label {
+        Label::Icon(icon)
+    }
+}
+
+impl<'a, Message, Renderer> Tabs<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`Tabs`] widget with the given [`State`], list of
+    /// [`Tab`], index of the active [`Tab`] and its content.
+    ///
+    /// [`Tabs`]: struct.Tabs.html
+    /// [`State`]: struct.State.html
+    /// [`Tab`]: struct.Tab.html
+    pub fn new<E>(
+        state: &'a mut State,
+        tabs: Vec<Tab>,
+        active: usize,
+        content: E,
+    ) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        Tabs {
+            state,
+            tabs,
+            active,
+            content: content.into(),
+            on_select: None,
+            on_close: None,
+            on_reorder: None,
+            width: Length::Fill,
+            height: Length::Fill,
+            padding: Renderer::DEFAULT_PADDING,
+            text_size: None,
+            font: Default::default(),
+            icon_font: Default::default(),
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the message that will be produced when a [`Tab`] is selected.
+    ///
+    /// [`Tab`]: struct.Tab.html
+    pub fn on_select(mut self, f: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that will be produced when the close button of a
+    /// [`Tab`] is pressed.
+    ///
+    /// [`Tab`]: struct.Tab.html
+    pub fn on_close(mut self, f: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_close = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that will be produced when a [`Tab`] is dragged to a
+    /// new position.
+    ///
+    /// The closure receives the current index of the [`Tab`] and the index it
+    /// should have once moved.
+    ///
+    /// [`Tab`]: struct.Tab.html
+    pub fn on_reorder(
+        mut self,
+        f: impl Fn(usize, usize) -> Message + 'a,
+    ) -> Self {
+        self.on_reorder = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the width of the [`Tabs`].
+    ///
+    /// [`Tabs`]: struct.Tabs.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Tabs`].
+    ///
+    /// [`Tabs`]: struct.Tabs.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the padding of the tabs of the [`Tabs`].
+    ///
+    /// [`Tabs`]: struct.Tabs.html
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the tabs of the [`Tabs`].
+    ///
+    /// [`Tabs`]: struct.Tabs.html
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the font of the tabs of the [`Tabs`].
+    ///
+    /// [`Tabs`]: struct.Tabs.html
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the font used to draw the icons of the tabs of the [`Tabs`].
+    ///
+    /// [`Tabs`]: struct.Tabs.html
+    pub fn icon_font(mut self, font: Renderer::Font) -> Self {
+        self.icon_font = font;
+        self
+    }
+
+    /// Sets the style of the [`Tabs`].
+    ///
+    /// [`Tabs`]: struct.Tabs.html
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as self::Renderer>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn size(&self, renderer: &Renderer) -> u16 {
+        self.text_size.unwrap_or(renderer.default_size())
+    }
+
+    fn label_width(&self, renderer: &Renderer, label: &Label) -> f32 {
+        let text_size = self.size(renderer);
+
+        let measure = |content: &str, font| {
+            let (width, _) =
+                renderer.measure(content, text_size, font, Size::INFINITY);
+
+            width
+        };
+
+        match label {
+            Label::Text(text) => measure(text, self.font),
+            Label::Icon(icon) => measure(&icon.to_string(), self.icon_font),
+            Label::IconText(icon, text) => {
+                measure(&icon.to_string(), self.icon_font)
+                    + f32::from(self.padding.left)
+                    + measure(text, self.font)
+            }
+        }
+    }
+
+    /// Returns whether the tab bar or a widget in the active tab has
+    /// keyboard focus.
+    fn has_focus(&mut self) -> bool {
+        let mut focusables = Vec::new();
+        self.content.widget.focusables(&mut focusables);
+
+        self.state.focus.is_focused
+            || focusables.iter().any(|focusable| focusable.is_focused())
+    }
+
+    /// Returns the horizontal scroll offset of the tab bar, keeping the
+    /// active tab visible if it has just changed.
+    fn offset(&self, bar: Layout<'_>) -> f32 {
+        let bounds = bar.bounds();
+
+        let total_width = bar
+            .children()
+            .last()
+            .map(|tab| tab.bounds().x + tab.bounds().width - bounds.x)
+            .unwrap_or(0.0);
+
+        let mut offset = self.state.offset;
+
+        if self.state.revealed != Some(self.active) {
+            if let Some(tab) = bar.children().nth(self.active) {
+                let tab = tab.bounds();
+                let start = tab.x - bounds.x;
+                let end = start + tab.width;
+
+                if start < offset {
+                    offset = start;
+                } else if end > offset + bounds.width {
+                    offset = end - bounds.width;
+                }
+            }
+        }
+
+        offset.min(total_width - bounds.width).max(0.0)
+    }
+
+    /// Returns the bounds of the tabs in the tab bar, after scrolling and
+    /// dragging.
+    fn headers(&self, bar: Layout<'_>, offset: f32) -> Vec<Rectangle> {
+        bar.children()
+            .enumerate()
+            .map(|(index, tab)| {
+                let mut bounds = tab.bounds();
+                bounds.x -= offset;
+
+                match self.state.drag {
+                    Some(drag) if drag.index == index => {
+                        bounds.x += drag.position - drag.origin;
+                    }
+                    _ => {}
+                }
+
+                bounds
+            })
+            .collect()
+    }
+
+    /// Returns the bounds of the close button of a tab, if it has one.
+    fn close_bounds(
+        &self,
+        renderer: &Renderer,
+        tab: &Tab,
+        bounds: Rectangle,
+    ) -> Option<Rectangle> {
+        if !tab.is_closable {
+            return None;
+        }
+
+        let size = f32::from(self.size(renderer));
+
+        Some(Rectangle {
+            x: bounds.x + bounds.width - f32::from(self.padding.right) - size,
+            y: bounds.center_y() - size / 2.0,
+            width: size,
+            height: size,
+        })
+    }
+
+    /// Returns the index where the dragged tab would be dropped.
+    fn drop_target(&self, bar: Layout<'_>, drag: Drag) -> usize {
+        let dragged = match bar.children().nth(drag.index) {
+            Some(tab) => tab.bounds(),
+            None => return drag.index,
+        };
+
+        let center = dragged.center_x() + drag.position - drag.origin;
+
+        bar.children()
+            .enumerate()
+            .filter(|(index, tab)| {
+                *index != drag.index && tab.bounds().center_x() < center
+            })
+            .count()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Tabs<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let padding = self.padding;
+        let text_size = f32::from(self.size(renderer));
+        let bar_height = text_size + f32::from(padding.vertical());
+
+        let mut x = 0.0;
+
+        let tabs: Vec<layout::Node> = self
+            .tabs
+            .iter()
+            .map(|tab| {
+                let close_width = if tab.is_closable {
+                    text_size + f32::from(padding.left)
+                } else {
+                    0.0
+                };
+
+                let width = self.label_width(renderer, &tab.label)
+                    + close_width
+                    + f32::from(padding.horizontal());
+
+                let mut node =
+                    layout::Node::new(Size::new(width.round(), bar_height));
+                node.move_to(Point::new(x, 0.0));
+
+                x += width.round();
+
+                node
+            })
+            .collect();
+
+        let mut content = self
+            .content
+            .layout(renderer, &limits.shrink(Size::new(0.0, bar_height)));
+        content.move_to(Point::new(0.0, bar_height));
+
+        let size = limits.resolve(Size::new(
+            content.size().width,
+            bar_height + content.size().height,
+        ));
+
+        let bar = layout::Node::with_children(
+            Size::new(size.width, bar_height),
+            tabs,
+        );
+
+        layout::Node::with_children(size, vec![bar, content])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> EventInteraction {
+        let mut children = layout.children();
+        let bar = children.next().unwrap();
+        let content = children.next().unwrap();
+
+        self.state.offset = self.offset(bar);
+        self.state.revealed = Some(self.active);
+
+        if let Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) =
+            event
+        {
+            self.state.focus.is_focused =
+                bar.bounds().contains(cursor_position);
+        }
+
+        let interaction = self.content.widget.on_event(
+            event.clone(),
+            content,
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        );
+
+        if interaction.consumed {
+            return interaction;
+        }
+
+        let bar_bounds = bar.bounds();
+        let is_over_bar = bar_bounds.contains(cursor_position);
+
+        match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if is_over_bar =>
+            {
+                let (x, y) = match delta {
+                    mouse::ScrollDelta::Lines { x, y } => (x * 60.0, y * 60.0),
+                    mouse::ScrollDelta::Pixels { x, y } => (x, y),
+                };
+
+                // Vertical wheels scroll the tab bar too
+                let delta = if x != 0.0 { x } else { y };
+
+                self.state.offset -= delta;
+                self.state.offset = self.offset(bar);
+
+                EventInteraction { consumed: true }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if is_over_bar =>
+            {
+                let headers = self.headers(bar, self.state.offset);
+
+                let pressed = headers
+                    .iter()
+                    .position(|bounds| bounds.contains(cursor_position));
+
+                if let Some(index) = pressed {
+                    let is_close_pressed = self
+                        .close_bounds(
+                            renderer,
+                            &self.tabs[index],
+                            headers[index],
+                        )
+                        .is_some_and(|bounds| bounds.contains(cursor_position));
+
+                    if is_close_pressed {
+                        if let Some(on_close) = &self.on_close {
+                            messages.push(on_close(index));
+                        }
+                    } else {
+                        if index != self.active {
+                            if let Some(on_select) = &self.on_select {
+                                messages.push(on_select(index));
+                            }
+                        }
+
+                        if self.on_reorder.is_some() {
+                            self.state.drag = Some(Drag {
+                                index,
+                                origin: cursor_position.x,
+                                position: cursor_position.x,
+                            });
+                        }
+                    }
+                }
+
+                EventInteraction { consumed: true }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some(drag) = &mut self.state.drag {
+                    drag.position = cursor_position.x;
+
+                    EventInteraction { consumed: true }
+                } else {
+                    interaction
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                if let Some(drag) = self.state.drag.take() {
+                    let target = self.drop_target(bar, drag);
+
+                    if target != drag.index {
+                        if let Some(on_reorder) = &self.on_reorder {
+                            messages.push(on_reorder(drag.index, target));
+                        }
+                    }
+
+                    EventInteraction { consumed: true }
+                } else {
+                    interaction
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Tab,
+                modifiers,
+            }) if modifiers.control && self.has_focus() => {
+                let count = self.tabs.len();
+
+                if count > 1 {
+                    let next = if modifiers.shift {
+                        (self.active + count - 1) % count
+                    } else {
+                        (self.active + 1) % count
+                    };
+
+                    if let Some(on_select) = &self.on_select {
+                        messages.push(on_select(next));
+                    }
+                }
+
+                EventInteraction { consumed: true }
+            }
+            _ => interaction,
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let mut children = layout.children();
+        let bar = children.next().unwrap();
+        let content_layout = children.next().unwrap();
+
+        let offset = self.offset(bar);
+        let bar_bounds = bar.bounds();
+        let is_over_bar = bar_bounds.contains(cursor_position);
+
+        let headers: Vec<_> = self
+            .tabs
+            .iter()
+            .zip(self.headers(bar, offset))
+            .enumerate()
+            .map(|(index, (tab, bounds))| {
+                let close_bounds = self.close_bounds(renderer, tab, bounds);
+                let is_dragged =
+                    self.state.drag.is_some_and(|drag| drag.index == index);
+
+                Header {
+                    label: &tab.label,
+                    bounds,
+                    close_bounds,
+                    is_active: index == self.active,
+                    is_hovered: is_over_bar && bounds.contains(cursor_position),
+                    is_close_hovered: is_over_bar
+                        && close_bounds.is_some_and(|bounds| {
+                            bounds.contains(cursor_position)
+                        }),
+                    is_dragged,
+                }
+            })
+            .collect();
+
+        let content = self.content.draw(
+            renderer,
+            defaults,
+            content_layout,
+            cursor_position,
+        );
+
+        self::Renderer::draw(
+            renderer,
+            layout.bounds(),
+            bar_bounds,
+            &headers,
+            content,
+            self.padding,
+            self.size(renderer),
+            self.font,
+            self.icon_font,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.padding.hash(state);
+        self.text_size.hash(state);
+        self.tabs.hash(state);
+        self.content.hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content.overlay(layout.children().nth(1).unwrap())
+    }
+
+    fn on_shortcut(
+        &mut self,
+        key_code: keyboard::KeyCode,
+        modifiers: keyboard::ModifiersState,
+        messages: &mut Vec<Message>,
+    ) -> EventInteraction {
+        self.content
+            .widget
+            .on_shortcut(key_code, modifiers, messages)
+    }
+
+    fn focusables<'b>(
+        &'b mut self,
+        focusables: &mut Vec<&'b mut dyn focus::Focusable>,
+    ) {
+        focusables.push(&mut self.state.focus);
+
+        self.content.widget.focusables(focusables);
+    }
+
+    fn targets<'b>(&'b mut self, targets: &mut Vec<(&'b Id, &'b mut dyn Any)>) {
+        self.content.widget.targets(targets);
+    }
+
+    fn redraw_request(&self) -> Option<Instant> {
+        self.content.widget.redraw_request()
+    }
+}
+
+/// The local state of a [`Tabs`] widget.
+///
+/// [`Tabs`]: struct.Tabs.html
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    offset: f32,
+    revealed: Option<usize>,
+    drag: Option<Drag>,
+    focus: Focus,
+}
+
+/// The keyboard focus of the tab bar of a [`Tabs`] widget.
+///
+/// [`Tabs`]: struct.Tabs.html
+#[derive(Debug, Clone, Copy, Default)]
+struct Focus {
+    is_focused: bool,
+}
+
+impl focus::Focusable for Focus {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    index: usize,
+    origin: f32,
+    position: f32,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> Self {
+        State::default()
+    }
+
+    /// Returns whether a tab is being dragged.
+    pub fn is_dragging(&self) -> bool {
+        self.drag.is_some()
+    }
+}
+
+/// A tab in the tab bar of a [`Tabs`] widget, given to its [`Renderer`].
+///
+/// [`Tabs`]: struct.Tabs.html
+/// [`Renderer`]: trait.Renderer.html
+#[derive(Debug, Clone, Copy)]
+pub struct Header<'a> {
+    /// The [`Label`] of the tab.
+    ///
+    /// [`Label`]: enum.Label.html
+    pub label: &'a Label,
+
+    /// The bounds of the tab, after scrolling the tab bar.
+    pub bounds: Rectangle,
+
+    /// The bounds of the close button of the tab, if it has one.
+    pub close_bounds: Option<Rectangle>,
+
+    /// Whether the tab is active.
+    pub is_active: bool,
+
+    /// Whether the cursor is over the tab.
+    pub is_hovered: bool,
+
+    /// Whether the cursor is over the close button of the tab.
+    pub is_close_hovered: bool,
+
+    /// Whether the tab is being dragged.
+    pub is_dragged: bool,
+}
+
+/// The renderer of a [`Tabs`] widget.
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use [`Tabs`] in your user interface.
+///
+/// [`Tabs`]: struct.Tabs.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: text::Renderer + Sized {
+    /// The default padding of the tabs of a [`Tabs`] widget.
+    ///
+    /// [`Tabs`]: struct.Tabs.html
+    const DEFAULT_PADDING: Padding;
+
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`Tabs`] widget.
+    ///
+    /// It receives:
+    ///   * the bounds of the [`Tabs`]
+    ///   * the bounds of its tab bar
+    ///   * the [`Header`] of every tab
+    ///   * the content of the active tab, already drawn
+    ///   * the padding of the tabs
+    ///   * the text size of the tabs
+    ///   * the font and the icon font of the tabs
+    ///   * the style of the [`Tabs`]
+    ///
+    /// [`Tabs`]: struct.Tabs.html
+    /// [`Header`]: struct.Header.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        bar_bounds: Rectangle,
+        headers: &[Header<'_>],
+        content: Self::Output,
+        padding: Padding,
+        text_size: u16,
+        font: Self::Font,
+        icon_font: Self::Font,
+        style: &<Self as Renderer>::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Tabs<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        tabs: Tabs<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(tabs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{renderer::Null, Text};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Select(usize),
+        Reorder(usize, usize),
+    }
+
+    #[test]
+    fn reorders_dragged_tabs() {
+        let mut state = State::new();
+        let mut messages = Vec::new();
+
+        // Labels are measured with a width of zero by the `Null` renderer
+        let mut tabs: Tabs<'_, Message, Null> = Tabs::new(
+            &mut state,
+            vec![Tab::new("a"), Tab::new("b"), Tab::new("c")],
+            1,
+            Text::new("content"),
+        )
+        .padding(10)
+        .on_select(Message::Select)
+        .on_reorder(Message::Reorder);
+
+        let node = tabs.layout(
+            &Null,
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 200.0)),
+        );
+
+        let events = [
+            (
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+                Point::new(5.0, 5.0),
+            ),
+            (
+                Event::Mouse(mouse::Event::CursorMoved { x: 50.0, y: 5.0 }),
+                Point::new(50.0, 5.0),
+            ),
+            (
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+                Point::new(50.0, 5.0),
+            ),
+        ];
+
+        for (event, cursor_position) in events.iter().cloned() {
+            let _ = tabs.on_event(
+                event,
+                Layout::new(&node),
+                cursor_position,
+                &mut messages,
+                &Null,
+                None,
+            );
+        }
+
+        assert_eq!(messages, vec![Message::Select(0), Message::Reorder(0, 2)]);
+    }
+
+    #[test]
+    fn switches_tabs_with_ctrl_tab_while_focused() {
+        let mut state = State::new();
+        let mut messages = Vec::new();
+
+        let mut tabs: Tabs<'_, Message, Null> = Tabs::new(
+            &mut state,
+            vec![Tab::new("a"), Tab::new("b")],
+            0,
+            Text::new("content"),
+        )
+        .on_select(Message::Select);
+
+        let node = tabs.layout(
+            &Null,
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 200.0)),
+        );
+
+        let ctrl_tab = Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code: keyboard::KeyCode::Tab,
+            modifiers: keyboard::ModifiersState {
+                control: true,
+                ..keyboard::ModifiersState::default()
+            },
+        });
+
+        let events = [
+            ctrl_tab.clone(),
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            ctrl_tab,
+        ];
+
+        for event in events.iter().cloned() {
+            let _ = tabs.on_event(
+                event,
+                Layout::new(&node),
+                Point::ORIGIN,
+                &mut messages,
+                &Null,
+                None,
+            );
+        }
+
+        assert_eq!(messages, vec![Message::Select(1)]);
+    }
+}
@@ -0,0 +1,1208 @@
+//! Edit multi-line text.
+//!
+//! A [`TextEditor`] has some local [`State`].
+//!
+//! [`TextEditor`]: struct.TextEditor.html
+//! [`State`]: struct.State.html
+pub mod line;
+
+pub use line::Line;
+
+use crate::text_input::history::{Edit, History};
+use crate::text_input::{editor::Editor, platform, Cursor, Value};
+use crate::{
+    focus, keyboard, layout,
+    mouse::{self, click},
+    text, Clipboard, Element, Event, EventInteraction, Hasher, Id, Layout,
+    Length, Padding, Point, Rectangle, Size, Widget,
+};
+
+/// A field that can be filled with multiple lines of text.
+///
+/// Long lines are wrapped at word boundaries and the contents can be
+/// scrolled vertically when they do not fit.
+///
+/// Pressing `Tab` inserts a tab while the [`TextEditor`] is focused, so the
+/// focus can only be moved to the previous widget with `Shift+Tab`.
+///
+/// [`TextEditor`]: struct.TextEditor.html
+///
+/// # Example
+/// ```
+/// # use iced_native::{text_editor, renderer::Null};
+/// #
+/// # pub type TextEditor<'a, Message> = iced_native::TextEditor<'a, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     TextEditorChanged(String),
+/// }
+///
+/// let mut state = text_editor::State::new();
+/// let value = "Some text\nin multiple lines";
+///
+/// let editor = TextEditor::new(
+///     &mut state,
+///     "This is the placeholder...",
+///     value,
+///     Message::TextEditorChanged,
+/// )
+/// .padding(10);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TextEditor<'a, Message, Renderer: self::Renderer> {
+    id: Option<Id>,
+    state: &'a mut State,
+    placeholder: String,
+    value: Value,
+    font: Renderer::Font,
+    width: Length,
+    max_width: u32,
+    height: Length,
+    padding: Padding,
+    size: Option<u16>,
+    on_change: Box<dyn Fn(String) -> Message>,
+    style: Renderer::Style,
+}
+
+impl<'a, Message, Renderer: self::Renderer> TextEditor<'a, Message, Renderer> {
+    /// Creates a new [`TextEditor`].
+    ///
+    /// It expects:
+    /// - some [`State`]
+    /// - a placeholder
+    /// - the current value
+    /// - a function that produces a message when the [`TextEditor`] changes
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    /// [`State`]: struct.State.html
+    pub fn new<F>(
+        state: &'a mut State,
+        placeholder: &str,
+        value: &str,
+        on_change: F,
+    ) -> Self
+    where
+        F: 'static + Fn(String) -> Message,
+    {
+        TextEditor {
+            id: None,
+            state,
+            placeholder: String::from(placeholder),
+            value: Value::new(value),
+            font: Default::default(),
+            width: Length::Fill,
+            max_width: u32::MAX,
+            height: Length::Shrink,
+            padding: Padding::ZERO,
+            size: None,
+            on_change: Box::new(on_change),
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the [`Font`] of the [`TextEditor`].
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    /// [`Font`]: ../../struct.Font.html
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the [`Id`] of the [`TextEditor`].
+    ///
+    /// It allows you to focus the [`TextEditor`] with a [`Command`]. See
+    /// [`focus::focus`].
+    ///
+    /// [`Id`]: ../struct.Id.html
+    /// [`TextEditor`]: struct.TextEditor.html
+    /// [`Command`]: ../../struct.Command.html
+    /// [`focus::focus`]: ../../focus/fn.focus.html
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the width of the [`TextEditor`].
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the maximum width of the [`TextEditor`].
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn max_width(mut self, max_width: u32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the height of the [`TextEditor`].
+    ///
+    /// By default, the [`TextEditor`] grows to fit all of its lines. Any
+    /// other [`Length`] makes its contents scrollable.
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    /// [`Length`]: ../../enum.Length.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the padding of the [`TextEditor`].
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`TextEditor`].
+    ///
+    /// Every line of the [`TextEditor`] is as tall as its text size.
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the style of the [`TextEditor`].
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the current [`State`] of the [`TextEditor`].
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    /// [`State`]: struct.State.html
+    pub fn state(&self) -> &State {
+        self.state
+    }
+
+    fn lines(&self, renderer: &Renderer, width: f32) -> Vec<Line> {
+        let size = self.size.unwrap_or(renderer.default_size());
+
+        line::wrap(&self.value, width, |text| {
+            renderer.measure_value(text, size, self.font)
+        })
+    }
+
+    fn line_height(&self, renderer: &Renderer) -> f32 {
+        f32::from(self.size.unwrap_or(renderer.default_size()))
+    }
+
+    /// Finds the grapheme index of the given `line` closest to the horizontal
+    /// position `x`, relative to the start of the line.
+    fn find_cursor_position_in_line(
+        &self,
+        renderer: &Renderer,
+        lines: &[Line],
+        line: usize,
+        x: f32,
+    ) -> usize {
+        let size = self.size.unwrap_or(renderer.default_size());
+        let start = lines[line].start;
+        let end = line::last_position(lines, line);
+
+        let width_until = |index: usize| {
+            renderer.measure_value(
+                &self.value.select(start, index).to_string(),
+                size,
+                self.font,
+            )
+        };
+
+        let mut low = start;
+        let mut high = end;
+
+        while low < high {
+            let middle = (low + high + 1) / 2;
+
+            if width_until(middle) <= x {
+                low = middle;
+            } else {
+                high = middle - 1;
+            }
+        }
+
+        if low < end && width_until(low + 1) - x < x - width_until(low) {
+            low + 1
+        } else {
+            low
+        }
+    }
+
+    fn find_cursor_position(
+        &self,
+        renderer: &Renderer,
+        text_bounds: Rectangle,
+        lines: &[Line],
+        position: Point,
+    ) -> usize {
+        let line_height = self.line_height(renderer);
+        let y = position.y - text_bounds.y + self.state.offset;
+
+        let line = ((y / line_height).max(0.0) as usize).min(lines.len() - 1);
+
+        self.find_cursor_position_in_line(
+            renderer,
+            lines,
+            line,
+            position.x - text_bounds.x,
+        )
+    }
+
+    /// Measures the horizontal position of the end of the [`Cursor`].
+    fn cursor_x(&self, renderer: &Renderer, lines: &[Line]) -> f32 {
+        let size = self.size.unwrap_or(renderer.default_size());
+        let index = self.state.cursor.end(&self.value);
+        let start = lines[line::find(lines, index)].start;
+
+        renderer.measure_value(
+            &self.value.select(start, index).to_string(),
+            size,
+            self.font,
+        )
+    }
+
+    fn move_vertically(
+        &mut self,
+        renderer: &Renderer,
+        text_bounds: Rectangle,
+        amount: isize,
+        select: bool,
+    ) {
+        let lines = self.lines(renderer, text_bounds.width);
+        let x = match self.state.preferred_x {
+            Some(x) => x,
+            None => self.cursor_x(renderer, &lines),
+        };
+
+        let current = line::find(&lines, self.state.cursor.end(&self.value));
+        let target = current as isize + amount;
+
+        let position = if target < 0 {
+            0
+        } else if target as usize >= lines.len() {
+            self.value.len()
+        } else {
+            self.find_cursor_position_in_line(
+                renderer,
+                &lines,
+                target as usize,
+                x,
+            )
+        };
+
+        if select {
+            self.state
+                .cursor
+                .select_range(self.state.cursor.start(&self.value), position);
+        } else {
+            self.state.cursor.move_to(position);
+        }
+
+        self.state.preferred_x = Some(x);
+        self.state.history.seal();
+    }
+
+    fn move_in_line(
+        &mut self,
+        renderer: &Renderer,
+        text_bounds: Rectangle,
+        to_end: bool,
+        select: bool,
+    ) {
+        let lines = self.lines(renderer, text_bounds.width);
+        let current = line::find(&lines, self.state.cursor.end(&self.value));
+
+        let position = if to_end {
+            line::last_position(&lines, current)
+        } else {
+            lines[current].start
+        };
+
+        if select {
+            self.state
+                .cursor
+                .select_range(self.state.cursor.start(&self.value), position);
+        } else {
+            self.state.cursor.move_to(position);
+        }
+    }
+
+    /// Scrolls the contents of the [`TextEditor`] just enough to make the
+    /// end of the [`Cursor`] visible.
+    fn scroll_to_cursor(
+        &mut self,
+        renderer: &Renderer,
+        text_bounds: Rectangle,
+    ) {
+        let lines = self.lines(renderer, text_bounds.width);
+        let line_height = self.line_height(renderer);
+
+        let line = line::find(&lines, self.state.cursor.end(&self.value));
+        let top = line as f32 * line_height;
+        let bottom = top + line_height;
+
+        if top < self.state.offset {
+            self.state.offset = top;
+        } else if bottom > self.state.offset + text_bounds.height {
+            self.state.offset = bottom - text_bounds.height;
+        }
+
+        self.state.scroll(
+            0.0,
+            lines.len() as f32 * line_height,
+            text_bounds.height,
+        );
+    }
+
+    fn edit(
+        &mut self,
+        edit: Edit,
+        f: impl FnOnce(&mut Editor<'_>),
+        messages: &mut Vec<Message>,
+    ) {
+        self.state
+            .history
+            .record(edit, &self.value, self.state.cursor);
+        self.state.preferred_x = None;
+
+        let mut editor = Editor::new(&mut self.value, &mut self.state.cursor);
+
+        f(&mut editor);
+
+        let message = (self.on_change)(editor.contents());
+        messages.push(message);
+    }
+
+    fn restore(
+        &mut self,
+        (value, cursor): (Value, Cursor),
+        messages: &mut Vec<Message>,
+    ) {
+        self.value = value;
+        self.state.cursor = cursor;
+        self.state.preferred_x = None;
+
+        let message = (self.on_change)(self.value.to_string());
+        messages.push(message);
+    }
+
+    fn copy(&self, clipboard: Option<&dyn Clipboard>) {
+        if let (Some(clipboard), Some((start, end))) =
+            (clipboard, self.state.cursor.selection(&self.value))
+        {
+            clipboard.write(self.value.select(start, end).to_string());
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for TextEditor<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+    Message: Clone,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let padding = self.padding;
+
+        let limits = limits
+            .pad(padding)
+            .width(self.width)
+            .max_width(self.max_width)
+            .height(self.height);
+
+        let content_height = if self.height == Length::Shrink {
+            let lines = self.lines(renderer, limits.max().width);
+
+            lines.len() as f32 * self.line_height(renderer)
+        } else {
+            0.0
+        };
+
+        let mut text =
+            layout::Node::new(limits.resolve(Size::new(0.0, content_height)));
+        text.move_to(Point::new(
+            f32::from(padding.left),
+            f32::from(padding.top),
+        ));
+
+        layout::Node::with_children(text.size().pad(padding), vec![text])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> EventInteraction {
+        let text_bounds = layout.children().next().unwrap().bounds();
+        let mut consumed = false;
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let is_clicked = layout.bounds().contains(cursor_position);
+
+                if is_clicked {
+                    consumed = true;
+                    let lines = self.lines(renderer, text_bounds.width);
+                    let position = self.find_cursor_position(
+                        renderer,
+                        text_bounds,
+                        &lines,
+                        cursor_position,
+                    );
+
+                    let click = mouse::Click::new(
+                        cursor_position,
+                        self.state.last_click,
+                    );
+
+                    match click.kind() {
+                        click::Kind::Single => {
+                            self.state.cursor.move_to(position);
+                        }
+                        click::Kind::Double => {
+                            self.state.cursor.select_range(
+                                self.value.previous_start_of_word(position),
+                                self.value.next_end_of_word(position),
+                            );
+                        }
+                        click::Kind::Triple => {
+                            let line = line::find(&lines, position);
+
+                            self.state.cursor.select_range(
+                                lines[line].start,
+                                lines[line].end,
+                            );
+                        }
+                    }
+
+                    self.state.last_click = Some(click);
+                    self.state.preferred_x = None;
+                    self.state.history.seal();
+                }
+
+                self.state.is_dragging = is_clicked;
+                self.state.is_focused = is_clicked;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                self.state.is_dragging = false;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { x, y }) => {
+                if self.state.is_dragging {
+                    consumed = true;
+                    let lines = self.lines(renderer, text_bounds.width);
+                    let position = self.find_cursor_position(
+                        renderer,
+                        text_bounds,
+                        &lines,
+                        Point::new(x, y),
+                    );
+
+                    self.state.cursor.select_range(
+                        self.state.cursor.start(&self.value),
+                        position,
+                    );
+
+                    self.scroll_to_cursor(renderer, text_bounds);
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                if layout.bounds().contains(cursor_position) {
+                    let lines = self.lines(renderer, text_bounds.width);
+                    let content_height =
+                        lines.len() as f32 * self.line_height(renderer);
+
+                    if content_height > text_bounds.height {
+                        consumed = true;
+
+                        let delta_y = match delta {
+                            mouse::ScrollDelta::Lines { y, .. } => {
+                                // TODO: Configurable speed (?)
+                                y * 60.0
+                            }
+                            mouse::ScrollDelta::Pixels { y, .. } => y,
+                        };
+
+                        self.state.scroll(
+                            delta_y,
+                            content_height,
+                            text_bounds.height,
+                        );
+                    }
+                }
+            }
+            Event::Keyboard(keyboard::Event::CharacterReceived(c)) => {
+                if self.state.is_focused
+                    && self.state.is_pasting.is_none()
+                    && !c.is_control()
+                {
+                    consumed = true;
+
+                    self.edit(
+                        Edit::Insert,
+                        |editor| editor.insert(c),
+                        messages,
+                    );
+                    self.scroll_to_cursor(renderer, text_bounds);
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) if self.state.is_focused => {
+                consumed = true;
+
+                match key_code {
+                    keyboard::KeyCode::Enter
+                    | keyboard::KeyCode::NumpadEnter => {
+                        self.edit(
+                            Edit::Insert,
+                            |editor| editor.insert('\n'),
+                            messages,
+                        );
+                    }
+                    keyboard::KeyCode::Backspace => {
+                        if platform::is_jump_modifier_pressed(modifiers)
+                            && self
+                                .state
+                                .cursor
+                                .selection(&self.value)
+                                .is_none()
+                        {
+                            self.state.cursor.select_left_by_words(&self.value);
+                        }
+
+                        // Deleting nothing is not worth undoing
+                        if self.state.cursor.selection(&self.value).is_some()
+                            || self.state.cursor.start(&self.value) > 0
+                        {
+                            self.edit(
+                                Edit::Delete,
+                                |editor| editor.backspace(),
+                                messages,
+                            );
+                        }
+                    }
+                    keyboard::KeyCode::Delete => {
+                        if platform::is_jump_modifier_pressed(modifiers)
+                            && self
+                                .state
+                                .cursor
+                                .selection(&self.value)
+                                .is_none()
+                        {
+                            self.state
+                                .cursor
+                                .select_right_by_words(&self.value);
+                        }
+
+                        if self.state.cursor.selection(&self.value).is_some()
+                            || self.state.cursor.end(&self.value)
+                                < self.value.len()
+                        {
+                            self.edit(
+                                Edit::Delete,
+                                |editor| editor.delete(),
+                                messages,
+                            );
+                        }
+                    }
+                    keyboard::KeyCode::Left => {
+                        if platform::is_jump_modifier_pressed(modifiers) {
+                            if modifiers.shift {
+                                self.state
+                                    .cursor
+                                    .select_left_by_words(&self.value);
+                            } else {
+                                self.state
+                                    .cursor
+                                    .move_left_by_words(&self.value);
+                            }
+                        } else if modifiers.shift {
+                            self.state.cursor.select_left(&self.value)
+                        } else {
+                            self.state.cursor.move_left(&self.value);
+                        }
+
+                        self.state.preferred_x = None;
+                        self.state.history.seal();
+                    }
+                    keyboard::KeyCode::Right => {
+                        if platform::is_jump_modifier_pressed(modifiers) {
+                            if modifiers.shift {
+                                self.state
+                                    .cursor
+                                    .select_right_by_words(&self.value);
+                            } else {
+                                self.state
+                                    .cursor
+                                    .move_right_by_words(&self.value);
+                            }
+                        } else if modifiers.shift {
+                            self.state.cursor.select_right(&self.value)
+                        } else {
+                            self.state.cursor.move_right(&self.value);
+                        }
+
+                        self.state.preferred_x = None;
+                        self.state.history.seal();
+                    }
+                    keyboard::KeyCode::Up => {
+                        self.move_vertically(
+                            renderer,
+                            text_bounds,
+                            -1,
+                            modifiers.shift,
+                        );
+                    }
+                    keyboard::KeyCode::Down => {
+                        self.move_vertically(
+                            renderer,
+                            text_bounds,
+                            1,
+                            modifiers.shift,
+                        );
+                    }
+                    keyboard::KeyCode::PageUp | keyboard::KeyCode::PageDown => {
+                        let page = (text_bounds.height
+                            / self.line_height(renderer))
+                        .floor()
+                        .max(1.0) as isize;
+
+                        let amount = if key_code == keyboard::KeyCode::PageUp {
+                            -page
+                        } else {
+                            page
+                        };
+
+                        self.move_vertically(
+                            renderer,
+                            text_bounds,
+                            amount,
+                            modifiers.shift,
+                        );
+                    }
+                    keyboard::KeyCode::Home | keyboard::KeyCode::End => {
+                        let to_end = key_code == keyboard::KeyCode::End;
+
+                        if platform::is_jump_modifier_pressed(modifiers) {
+                            let position =
+                                if to_end { self.value.len() } else { 0 };
+
+                            if modifiers.shift {
+                                self.state.cursor.select_range(
+                                    self.state.cursor.start(&self.value),
+                                    position,
+                                );
+                            } else {
+                                self.state.cursor.move_to(position);
+                            }
+                        } else {
+                            self.move_in_line(
+                                renderer,
+                                text_bounds,
+                                to_end,
+                                modifiers.shift,
+                            );
+                        }
+
+                        self.state.preferred_x = None;
+                        self.state.history.seal();
+                    }
+                    keyboard::KeyCode::A
+                        if platform::is_copy_paste_modifier_pressed(
+                            modifiers,
+                        ) =>
+                    {
+                        self.state.cursor.select_all(&self.value);
+                        self.state.history.seal();
+                    }
+                    keyboard::KeyCode::C
+                        if platform::is_copy_paste_modifier_pressed(
+                            modifiers,
+                        ) =>
+                    {
+                        self.copy(clipboard);
+                    }
+                    keyboard::KeyCode::X
+                        if platform::is_copy_paste_modifier_pressed(
+                            modifiers,
+                        ) =>
+                    {
+                        if self.state.cursor.selection(&self.value).is_some() {
+                            self.copy(clipboard);
+                            self.edit(
+                                Edit::Other,
+                                |editor| editor.delete(),
+                                messages,
+                            );
+                        }
+                    }
+                    keyboard::KeyCode::V => {
+                        if platform::is_copy_paste_modifier_pressed(modifiers) {
+                            if let Some(clipboard) = clipboard {
+                                let content = match self.state.is_pasting.take()
+                                {
+                                    Some(content) => content,
+                                    None => {
+                                        let content: String = clipboard
+                                            .content()
+                                            .unwrap_or(String::new())
+                                            .replace("\r\n", "\n")
+                                            .chars()
+                                            .filter(|c| {
+                                                !c.is_control() || *c == '\n'
+                                            })
+                                            .collect();
+
+                                        Value::new(&content)
+                                    }
+                                };
+
+                                self.edit(
+                                    Edit::Other,
+                                    |editor| editor.paste(content.clone()),
+                                    messages,
+                                );
+
+                                self.state.is_pasting = Some(content);
+                            }
+                        } else {
+                            self.state.is_pasting = None;
+                        }
+                    }
+                    keyboard::KeyCode::Z
+                        if platform::is_copy_paste_modifier_pressed(
+                            modifiers,
+                        ) =>
+                    {
+                        let entry = if modifiers.shift {
+                            self.state
+                                .history
+                                .redo(&self.value, self.state.cursor)
+                        } else {
+                            self.state
+                                .history
+                                .undo(&self.value, self.state.cursor)
+                        };
+
+                        if let Some(entry) = entry {
+                            self.restore(entry, messages);
+                        }
+                    }
+                    keyboard::KeyCode::Y
+                        if platform::is_copy_paste_modifier_pressed(
+                            modifiers,
+                        ) =>
+                    {
+                        if let Some(entry) = self
+                            .state
+                            .history
+                            .redo(&self.value, self.state.cursor)
+                        {
+                            self.restore(entry, messages);
+                        }
+                    }
+                    // Tabs with modifiers are left to the focus traversal
+                    keyboard::KeyCode::Tab
+                        if modifiers == keyboard::ModifiersState::default() =>
+                    {
+                        self.edit(
+                            Edit::Insert,
+                            |editor| editor.insert('\t'),
+                            messages,
+                        );
+                    }
+                    keyboard::KeyCode::Escape => {
+                        self.state.is_focused = false;
+                        self.state.is_dragging = false;
+                        self.state.is_pasting = None;
+                    }
+                    _ => {
+                        consumed = false;
+                    }
+                }
+
+                if consumed && self.state.is_focused {
+                    self.scroll_to_cursor(renderer, text_bounds);
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key_code: keyboard::KeyCode::V,
+                ..
+            }) => {
+                self.state.is_pasting = None;
+            }
+            _ => {}
+        }
+
+        EventInteraction { consumed }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+        let text_bounds = layout.children().next().unwrap().bounds();
+        let lines = self.lines(renderer, text_bounds.width);
+
+        self::Renderer::draw(
+            renderer,
+            bounds,
+            text_bounds,
+            cursor_position,
+            self.font,
+            self.size.unwrap_or(renderer.default_size()),
+            &self.placeholder,
+            &self.value,
+            &lines,
+            &self.state,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        use std::{any::TypeId, hash::Hash};
+        struct Marker;
+        TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.max_width.hash(state);
+        self.height.hash(state);
+        self.padding.hash(state);
+        self.size.hash(state);
+
+        if self.height == Length::Shrink {
+            self.value.to_string().hash(state);
+        }
+    }
+
+    fn focusables<'b>(
+        &'b mut self,
+        focusables: &mut Vec<&'b mut dyn focus::Focusable>,
+    ) {
+        focusables.push(self);
+    }
+}
+
+impl<'a, Message, Renderer> focus::Focusable
+    for TextEditor<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn is_focused(&self) -> bool {
+        self.state.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.state.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.state.is_focused = false;
+        self.state.is_dragging = false;
+        self.state.is_pasting = None;
+    }
+
+    fn id(&self) -> Option<&Id> {
+        self.id.as_ref()
+    }
+}
+
+/// The renderer of a [`TextEditor`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`TextEditor`] in your user interface.
+///
+/// [`TextEditor`]: struct.TextEditor.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: text::Renderer + Sized {
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Returns the width of the given text in a [`TextEditor`].
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    fn measure_value(&self, value: &str, size: u16, font: Self::Font) -> f32;
+
+    /// Draws a [`TextEditor`].
+    ///
+    /// It receives:
+    /// - the bounds of the [`TextEditor`]
+    /// - the bounds of the text (i.e. the visible area of the lines)
+    /// - the cursor position
+    /// - the placeholder to show when the value is empty
+    /// - the current [`Value`]
+    /// - the visual [`Line`]s of the [`Value`], each one as tall as `size`
+    /// - the current [`State`]
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    /// [`Value`]: ../text_input/struct.Value.html
+    /// [`Line`]: line/struct.Line.html
+    /// [`State`]: struct.State.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        text_bounds: Rectangle,
+        cursor_position: Point,
+        font: Self::Font,
+        size: u16,
+        placeholder: &str,
+        value: &Value,
+        lines: &[Line],
+        state: &State,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<TextEditor<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a + Clone,
+{
+    fn from(
+        text_editor: TextEditor<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(text_editor)
+    }
+}
+
+/// The state of a [`TextEditor`].
+///
+/// [`TextEditor`]: struct.TextEditor.html
+#[derive(Debug, Default, Clone)]
+pub struct State {
+    is_focused: bool,
+    is_dragging: bool,
+    is_pasting: Option<Value>,
+    last_click: Option<mouse::Click>,
+    cursor: Cursor,
+    offset: f32,
+    preferred_x: Option<f32>,
+    history: History,
+}
+
+impl State {
+    /// Creates a new [`State`], representing an unfocused [`TextEditor`].
+    ///
+    /// [`State`]: struct.State.html
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new [`State`], representing a focused [`TextEditor`].
+    ///
+    /// [`State`]: struct.State.html
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn focused() -> Self {
+        Self {
+            is_focused: true,
+            ..Self::default()
+        }
+    }
+
+    /// Returns whether the [`TextEditor`] is currently focused or not.
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    /// Returns the [`Cursor`] of the [`TextEditor`].
+    ///
+    /// [`Cursor`]: ../text_input/struct.Cursor.html
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn cursor(&self) -> Cursor {
+        self.cursor
+    }
+
+    /// Returns the amount of pixels the contents of the [`TextEditor`] are
+    /// scrolled vertically.
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn offset(&self) -> f32 {
+        self.offset
+    }
+
+    /// Moves the [`Cursor`] of the [`TextEditor`] to the front of the text.
+    ///
+    /// [`Cursor`]: ../text_input/struct.Cursor.html
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn move_cursor_to_front(&mut self) {
+        self.cursor.move_to(0);
+    }
+
+    /// Moves the [`Cursor`] of the [`TextEditor`] to the end of the text.
+    ///
+    /// [`Cursor`]: ../text_input/struct.Cursor.html
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn move_cursor_to_end(&mut self) {
+        self.cursor.move_to(usize::MAX);
+    }
+
+    /// Moves the [`Cursor`] of the [`TextEditor`] to an arbitrary location.
+    ///
+    /// [`Cursor`]: ../text_input/struct.Cursor.html
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn move_cursor_to(&mut self, position: usize) {
+        self.cursor.move_to(position);
+    }
+
+    /// Returns whether there are changes in the [`TextEditor`] that can be
+    /// undone.
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn can_undo(&self) -> bool {
+        self.history.can_undo()
+    }
+
+    /// Returns whether there are undone changes in the [`TextEditor`] that
+    /// can be redone.
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn can_redo(&self) -> bool {
+        self.history.can_redo()
+    }
+
+    /// Returns the undo/redo [`History`] of the [`TextEditor`].
+    ///
+    /// [`History`]: ../text_input/history/struct.History.html
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn history(&self) -> &History {
+        &self.history
+    }
+
+    /// Forgets all the changes that can be undone or redone in the
+    /// [`TextEditor`].
+    ///
+    /// This is useful when the value of the [`TextEditor`] is replaced by
+    /// your application.
+    ///
+    /// [`TextEditor`]: struct.TextEditor.html
+    pub fn clear_history(&mut self) {
+        self.history.clear();
+    }
+
+    /// Scrolls by `delta_y` pixels, making sure the offset stays within the
+    /// given content and viewport heights.
+    fn scroll(&mut self, delta_y: f32, content_height: f32, height: f32) {
+        self.offset = (self.offset - delta_y)
+            .min(content_height - height)
+            .max(0.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+
+    fn process(state: &mut State, value: &str, event: Event) -> Vec<String> {
+        let mut text_editor = TextEditor::new(state, "", value, |value| value);
+
+        let node = text_editor.layout(
+            &Null,
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 200.0)),
+        );
+
+        let mut messages = Vec::new();
+
+        let _ = text_editor.on_event(
+            event,
+            Layout::new(&node),
+            Point::ORIGIN,
+            &mut messages,
+            &Null,
+            None,
+        );
+
+        messages
+    }
+
+    fn press(
+        key_code: keyboard::KeyCode,
+        modifiers: keyboard::ModifiersState,
+    ) -> Event {
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code,
+            modifiers,
+        })
+    }
+
+    #[test]
+    fn undoes_and_redoes_typing() {
+        let mut state = State::focused();
+
+        // The copy and paste modifier depends on the platform
+        let undo = keyboard::ModifiersState {
+            control: true,
+            logo: true,
+            ..keyboard::ModifiersState::default()
+        };
+        let redo = keyboard::ModifiersState {
+            shift: true,
+            ..undo
+        };
+
+        // Deleting nothing is not recorded
+        let backspace = press(
+            keyboard::KeyCode::Backspace,
+            keyboard::ModifiersState::default(),
+        );
+
+        assert!(process(&mut state, "", backspace).is_empty());
+        assert!(!state.can_undo());
+
+        let typing = Event::Keyboard(keyboard::Event::CharacterReceived('a'));
+        assert_eq!(process(&mut state, "", typing), vec!["a"]);
+
+        let typing = Event::Keyboard(keyboard::Event::CharacterReceived('b'));
+        assert_eq!(process(&mut state, "a", typing), vec!["ab"]);
+
+        assert!(state.can_undo());
+        assert!(!state.can_redo());
+
+        let z = press(keyboard::KeyCode::Z, undo);
+        assert_eq!(process(&mut state, "ab", z), vec![""]);
+
+        assert!(!state.can_undo());
+        assert!(state.can_redo());
+
+        let z = press(keyboard::KeyCode::Z, redo);
+        assert_eq!(process(&mut state, "", z), vec!["ab"]);
+
+        assert!(state.can_undo());
+        assert!(!state.can_redo());
+    }
+}
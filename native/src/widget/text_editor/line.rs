@@ -0,0 +1,204 @@
+//! Break the contents of a text editor into visual lines.
+use crate::text_input::Value;
+
+/// A visual line of a [`TextEditor`].
+///
+/// It spans the graphemes of a [`Value`] from `start` until `end`, excluding
+/// any line break.
+///
+/// [`TextEditor`]: ../struct.TextEditor.html
+/// [`Value`]: ../../text_input/struct.Value.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line {
+    /// The grapheme index where the [`Line`] starts.
+    ///
+    /// [`Line`]: struct.Line.html
+    pub start: usize,
+
+    /// The grapheme index where the [`Line`] ends.
+    ///
+    /// [`Line`]: struct.Line.html
+    pub end: usize,
+}
+
+/// Breaks the given [`Value`] into visual lines that fit the given `width`.
+///
+/// A new line is started on every line break of the [`Value`] and whenever
+/// the next word does not fit in the current line. Words wider than `width`
+/// are broken into multiple lines.
+///
+/// The `measure` function must produce the width of the given text.
+///
+/// [`Value`]: ../../text_input/struct.Value.html
+// TODO: Reduce allocations, cache results (?)
+pub fn wrap(
+    value: &Value,
+    width: f32,
+    measure: impl Fn(&str) -> f32,
+) -> Vec<Line> {
+    let mut lines = Vec::new();
+    let mut start = 0;
+
+    loop {
+        let end = (start..value.len())
+            .find(|&index| is_line_break(value, index))
+            .unwrap_or(value.len());
+
+        wrap_line(value, start, end, width, &measure, &mut lines);
+
+        if end == value.len() {
+            break;
+        }
+
+        start = end + 1;
+    }
+
+    lines
+}
+
+/// Returns the index of the visual [`Line`] containing the grapheme at the
+/// given `index`.
+///
+/// [`Line`]: struct.Line.html
+pub fn find(lines: &[Line], index: usize) -> usize {
+    lines
+        .iter()
+        .rposition(|line| line.start <= index)
+        .unwrap_or(0)
+}
+
+/// Returns the last position that a cursor can occupy in the visual [`Line`]
+/// with the given index.
+///
+/// Soft-wrapped lines end where the next one starts, so their last grapheme
+/// is excluded.
+///
+/// [`Line`]: struct.Line.html
+pub fn last_position(lines: &[Line], index: usize) -> usize {
+    let line = lines[index];
+
+    match lines.get(index + 1) {
+        Some(next) if next.start == line.end && line.end > line.start => {
+            line.end - 1
+        }
+        _ => line.end,
+    }
+}
+
+fn wrap_line(
+    value: &Value,
+    start: usize,
+    end: usize,
+    width: f32,
+    measure: &impl Fn(&str) -> f32,
+    lines: &mut Vec<Line>,
+) {
+    let width_until =
+        |from: usize, to: usize| measure(&value.select(from, to).to_string());
+
+    let mut line_start = start;
+
+    loop {
+        if line_start == end || width_until(line_start, end) <= width {
+            lines.push(Line {
+                start: line_start,
+                end,
+            });
+
+            break;
+        }
+
+        // Find the longest sequence of graphemes that fits
+        let mut low = line_start + 1;
+        let mut high = end;
+
+        while low < high {
+            let middle = (low + high + 1) / 2;
+
+            if width_until(line_start, middle) <= width {
+                low = middle;
+            } else {
+                high = middle - 1;
+            }
+        }
+
+        let fit = low;
+
+        // Prefer breaking after whitespace, keeping it in the current line
+        let line_end = match (line_start + 1..=fit)
+            .rev()
+            .find(|&index| is_whitespace(value, index - 1))
+        {
+            Some(mut index) => {
+                while index < end && is_whitespace(value, index) {
+                    index += 1;
+                }
+
+                index
+            }
+            None => fit,
+        };
+
+        lines.push(Line {
+            start: line_start,
+            end: line_end,
+        });
+
+        if line_end >= end {
+            break;
+        }
+
+        line_start = line_end;
+    }
+}
+
+pub(crate) fn is_line_break(value: &Value, index: usize) -> bool {
+    match value.grapheme(index) {
+        Some("\n") | Some("\r\n") | Some("\r") => true,
+        _ => false,
+    }
+}
+
+fn is_whitespace(value: &Value, index: usize) -> bool {
+    value
+        .grapheme(index)
+        .map(|grapheme| grapheme.chars().all(char::is_whitespace))
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(text: &str, width: f32) -> Vec<(usize, usize)> {
+        wrap(&Value::new(text), width, |text| text.chars().count() as f32)
+            .into_iter()
+            .map(|line| (line.start, line.end))
+            .collect()
+    }
+
+    #[test]
+    fn line_breaks() {
+        assert_eq!(lines("", 10.0), vec![(0, 0)]);
+        assert_eq!(lines("ab\ncd", 10.0), vec![(0, 2), (3, 5)]);
+        assert_eq!(lines("ab\r\n\n", 10.0), vec![(0, 2), (3, 3), (4, 4)]);
+    }
+
+    #[test]
+    fn word_wrapping() {
+        assert_eq!(lines("hello big world", 10.0), vec![(0, 10), (10, 15)]);
+        assert_eq!(lines("abcdefgh ij", 4.0), vec![(0, 4), (4, 8), (8, 11)]);
+    }
+
+    #[test]
+    fn last_positions() {
+        let wrapped = wrap(&Value::new("hello world\nab"), 8.0, |text| {
+            text.chars().count() as f32
+        });
+
+        assert_eq!(last_position(&wrapped, 0), 5);
+        assert_eq!(last_position(&wrapped, 1), 11);
+        assert_eq!(find(&wrapped, 6), 1);
+        assert_eq!(find(&wrapped, 12), 2);
+    }
+}
@@ -4,23 +4,29 @@
 //!
 //! [`TextInput`]: struct.TextInput.html
 //! [`State`]: struct.State.html
-mod editor;
 mod value;
 
+pub(crate) mod editor;
+
 pub mod cursor;
+pub mod history;
 
 pub use cursor::Cursor;
+pub use history::History;
 pub use value::Value;
 
 use editor::Editor;
+use history::Edit;
 
+use crate::animation::{Animated, Frame, Transition};
 use crate::{
-    keyboard, layout,
+    focus, keyboard, layout,
     mouse::{self, click},
-    text, Clipboard, Element, Event, EventInteraction, Hasher, Layout, Length,
-    Point, Rectangle, Size, Widget,
+    text, window, Clipboard, Element, Event, EventInteraction, Hasher, Id,
+    Layout, Length, Padding, Point, Rectangle, Size, Widget,
 };
 
+use std::time::Instant;
 use std::u32;
 
 /// A field that can be filled with text.
@@ -49,6 +55,7 @@ use std::u32;
 /// ![Text input drawn by `iced_wgpu`](https://github.com/hecrj/iced/blob/7760618fb112074bc40b148944521f312152012a/docs/images/text_input.png?raw=true)
 #[allow(missing_debug_implementations)]
 pub struct TextInput<'a, Message, Renderer: self::Renderer> {
+    id: Option<Id>,
     state: &'a mut State,
     placeholder: String,
     value: Value,
@@ -56,10 +63,11 @@ pub struct TextInput<'a, Message, Renderer: self::Renderer> {
     font: Renderer::Font,
     width: Length,
     max_width: u32,
-    padding: u16,
+    padding: Padding,
     size: Option<u16>,
     on_change: Box<dyn Fn(String) -> Message>,
     on_submit: Option<Message>,
+    transition: Transition,
     style: Renderer::Style,
 }
 
@@ -84,6 +92,7 @@ impl<'a, Message, Renderer: self::Renderer> TextInput<'a, Message, Renderer> {
         F: 'static + Fn(String) -> Message,
     {
         TextInput {
+            id: None,
             state,
             placeholder: String::from(placeholder),
             value: Value::new(value),
@@ -91,10 +100,11 @@ impl<'a, Message, Renderer: self::Renderer> TextInput<'a, Message, Renderer> {
             font: Default::default(),
             width: Length::Fill,
             max_width: u32::MAX,
-            padding: 0,
+            padding: Padding::ZERO,
             size: None,
             on_change: Box::new(on_change),
             on_submit: None,
+            transition: Transition::default(),
             style: Renderer::Style::default(),
         }
     }
@@ -115,6 +125,21 @@ impl<'a, Message, Renderer: self::Renderer> TextInput<'a, Message, Renderer> {
         self.font = font;
         self
     }
+
+    /// Sets the [`Id`] of the [`TextInput`].
+    ///
+    /// It allows you to focus the [`TextInput`] with a [`Command`]. See
+    /// [`focus::focus`].
+    ///
+    /// [`Id`]: ../struct.Id.html
+    /// [`TextInput`]: struct.TextInput.html
+    /// [`Command`]: ../../struct.Command.html
+    /// [`focus::focus`]: ../../focus/fn.focus.html
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Sets the width of the [`TextInput`].
     ///
     /// [`TextInput`]: struct.TextInput.html
@@ -134,8 +159,8 @@ impl<'a, Message, Renderer: self::Renderer> TextInput<'a, Message, Renderer> {
     /// Sets the padding of the [`TextInput`].
     ///
     /// [`TextInput`]: struct.TextInput.html
-    pub fn padding(mut self, units: u16) -> Self {
-        self.padding = units;
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
         self
     }
 
@@ -164,12 +189,35 @@ impl<'a, Message, Renderer: self::Renderer> TextInput<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the [`Transition`] used to blend the styles of the [`TextInput`]
+    /// when its [`Status`] changes.
+    ///
+    /// By default, styles change instantly.
+    ///
+    /// [`Transition`]: ../../animation/struct.Transition.html
+    /// [`TextInput`]: struct.TextInput.html
+    /// [`Status`]: enum.Status.html
+    pub fn transition(mut self, transition: Transition) -> Self {
+        self.transition = transition;
+        self
+    }
+
     /// Returns the current [`State`] of the [`TextInput`].
     ///
     /// [`TextInput`]: struct.TextInput.html
     pub fn state(&self) -> &State {
         self.state
     }
+
+    fn status(&self, bounds: Rectangle, cursor_position: Point) -> Status {
+        if self.state.is_focused {
+            Status::Focused
+        } else if bounds.contains(cursor_position) {
+            Status::Hovered
+        } else {
+            Status::Active
+        }
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -191,7 +239,7 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        let padding = self.padding as f32;
+        let padding = self.padding;
         let text_size = self.size.unwrap_or(renderer.default_size());
 
         let limits = limits
@@ -201,7 +249,10 @@ where
             .height(Length::Units(text_size));
 
         let mut text = layout::Node::new(limits.resolve(Size::ZERO));
-        text.move_to(Point::new(padding, padding));
+        text.move_to(Point::new(
+            f32::from(padding.left),
+            f32::from(padding.top),
+        ));
 
         layout::Node::with_children(text.size().pad(padding), vec![text])
     }
@@ -216,7 +267,16 @@ where
         clipboard: Option<&dyn Clipboard>,
     ) -> EventInteraction {
         let mut consumed = false;
-        match event {
+
+        // Any input restarts the blinking of the text cursor
+        let is_input = match event {
+            Event::Keyboard(keyboard::Event::KeyPressed { .. })
+            | Event::Keyboard(keyboard::Event::CharacterReceived(_))
+            | Event::Mouse(mouse::Event::ButtonPressed(_)) => true,
+            _ => false,
+        };
+
+        let interaction = match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
                 let is_clicked = layout.bounds().contains(cursor_position);
 
@@ -232,25 +292,34 @@ where
 
                     match click.kind() {
                         click::Kind::Single => {
-                            if target > 0.0 {
+                            let position = if target > 0.0 {
                                 let value = if self.is_secure {
                                     self.value.secure()
                                 } else {
                                     self.value.clone()
                                 };
 
-                                let position = renderer.find_cursor_position(
+                                renderer.find_cursor_position(
                                     text_layout.bounds(),
                                     self.font,
                                     self.size,
                                     &value,
                                     &self.state,
                                     target,
-                                );
+                                )
+                            } else {
+                                0
+                            };
 
-                                self.state.cursor.move_to(position);
+                            if self.state.keyboard_modifiers.shift
+                                && self.state.is_focused
+                            {
+                                self.state.cursor.select_range(
+                                    self.state.cursor.start(&self.value),
+                                    position,
+                                );
                             } else {
-                                self.state.cursor.move_to(0);
+                                self.state.cursor.move_to(position);
                             }
                         }
                         click::Kind::Double => {
@@ -278,6 +347,7 @@ where
                     }
 
                     self.state.last_click = Some(click);
+                    self.state.history.seal();
                 }
 
                 self.state.is_dragging = is_clicked;
@@ -324,6 +394,12 @@ where
                     && !c.is_control()
                 {
                     consumed = true;
+                    self.state.history.record(
+                        Edit::Insert,
+                        &self.value,
+                        self.state.cursor,
+                    );
+
                     let mut editor =
                         Editor::new(&mut self.value, &mut self.state.cursor);
 
@@ -358,6 +434,17 @@ where
                         }
                     }
 
+                    // Only record the edit if something is deleted
+                    if self.state.cursor.selection(&self.value).is_some()
+                        || self.state.cursor.start(&self.value) > 0
+                    {
+                        self.state.history.record(
+                            Edit::Delete,
+                            &self.value,
+                            self.state.cursor,
+                        );
+                    }
+
                     let mut editor =
                         Editor::new(&mut self.value, &mut self.state.cursor);
 
@@ -384,6 +471,17 @@ where
                         }
                     }
 
+                    // Only record the edit if something is deleted
+                    if self.state.cursor.selection(&self.value).is_some()
+                        || self.state.cursor.end(&self.value) < self.value.len()
+                    {
+                        self.state.history.record(
+                            Edit::Delete,
+                            &self.value,
+                            self.state.cursor,
+                        );
+                    }
+
                     let mut editor =
                         Editor::new(&mut self.value, &mut self.state.cursor);
 
@@ -408,6 +506,7 @@ where
                         self.state.cursor.move_left(&self.value);
                     }
                     consumed = true;
+                    self.state.history.seal();
                     EventInteraction { consumed }
                 }
                 keyboard::KeyCode::Right => {
@@ -426,6 +525,7 @@ where
                     } else {
                         self.state.cursor.move_right(&self.value);
                     }
+                    self.state.history.seal();
                     EventInteraction { consumed }
                 }
                 keyboard::KeyCode::Home => {
@@ -438,6 +538,7 @@ where
                         self.state.cursor.move_to(0);
                     }
                     consumed = true;
+                    self.state.history.seal();
                     EventInteraction { consumed }
                 }
                 keyboard::KeyCode::End => {
@@ -450,6 +551,7 @@ where
                         self.state.cursor.move_to(self.value.len());
                     }
                     consumed = true;
+                    self.state.history.seal();
                     EventInteraction { consumed }
                 }
                 keyboard::KeyCode::V => {
@@ -469,6 +571,12 @@ where
                                 }
                             };
 
+                            self.state.history.record(
+                                Edit::Other,
+                                &self.value,
+                                self.state.cursor,
+                            );
+
                             let mut editor = Editor::new(
                                 &mut self.value,
                                 &mut self.state.cursor,
@@ -486,9 +594,86 @@ where
                     }
                     EventInteraction { consumed }
                 }
+                keyboard::KeyCode::C => {
+                    if platform::is_copy_paste_modifier_pressed(modifiers)
+                        && !self.is_secure
+                    {
+                        if let (Some(clipboard), Some((start, end))) = (
+                            clipboard,
+                            self.state.cursor.selection(&self.value),
+                        ) {
+                            clipboard.write(
+                                self.value.select(start, end).to_string(),
+                            );
+                            consumed = true;
+                        }
+                    }
+                    EventInteraction { consumed }
+                }
+                keyboard::KeyCode::X => {
+                    if platform::is_copy_paste_modifier_pressed(modifiers)
+                        && !self.is_secure
+                    {
+                        if let (Some(clipboard), Some((start, end))) = (
+                            clipboard,
+                            self.state.cursor.selection(&self.value),
+                        ) {
+                            clipboard.write(
+                                self.value.select(start, end).to_string(),
+                            );
+
+                            self.state.history.record(
+                                Edit::Other,
+                                &self.value,
+                                self.state.cursor,
+                            );
+
+                            let mut editor = Editor::new(
+                                &mut self.value,
+                                &mut self.state.cursor,
+                            );
+
+                            editor.delete();
+
+                            let message = (self.on_change)(editor.contents());
+                            messages.push(message);
+                            consumed = true;
+                        }
+                    }
+                    EventInteraction { consumed }
+                }
                 keyboard::KeyCode::A => {
                     if platform::is_copy_paste_modifier_pressed(modifiers) {
                         self.state.cursor.select_all(&self.value);
+                        self.state.history.seal();
+                        consumed = true;
+                    }
+                    EventInteraction { consumed }
+                }
+                keyboard::KeyCode::Z | keyboard::KeyCode::Y => {
+                    if platform::is_copy_paste_modifier_pressed(modifiers) {
+                        let entry = if key_code == keyboard::KeyCode::Z
+                            && !modifiers.shift
+                        {
+                            self.state
+                                .history
+                                .undo(&self.value, self.state.cursor)
+                        } else {
+                            self.state
+                                .history
+                                .redo(&self.value, self.state.cursor)
+                        };
+
+                        if let Some((value, cursor)) = entry {
+                            self.value = value;
+                            self.state.cursor = cursor;
+
+                            let message =
+                                (self.on_change)(self.value.to_string());
+                            messages.push(message);
+                        }
+
+                        consumed = true;
                     }
                     EventInteraction { consumed }
                 }
@@ -500,6 +685,10 @@ where
                 }
                 _ => EventInteraction::default(),
             },
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                self.state.keyboard_modifiers = modifiers;
+                EventInteraction::default()
+            }
             Event::Keyboard(keyboard::Event::KeyReleased {
                 key_code, ..
             }) => match key_code {
@@ -510,8 +699,29 @@ where
                 }
                 _ => EventInteraction::default(),
             },
+            Event::Window(window::Event::Focused) => {
+                self.state.is_window_focused = true;
+                self.state.reset_blink();
+                EventInteraction::default()
+            }
+            Event::Window(window::Event::Unfocused) => {
+                self.state.is_window_focused = false;
+                EventInteraction::default()
+            }
             _ => EventInteraction::default(),
+        };
+
+        if is_input && self.state.is_focused {
+            self.state.reset_blink();
         }
+
+        let status = self.status(layout.bounds(), cursor_position);
+
+        self.state
+            .status
+            .transition(status, self.transition, Instant::now());
+
+        interaction
     }
 
     fn draw(
@@ -523,6 +733,14 @@ where
     ) -> Renderer::Output {
         let bounds = layout.bounds();
         let text_bounds = layout.children().next().unwrap().bounds();
+        let status = self.status(bounds, cursor_position);
+
+        // The status may have changed without any events, like when focusing
+        let status = if *self.state.status.target() == status {
+            self.state.status.at(Instant::now())
+        } else {
+            Frame::settled(status)
+        };
 
         if self.is_secure {
             self::Renderer::draw(
@@ -535,6 +753,7 @@ where
                 &self.placeholder,
                 &self.value.secure(),
                 &self.state,
+                status,
                 &self.style,
             )
         } else {
@@ -548,6 +767,7 @@ where
                 &self.placeholder,
                 &self.value,
                 &self.state,
+                status,
                 &self.style,
             )
         }
@@ -563,6 +783,46 @@ where
         self.padding.hash(state);
         self.size.hash(state);
     }
+
+    fn focusables<'b>(
+        &'b mut self,
+        focusables: &mut Vec<&'b mut dyn focus::Focusable>,
+    ) {
+        focusables.push(self);
+    }
+
+    fn redraw_request(&self) -> Option<Instant> {
+        let now = Instant::now();
+        let status = self.state.status.redraw_request(now);
+
+        status.into_iter().chain(self.state.next_blink(now)).min()
+    }
+}
+
+impl<'a, Message, Renderer> focus::Focusable
+    for TextInput<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn is_focused(&self) -> bool {
+        self.state.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.state.is_focused = true;
+        self.state.cursor.move_to(self.value.len());
+        self.state.reset_blink();
+    }
+
+    fn unfocus(&mut self) {
+        self.state.is_focused = false;
+        self.state.is_dragging = false;
+        self.state.is_pasting = None;
+    }
+
+    fn id(&self) -> Option<&Id> {
+        self.id.as_ref()
+    }
 }
 
 /// The renderer of a [`TextInput`].
@@ -607,10 +867,13 @@ pub trait Renderer: text::Renderer + Sized {
     /// - the placeholder to show when the value is empty
     /// - the current [`Value`]
     /// - the current [`State`]
+    /// - the current [`Frame`] of the [`Status`] of the [`TextInput`]
     ///
     /// [`TextInput`]: struct.TextInput.html
     /// [`Value`]: struct.Value.html
     /// [`State`]: struct.State.html
+    /// [`Frame`]: ../../animation/struct.Frame.html
+    /// [`Status`]: enum.Status.html
     fn draw(
         &mut self,
         bounds: Rectangle,
@@ -621,6 +884,7 @@ pub trait Renderer: text::Renderer + Sized {
         placeholder: &str,
         value: &Value,
         state: &State,
+        status: Frame<Status>,
         style: &Self::Style,
     ) -> Self::Output;
 
@@ -666,19 +930,63 @@ where
     }
 }
 
+/// The possible statuses of a [`TextInput`].
+///
+/// [`TextInput`]: struct.TextInput.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Status {
+    /// The [`TextInput`] is idle.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    #[default]
+    Active,
+
+    /// The [`TextInput`] is being hovered.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    Hovered,
+
+    /// The [`TextInput`] is focused.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    Focused,
+}
+
 /// The state of a [`TextInput`].
 ///
 /// [`TextInput`]: struct.TextInput.html
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Clone)]
 pub struct State {
     is_focused: bool,
     is_dragging: bool,
     is_pasting: Option<Value>,
+    is_window_focused: bool,
     last_click: Option<mouse::Click>,
+    last_input: Option<Instant>,
     cursor: Cursor,
+    keyboard_modifiers: keyboard::ModifiersState,
+    history: History,
+    status: Animated<Status>,
     // TODO: Add stateful horizontal scrolling offset
 }
 
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            is_focused: false,
+            is_dragging: false,
+            is_pasting: None,
+            is_window_focused: true,
+            last_click: None,
+            last_input: None,
+            cursor: Cursor::default(),
+            keyboard_modifiers: keyboard::ModifiersState::default(),
+            history: History::default(),
+            status: Animated::default(),
+        }
+    }
+}
+
 impl State {
     /// Creates a new [`State`], representing an unfocused [`TextInput`].
     ///
@@ -693,10 +1001,9 @@ impl State {
     pub fn focused() -> Self {
         Self {
             is_focused: true,
-            is_dragging: false,
-            is_pasting: None,
-            last_click: None,
-            cursor: Cursor::default(),
+            last_input: Some(Instant::now()),
+            status: Animated::new(Status::Focused),
+            ..Self::default()
         }
     }
 
@@ -707,6 +1014,52 @@ impl State {
         self.is_focused
     }
 
+    /// Returns whether the text cursor of the [`TextInput`] is visible at the
+    /// given [`Instant`].
+    ///
+    /// The text cursor blinks while the [`TextInput`] is focused, starting
+    /// over on every keystroke. It is hidden when the window is unfocused.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    /// [`Instant`]: https://doc.rust-lang.org/std/time/struct.Instant.html
+    pub fn is_cursor_visible(&self, now: Instant) -> bool {
+        if !self.is_focused || !self.is_window_focused {
+            return false;
+        }
+
+        match self.last_input {
+            Some(last_input) => {
+                let elapsed = now.saturating_duration_since(last_input);
+                let interval = platform::cursor_blink_interval();
+
+                (elapsed.as_millis() / interval.as_millis()) % 2 == 0
+            }
+            None => true,
+        }
+    }
+
+    /// Returns the [`Instant`] when the text cursor toggles its visibility,
+    /// if it is blinking.
+    ///
+    /// [`Instant`]: https://doc.rust-lang.org/std/time/struct.Instant.html
+    fn next_blink(&self, now: Instant) -> Option<Instant> {
+        if !self.is_focused || !self.is_window_focused {
+            return None;
+        }
+
+        let last_input = self.last_input?;
+        let elapsed = now.saturating_duration_since(last_input);
+        let interval = platform::cursor_blink_interval();
+
+        let blinks = elapsed.as_millis() / interval.as_millis() + 1;
+
+        Some(last_input + interval * blinks as u32)
+    }
+
+    fn reset_blink(&mut self) {
+        self.last_input = Some(Instant::now());
+    }
+
     /// Returns the [`Cursor`] of the [`TextInput`].
     ///
     /// [`Cursor`]: struct.Cursor.html
@@ -715,6 +1068,25 @@ impl State {
         self.cursor
     }
 
+    /// Returns the undo/redo [`History`] of the [`TextInput`].
+    ///
+    /// [`History`]: history/struct.History.html
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn history(&self) -> &History {
+        &self.history
+    }
+
+    /// Forgets all the changes that can be undone or redone in the
+    /// [`TextInput`].
+    ///
+    /// This is useful when the value of the [`TextInput`] is replaced by
+    /// your application.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    pub fn clear_history(&mut self) {
+        self.history.clear();
+    }
+
     /// Moves the [`Cursor`] of the [`TextInput`] to the front of the input text.
     ///
     /// [`Cursor`]: struct.Cursor.html
@@ -796,9 +1168,23 @@ fn find_cursor_position<Renderer: self::Renderer>(
     }
 }
 
-mod platform {
+pub(crate) mod platform {
     use crate::keyboard;
 
+    use std::time::Duration;
+
+    /// Returns the time the text cursor stays visible, or hidden, when
+    /// blinking.
+    pub fn cursor_blink_interval() -> Duration {
+        if cfg!(target_os = "windows") {
+            Duration::from_millis(530)
+        } else if cfg!(target_os = "macos") {
+            Duration::from_millis(500)
+        } else {
+            Duration::from_millis(600)
+        }
+    }
+
     pub fn is_jump_modifier_pressed(
         modifiers: keyboard::ModifiersState,
     ) -> bool {
@@ -819,3 +1205,134 @@ mod platform {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{clipboard, renderer::Null};
+
+    fn press(
+        text_input: &mut TextInput<'_, String, Null>,
+        key_code: keyboard::KeyCode,
+        modifiers: keyboard::ModifiersState,
+        clipboard: &clipboard::Memory,
+    ) -> Vec<String> {
+        let node = text_input.layout(
+            &Null,
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 200.0)),
+        );
+
+        let mut messages = Vec::new();
+
+        let _ = text_input.on_event(
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }),
+            Layout::new(&node),
+            Point::ORIGIN,
+            &mut messages,
+            &Null,
+            Some(clipboard),
+        );
+
+        messages
+    }
+
+    /// The copy and paste modifier depends on the platform.
+    fn copy_paste() -> keyboard::ModifiersState {
+        keyboard::ModifiersState {
+            control: true,
+            logo: true,
+            ..keyboard::ModifiersState::default()
+        }
+    }
+
+    #[test]
+    fn copies_and_cuts_the_selection() {
+        let clipboard = clipboard::Memory::new();
+        let mut state = State::focused();
+        let mut text_input =
+            TextInput::new(&mut state, "", "Hello", |value| value);
+
+        assert!(press(
+            &mut text_input,
+            keyboard::KeyCode::A,
+            copy_paste(),
+            &clipboard
+        )
+        .is_empty());
+        assert!(press(
+            &mut text_input,
+            keyboard::KeyCode::C,
+            copy_paste(),
+            &clipboard
+        )
+        .is_empty());
+        assert_eq!(clipboard.content(), Some(String::from("Hello")));
+
+        clipboard.write(String::from("Other"));
+
+        assert_eq!(
+            press(
+                &mut text_input,
+                keyboard::KeyCode::X,
+                copy_paste(),
+                &clipboard
+            ),
+            vec![String::new()]
+        );
+        assert_eq!(clipboard.content(), Some(String::from("Hello")));
+    }
+
+    #[test]
+    fn does_not_copy_secure_values() {
+        let clipboard = clipboard::Memory::new();
+        let mut state = State::focused();
+        let mut text_input =
+            TextInput::new(&mut state, "", "Secret", |value| value).password();
+
+        let _ = press(
+            &mut text_input,
+            keyboard::KeyCode::A,
+            copy_paste(),
+            &clipboard,
+        );
+        let _ = press(
+            &mut text_input,
+            keyboard::KeyCode::C,
+            copy_paste(),
+            &clipboard,
+        );
+        let _ = press(
+            &mut text_input,
+            keyboard::KeyCode::X,
+            copy_paste(),
+            &clipboard,
+        );
+
+        assert_eq!(clipboard.content(), None);
+    }
+
+    #[test]
+    fn does_not_record_deleting_nothing() {
+        let clipboard = clipboard::Memory::new();
+        let mut state = State::focused();
+        let mut text_input = TextInput::new(&mut state, "", "", |value| value);
+
+        let _ = press(
+            &mut text_input,
+            keyboard::KeyCode::Backspace,
+            keyboard::ModifiersState::default(),
+            &clipboard,
+        );
+        let _ = press(
+            &mut text_input,
+            keyboard::KeyCode::Delete,
+            keyboard::ModifiersState::default(),
+            &clipboard,
+        );
+
+        assert!(!state.history().can_undo());
+    }
+}
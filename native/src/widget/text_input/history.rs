@@ -0,0 +1,215 @@
+//! Undo and redo the changes of a text input.
+use crate::text_input::{Cursor, Value};
+
+use std::collections::VecDeque;
+
+/// The maximum amount of changes that can be undone.
+const LIMIT: usize = 100;
+
+/// The undo/redo history of a text input.
+///
+/// Consecutive insertions or deletions are coalesced into a single step,
+/// until the cursor is moved.
+#[derive(Debug, Clone, Default)]
+pub struct History {
+    undo: VecDeque<Entry>,
+    redo: Vec<Entry>,
+    last_edit: Option<Edit>,
+}
+
+/// A kind of change applied to the contents of a text input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Edit {
+    /// Consecutive insertions are undone at once.
+    Insert,
+
+    /// Consecutive deletions are undone at once.
+    Delete,
+
+    /// Any other change is undone on its own.
+    Other,
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    value: Value,
+    cursor: Cursor,
+}
+
+impl History {
+    /// Returns whether there are changes that can be undone.
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Returns whether there are undone changes that can be redone.
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Returns the amount of changes that can be undone.
+    pub fn undo_steps(&self) -> usize {
+        self.undo.len()
+    }
+
+    /// Returns the amount of undone changes that can be redone.
+    pub fn redo_steps(&self) -> usize {
+        self.redo.len()
+    }
+
+    /// Forgets all the changes that can be undone or redone.
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+        self.last_edit = None;
+    }
+
+    /// Records the contents before applying the given [`Edit`].
+    ///
+    /// [`Edit`]: enum.Edit.html
+    pub(crate) fn record(&mut self, edit: Edit, value: &Value, cursor: Cursor) {
+        self.redo.clear();
+
+        let is_coalesced = edit != Edit::Other && self.last_edit == Some(edit);
+
+        if !is_coalesced {
+            self.undo.push_back(Entry {
+                value: value.clone(),
+                cursor,
+            });
+
+            if self.undo.len() > LIMIT {
+                let _ = self.undo.pop_front();
+            }
+        }
+
+        self.last_edit = Some(edit);
+    }
+
+    /// Stops coalescing the next [`Edit`] with the previous ones.
+    ///
+    /// [`Edit`]: enum.Edit.html
+    pub(crate) fn seal(&mut self) {
+        self.last_edit = None;
+    }
+
+    pub(crate) fn undo(
+        &mut self,
+        value: &Value,
+        cursor: Cursor,
+    ) -> Option<(Value, Cursor)> {
+        let entry = self.undo.pop_back()?;
+
+        self.redo.push(Entry {
+            value: value.clone(),
+            cursor,
+        });
+        self.last_edit = None;
+
+        Some((entry.value, entry.cursor))
+    }
+
+    pub(crate) fn redo(
+        &mut self,
+        value: &Value,
+        cursor: Cursor,
+    ) -> Option<(Value, Cursor)> {
+        let entry = self.redo.pop()?;
+
+        self.undo.push_back(Entry {
+            value: value.clone(),
+            cursor,
+        });
+        self.last_edit = None;
+
+        Some((entry.value, entry.cursor))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Records the given edit turning the first value into the second one,
+    /// returning the latter.
+    fn apply(history: &mut History, edit: Edit, from: &str, to: &str) -> Value {
+        let mut cursor = Cursor::default();
+        cursor.move_to(from.len());
+
+        history.record(edit, &Value::new(from), cursor);
+
+        Value::new(to)
+    }
+
+    #[test]
+    fn coalesces_consecutive_edits_of_the_same_kind() {
+        let mut history = History::default();
+
+        let _ = apply(&mut history, Edit::Insert, "", "a");
+        let _ = apply(&mut history, Edit::Insert, "a", "ab");
+        assert_eq!(history.undo_steps(), 1);
+
+        let _ = apply(&mut history, Edit::Delete, "ab", "a");
+        let _ = apply(&mut history, Edit::Delete, "a", "");
+        assert_eq!(history.undo_steps(), 2);
+
+        let _ = apply(&mut history, Edit::Other, "", "pasted");
+        let _ = apply(&mut history, Edit::Other, "pasted", "pasted twice");
+        assert_eq!(history.undo_steps(), 4);
+
+        // Sealing stops coalescing, like moving the cursor does
+        history.seal();
+        let _ = apply(&mut history, Edit::Insert, "pasted twice", "!");
+        let _ = apply(&mut history, Edit::Insert, "!", "!!");
+        assert_eq!(history.undo_steps(), 5);
+    }
+
+    #[test]
+    fn undoes_and_redoes_in_order() {
+        let mut history = History::default();
+        let cursor = Cursor::default();
+
+        let _ = apply(&mut history, Edit::Insert, "", "a");
+        history.seal();
+        let value = apply(&mut history, Edit::Insert, "a", "ab");
+
+        let (value, cursor) = history.undo(&value, cursor).unwrap();
+        assert_eq!(value.to_string(), "a");
+
+        let (value, cursor) = history.redo(&value, cursor).unwrap();
+        assert_eq!(value.to_string(), "ab");
+        assert!(!history.can_redo());
+
+        // The redone change can be undone again
+        let (value, cursor) = history.undo(&value, cursor).unwrap();
+        assert_eq!(value.to_string(), "a");
+
+        let (value, _) = history.undo(&value, cursor).unwrap();
+        assert_eq!(value.to_string(), "");
+        assert!(!history.can_undo());
+        assert_eq!(history.redo_steps(), 2);
+    }
+
+    #[test]
+    fn new_edits_discard_undone_changes() {
+        let mut history = History::default();
+
+        let value = apply(&mut history, Edit::Insert, "", "a");
+        let (value, _) = history.undo(&value, Cursor::default()).unwrap();
+        assert!(history.can_redo());
+
+        let _ = apply(&mut history, Edit::Insert, &value.to_string(), "b");
+        assert!(!history.can_redo());
+    }
+
+    #[test]
+    fn forgets_the_oldest_changes_past_the_limit() {
+        let mut history = History::default();
+
+        for _ in 0..LIMIT + 10 {
+            let _ = apply(&mut history, Edit::Other, "", "");
+        }
+
+        assert_eq!(history.undo_steps(), LIMIT);
+    }
+}
@@ -84,6 +84,22 @@ impl Value {
         Self { graphemes }
     }
 
+    /// Returns a new [`Value`] containing the graphemes from `start` until the
+    /// given `end`.
+    ///
+    /// [`Value`]: struct.Value.html
+    pub fn select(&self, start: usize, end: usize) -> Self {
+        let graphemes =
+            self.graphemes[start.min(self.len())..end.min(self.len())].to_vec();
+
+        Self { graphemes }
+    }
+
+    /// Returns the grapheme at the given `index`, if any.
+    pub(crate) fn grapheme(&self, index: usize) -> Option<&str> {
+        self.graphemes.get(index).map(String::as_str)
+    }
+
     /// Converts the [`Value`] into a `String`.
     ///
     /// [`Value`]: struct.Value.html
@@ -0,0 +1,540 @@
+//! Display a hint when hovering over other widgets.
+//!
+//! A [`Tooltip`] has some local [`State`].
+//!
+//! [`Tooltip`]: struct.Tooltip.html
+//! [`State`]: struct.State.html
+use crate::{
+    focus, keyboard, layout, mouse, overlay, Clipboard, Element, Event,
+    EventInteraction, Hasher, Id, Layout, Length, Padding, Point, Rectangle,
+    Size, Vector, Widget,
+};
+
+use std::any::Any;
+use std::hash::Hash;
+use std::time::{Duration, Instant};
+
+/// An element that displays a hint on top of its content when hovered.
+///
+/// The hint can be a simple [`Text`] or any other [`Element`]. It is not
+/// displayed while the content shows an overlay of its own, like a menu.
+///
+/// ```
+/// # use iced_native::{tooltip, renderer::Null, Text};
+/// #
+/// # pub type Tooltip<'a, Message> = iced_native::Tooltip<'a, Message, Null>;
+/// #
+/// let mut state = tooltip::State::new();
+///
+/// let tooltip: Tooltip<'_, ()> = Tooltip::new(
+///     &mut state,
+///     Text::new("Hover me!"),
+///     Text::new("Hello there!"),
+/// )
+/// .position(tooltip::Position::Bottom);
+/// ```
+///
+/// [`Text`]: ../text/struct.Text.html
+/// [`Element`]: ../../struct.Element.html
+#[allow(missing_debug_implementations)]
+pub struct Tooltip<'a, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    content: Element<'a, Message, Renderer>,
+    tooltip: Element<'a, Message, Renderer>,
+    position: Position,
+    delay: Duration,
+    gap: u16,
+    padding: Padding,
+    style: <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer> Tooltip<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    /// The default delay before a [`Tooltip`] is shown.
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    pub const DEFAULT_DELAY: Duration = Duration::from_millis(500);
+
+    /// Creates a new [`Tooltip`] with some local [`State`], the content to
+    /// hover and the hint to display.
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    /// [`State`]: struct.State.html
+    pub fn new(
+        state: &'a mut State,
+        content: impl Into<Element<'a, Message, Renderer>>,
+        tooltip: impl Into<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        Tooltip {
+            state,
+            content: content.into(),
+            tooltip: tooltip.into(),
+            position: Position::Top,
+            delay: Self::DEFAULT_DELAY,
+            gap: 5,
+            padding: Renderer::DEFAULT_PADDING,
+            style: Default::default(),
+        }
+    }
+
+    /// Sets the [`Position`] of the [`Tooltip`] relative to its content.
+    ///
+    /// [`Position`]: enum.Position.html
+    /// [`Tooltip`]: struct.Tooltip.html
+    pub fn position(mut self, position: Position) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Sets the time the content needs to be hovered before the [`Tooltip`]
+    /// is shown.
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Sets the gap between the content and the [`Tooltip`].
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    pub fn gap(mut self, gap: u16) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Sets the padding of the [`Tooltip`].
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the style of the [`Tooltip`].
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as self::Renderer>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn is_visible(&self, now: Instant) -> bool {
+        match self.state.hovered_since {
+            Some(hovered_since) if !self.state.is_dismissed => {
+                now.saturating_duration_since(hovered_since) >= self.delay
+            }
+            _ => false,
+        }
+    }
+}
+
+/// The position of a [`Tooltip`] relative to its content.
+///
+/// If the [`Tooltip`] does not fit in the preferred side of its content, it
+/// is placed on the opposite side.
+///
+/// [`Tooltip`]: struct.Tooltip.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Position {
+    /// Above the content.
+    Top,
+
+    /// Below the content.
+    Bottom,
+
+    /// To the left of the content.
+    Left,
+
+    /// To the right of the content.
+    Right,
+
+    /// Next to the mouse cursor.
+    FollowCursor,
+}
+
+/// The local state of a [`Tooltip`].
+///
+/// [`Tooltip`]: struct.Tooltip.html
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct State {
+    hovered_since: Option<Instant>,
+    is_dismissed: bool,
+    cursor_offset: Vector,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Tooltip<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> EventInteraction {
+        let bounds = layout.bounds();
+
+        if let Event::Mouse(_) = event {
+            if bounds.contains(cursor_position) {
+                if self.state.hovered_since.is_none() {
+                    self.state.hovered_since = Some(Instant::now());
+                }
+
+                self.state.cursor_offset =
+                    cursor_position - Point::new(bounds.x, bounds.y);
+            } else {
+                self.state.hovered_since = None;
+                self.state.is_dismissed = false;
+            }
+        }
+
+        match event {
+            // Pressing anything hides the tooltip until the cursor leaves
+            Event::Mouse(mouse::Event::ButtonPressed(_))
+            | Event::Keyboard(keyboard::Event::KeyPressed { .. })
+                if self.state.hovered_since.is_some() =>
+            {
+                self.state.is_dismissed = true;
+            }
+            _ => {}
+        }
+
+        self.content.widget.on_event(
+            event,
+            layout,
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self.content
+            .draw(renderer, defaults, layout, cursor_position)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let is_visible = self.is_visible(Instant::now());
+
+        // The overlays of the content, like menus, hide the tooltip
+        if let Some(overlay) = self.content.overlay(layout) {
+            return Some(overlay);
+        }
+
+        if !is_visible {
+            return None;
+        }
+
+        let bounds = layout.bounds();
+
+        Some(overlay::Element::new(
+            layout.position(),
+            Box::new(Overlay {
+                tooltip: &self.tooltip,
+                target: Size::new(bounds.width, bounds.height),
+                cursor_offset: self.state.cursor_offset,
+                position: self.position,
+                gap: self.gap,
+                padding: self.padding,
+                style: &self.style,
+            }),
+        ))
+    }
+
+    fn on_shortcut(
+        &mut self,
+        key_code: keyboard::KeyCode,
+        modifiers: keyboard::ModifiersState,
+        messages: &mut Vec<Message>,
+    ) -> EventInteraction {
+        self.content
+            .widget
+            .on_shortcut(key_code, modifiers, messages)
+    }
+
+    fn focusables<'b>(
+        &'b mut self,
+        focusables: &mut Vec<&'b mut dyn focus::Focusable>,
+    ) {
+        self.content.focusables(focusables);
+    }
+
+    fn targets<'b>(&'b mut self, targets: &mut Vec<(&'b Id, &'b mut dyn Any)>) {
+        self.content.targets(targets);
+    }
+
+    fn redraw_request(&self) -> Option<Instant> {
+        // Wake up when the tooltip needs to show up
+        let show_at = match self.state.hovered_since {
+            Some(hovered_since) if !self.state.is_dismissed => {
+                let show_at = hovered_since + self.delay;
+
+                if show_at > Instant::now() {
+                    Some(show_at)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        };
+
+        show_at
+            .into_iter()
+            .chain(self.content.redraw_request())
+            .min()
+    }
+}
+
+struct Overlay<'a, Message, Renderer: self::Renderer> {
+    tooltip: &'a Element<'a, Message, Renderer>,
+    target: Size,
+    cursor_offset: Vector,
+    position: Position,
+    gap: u16,
+    padding: Padding,
+    style: &'a <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Overlay<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let padding = self.padding;
+        let gap = f32::from(self.gap);
+
+        let limits = layout::Limits::new(Size::ZERO, bounds).pad(padding);
+
+        let mut content = self.tooltip.layout(renderer, &limits);
+        content.move_to(Point::new(
+            f32::from(padding.left),
+            f32::from(padding.top),
+        ));
+
+        let size = content.size().pad(padding);
+
+        let target = Rectangle {
+            x: position.x,
+            y: position.y,
+            width: self.target.width,
+            height: self.target.height,
+        };
+
+        let centered_x = target.center_x() - size.width / 2.0;
+        let centered_y = target.center_y() - size.height / 2.0;
+
+        let above = target.y - size.height - gap;
+        let below = target.y + target.height + gap;
+        let left = target.x - size.width - gap;
+        let right = target.x + target.width + gap;
+
+        let fits_above = above >= 0.0;
+        let fits_below = below + size.height <= bounds.height;
+        let fits_left = left >= 0.0;
+        let fits_right = right + size.width <= bounds.width;
+
+        let (x, y) = match self.position {
+            Position::Top => (
+                centered_x,
+                if fits_above || !fits_below {
+                    above
+                } else {
+                    below
+                },
+            ),
+            Position::Bottom => (
+                centered_x,
+                if fits_below || !fits_above {
+                    below
+                } else {
+                    above
+                },
+            ),
+            Position::Left => (
+                if fits_left || !fits_right {
+                    left
+                } else {
+                    right
+                },
+                centered_y,
+            ),
+            Position::Right => (
+                if fits_right || !fits_left {
+                    right
+                } else {
+                    left
+                },
+                centered_y,
+            ),
+            Position::FollowCursor => {
+                let cursor = position + self.cursor_offset;
+
+                (cursor.x + gap, cursor.y + gap)
+            }
+        };
+
+        // Keep the tooltip inside the viewport
+        let x = x.min(bounds.width - size.width).max(0.0);
+        let y = y.min(bounds.height - size.height).max(0.0);
+
+        let mut node = layout::Node::with_children(size, vec![content]);
+        node.move_to(Point::new(x, y));
+
+        node
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        self::Renderer::draw(
+            renderer,
+            defaults,
+            layout.bounds(),
+            cursor_position,
+            self.style,
+            self.tooltip,
+            layout.children().next().unwrap(),
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher, position: Point) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        (position.x as u32).hash(state);
+        (position.y as u32).hash(state);
+        (self.target.width as u32).hash(state);
+        (self.target.height as u32).hash(state);
+        self.position.hash(state);
+        self.gap.hash(state);
+        self.padding.hash(state);
+
+        if self.position == Position::FollowCursor {
+            (self.cursor_offset.x as u32).hash(state);
+            (self.cursor_offset.y as u32).hash(state);
+        }
+
+        self.tooltip.hash_layout(state);
+    }
+
+    fn is_over(&self, _layout: Layout<'_>, _cursor_position: Point) -> bool {
+        // Tooltips are not interactive, the content below keeps the cursor
+        false
+    }
+
+    fn redraw_request(&self) -> Option<Instant> {
+        self.tooltip.redraw_request()
+    }
+}
+
+/// The renderer of a [`Tooltip`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Tooltip`] in your user interface.
+///
+/// [`Tooltip`]: struct.Tooltip.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer {
+    /// The default padding of a [`Tooltip`].
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    const DEFAULT_PADDING: Padding;
+
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws the hint of a [`Tooltip`].
+    ///
+    /// It receives:
+    ///   * the bounds of the hint
+    ///   * the cursor position
+    ///   * the style of the [`Tooltip`]
+    ///   * the content of the hint and its [`Layout`]
+    ///
+    /// [`Tooltip`]: struct.Tooltip.html
+    /// [`Layout`]: ../../struct.Layout.html
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        bounds: Rectangle,
+        cursor_position: Point,
+        style: &<Self as Renderer>::Style,
+        content: &Element<'_, Message, Self>,
+        content_layout: Layout<'_>,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Tooltip<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        tooltip: Tooltip<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(tooltip)
+    }
+}
@@ -0,0 +1,809 @@
+//! Display a hierarchy of nodes that can be expanded and collapsed.
+use crate::{
+    focus, keyboard, layout, mouse, overlay, Clipboard, Element, Event,
+    EventInteraction, Hasher, Id, Layout, Length, Point, Size, Widget,
+};
+
+use std::any::Any;
+use std::hash::Hash;
+use std::time::Instant;
+
+/// A hierarchical list of nodes, like a file browser or a scene hierarchy.
+///
+/// Every [`Node`] has a key identifying it in the messages produced by the
+/// [`TreeView`]. Whether a [`Node`] is expanded and which one is selected is
+/// kept in your application state, so the children of a [`Node`] can be
+/// loaded lazily when it is expanded for the first time.
+///
+/// When focused, the selection can be moved with the arrow keys: `Up` and
+/// `Down` move between the visible nodes, `Right` expands a node or selects
+/// its first child, and `Left` collapses a node or selects its parent.
+///
+/// ```
+/// # use iced_native::{renderer::Null, Text};
+/// #
+/// # pub type TreeView<'a, K, Message> =
+/// #     iced_native::TreeView<'a, K, Message, Null>;
+/// # pub type Node<'a, K, Message> =
+/// #     iced_native::tree_view::Node<'a, K, Message, Null>;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Select(&'static str),
+///     Toggle(&'static str, bool),
+/// }
+///
+/// let tree_view: TreeView<'_, &'static str, Message> = TreeView::new(vec![
+///     Node::new("src", Text::new("src"))
+///         .push(Node::new("src/main.rs", Text::new("main.rs")))
+///         .expanded(true),
+///     // The children of `target` will be loaded once it is expanded
+///     Node::new("target", Text::new("target")).expandable(true),
+/// ])
+/// .selected(Some("src/main.rs"))
+/// .on_select(Message::Select)
+/// .on_toggle(Message::Toggle);
+/// ```
+///
+/// [`Node`]: struct.Node.html
+/// [`TreeView`]: struct.TreeView.html
+#[allow(missing_debug_implementations)]
+pub struct TreeView<'a, K, Message, Renderer: self::Renderer> {
+    focus: Focus,
+    entries: Vec<Entry<K>>,
+    contents: Vec<Element<'a, Message, Renderer>>,
+    selected: Option<K>,
+    on_select: Option<Box<dyn Fn(K) -> Message + 'a>>,
+    on_toggle: Option<Box<dyn Fn(K, bool) -> Message + 'a>>,
+    width: Length,
+    height: Length,
+    indent: u16,
+    spacing: u16,
+    style: Renderer::Style,
+}
+
+/// A node of a [`TreeView`].
+///
+/// [`TreeView`]: struct.TreeView.html
+#[allow(missing_debug_implementations)]
+pub struct Node<'a, K, Message, Renderer> {
+    key: K,
+    content: Element<'a, Message, Renderer>,
+    children: Vec<Node<'a, K, Message, Renderer>>,
+    is_expandable: bool,
+    is_expanded: bool,
+}
+
+impl<'a, K, Message, Renderer> Node<'a, K, Message, Renderer> {
+    /// Creates a new [`Node`] with the given key and content.
+    ///
+    /// [`Node`]: struct.Node.html
+    pub fn new<E>(key: K, content: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        Node {
+            key,
+            content: content.into(),
+            children: Vec::new(),
+            is_expandable: false,
+            is_expanded: false,
+        }
+    }
+
+    /// Adds a child to the [`Node`], making it expandable.
+    ///
+    /// [`Node`]: struct.Node.html
+    pub fn push(mut self, child: Node<'a, K, Message, Renderer>) -> Self {
+        self.children.push(child);
+        self.is_expandable = true;
+        self
+    }
+
+    /// Sets whether the [`Node`] can be expanded, even if its children have
+    /// not been loaded yet.
+    ///
+    /// [`Node`]: struct.Node.html
+    pub fn expandable(mut self, is_expandable: bool) -> Self {
+        self.is_expandable = is_expandable;
+        self
+    }
+
+    /// Sets whether the [`Node`] is expanded, showing its children.
+    ///
+    /// [`Node`]: struct.Node.html
+    pub fn expanded(mut self, is_expanded: bool) -> Self {
+        self.is_expanded = is_expanded;
+        self
+    }
+}
+
+/// A visible [`Node`] of a [`TreeView`].
+///
+/// [`Node`]: struct.Node.html
+/// [`TreeView`]: struct.TreeView.html
+#[derive(Debug, Clone)]
+struct Entry<K> {
+    key: K,
+    depth: usize,
+    parent: Option<usize>,
+    is_expandable: bool,
+    is_expanded: bool,
+}
+
+impl<'a, K, Message, Renderer> TreeView<'a, K, Message, Renderer>
+where
+    K: Clone + PartialEq,
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`TreeView`] with the given root nodes.
+    ///
+    /// [`TreeView`]: struct.TreeView.html
+    pub fn new(roots: Vec<Node<'a, K, Message, Renderer>>) -> Self {
+        let mut entries = Vec::new();
+        let mut contents = Vec::new();
+
+        for root in roots {
+            flatten(root, 0, None, &mut entries, &mut contents);
+        }
+
+        TreeView {
+            focus: Focus::default(),
+            entries,
+            contents,
+            selected: None,
+            on_select: None,
+            on_toggle: None,
+            width: Length::Fill,
+            height: Length::Shrink,
+            indent: Renderer::DEFAULT_INDENT,
+            spacing: 0,
+            style: Renderer::Style::default(),
+        }
+    }
+
+    /// Sets the [`Id`] of the [`TreeView`].
+    ///
+    /// [`Id`]: ../struct.Id.html
+    /// [`TreeView`]: struct.TreeView.html
+    pub fn id(mut self, id: Id) -> Self {
+        self.focus.id = Some(id);
+        self
+    }
+
+    /// Sets the key of the selected [`Node`] of the [`TreeView`].
+    ///
+    /// [`Node`]: struct.Node.html
+    /// [`TreeView`]: struct.TreeView.html
+    pub fn selected(mut self, selected: Option<K>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Sets the message that will be produced when a [`Node`] is selected,
+    /// either by clicking it or with the arrow keys.
+    ///
+    /// [`Node`]: struct.Node.html
+    pub fn on_select(mut self, f: impl Fn(K) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that will be produced when a [`Node`] is expanded or
+    /// collapsed.
+    ///
+    /// The closure receives the key of the [`Node`] and whether it should be
+    /// expanded.
+    ///
+    /// [`Node`]: struct.Node.html
+    pub fn on_toggle(mut self, f: impl Fn(K, bool) -> Message + 'a) -> Self {
+        self.on_toggle = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the width of the [`TreeView`].
+    ///
+    /// [`TreeView`]: struct.TreeView.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`TreeView`].
+    ///
+    /// [`TreeView`]: struct.TreeView.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the indentation of every level of the [`TreeView`].
+    ///
+    /// It is also the size of the disclosure arrows.
+    ///
+    /// [`TreeView`]: struct.TreeView.html
+    pub fn indent(mut self, indent: u16) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Sets the vertical spacing between the nodes of the [`TreeView`].
+    ///
+    /// [`TreeView`]: struct.TreeView.html
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the style of the [`TreeView`].
+    ///
+    /// [`TreeView`]: struct.TreeView.html
+    pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn selected_index(&self) -> Option<usize> {
+        let selected = self.selected.as_ref()?;
+
+        self.entries.iter().position(|entry| &entry.key == selected)
+    }
+
+    fn select(&self, index: usize, messages: &mut Vec<Message>) {
+        if let Some(on_select) = &self.on_select {
+            messages.push(on_select(self.entries[index].key.clone()));
+        }
+    }
+
+    fn toggle(&self, index: usize, messages: &mut Vec<Message>) {
+        let entry = &self.entries[index];
+
+        if let Some(on_toggle) = &self.on_toggle {
+            if entry.is_expandable {
+                messages.push(on_toggle(entry.key.clone(), !entry.is_expanded));
+            }
+        }
+    }
+
+    /// Handles a key press while the [`TreeView`] is focused.
+    ///
+    /// Returns whether the key was used.
+    ///
+    /// [`TreeView`]: struct.TreeView.html
+    fn navigate(
+        &self,
+        key_code: keyboard::KeyCode,
+        messages: &mut Vec<Message>,
+    ) -> bool {
+        if self.entries.is_empty() {
+            return false;
+        }
+
+        let last = self.entries.len() - 1;
+
+        let current = match self.selected_index() {
+            Some(current) => current,
+            None => match key_code {
+                keyboard::KeyCode::Up
+                | keyboard::KeyCode::Down
+                | keyboard::KeyCode::Home
+                | keyboard::KeyCode::End => {
+                    self.select(0, messages);
+
+                    return true;
+                }
+                _ => return false,
+            },
+        };
+
+        let entry = &self.entries[current];
+
+        match key_code {
+            keyboard::KeyCode::Up if current > 0 => {
+                self.select(current - 1, messages);
+            }
+            keyboard::KeyCode::Down if current < last => {
+                self.select(current + 1, messages);
+            }
+            keyboard::KeyCode::Home if current > 0 => {
+                self.select(0, messages);
+            }
+            keyboard::KeyCode::End if current < last => {
+                self.select(last, messages);
+            }
+            keyboard::KeyCode::Right if entry.is_expandable => {
+                if !entry.is_expanded {
+                    self.toggle(current, messages);
+                } else if self
+                    .entries
+                    .get(current + 1)
+                    .is_some_and(|next| next.parent == Some(current))
+                {
+                    self.select(current + 1, messages);
+                }
+            }
+            keyboard::KeyCode::Left => {
+                if entry.is_expanded {
+                    self.toggle(current, messages);
+                } else if let Some(parent) = entry.parent {
+                    self.select(parent, messages);
+                }
+            }
+            keyboard::KeyCode::Enter | keyboard::KeyCode::Space => {
+                self.toggle(current, messages);
+            }
+            keyboard::KeyCode::Up
+            | keyboard::KeyCode::Down
+            | keyboard::KeyCode::Home
+            | keyboard::KeyCode::End
+            | keyboard::KeyCode::Right => {}
+            _ => return false,
+        }
+
+        true
+    }
+}
+
+/// Appends the given [`Node`] and its visible descendants, depth-first.
+///
+/// The children of collapsed nodes are dropped.
+///
+/// [`Node`]: struct.Node.html
+fn flatten<'a, K, Message, Renderer>(
+    node: Node<'a, K, Message, Renderer>,
+    depth: usize,
+    parent: Option<usize>,
+    entries: &mut Vec<Entry<K>>,
+    contents: &mut Vec<Element<'a, Message, Renderer>>,
+) {
+    let index = entries.len();
+
+    entries.push(Entry {
+        key: node.key,
+        depth,
+        parent,
+        is_expandable: node.is_expandable,
+        is_expanded: node.is_expandable && node.is_expanded,
+    });
+    contents.push(node.content);
+
+    if node.is_expanded {
+        for child in node.children {
+            flatten(child, depth + 1, Some(index), entries, contents);
+        }
+    }
+}
+
+impl<'a, K, Message, Renderer> Widget<Message, Renderer>
+    for TreeView<'a, K, Message, Renderer>
+where
+    K: Clone + PartialEq,
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let max_width = limits.max().width;
+
+        let indent = f32::from(self.indent);
+        let spacing = f32::from(self.spacing);
+
+        let contents: Vec<layout::Node> = self
+            .entries
+            .iter()
+            .zip(&self.contents)
+            .map(|(entry, content)| {
+                let offset = indent * (entry.depth + 1) as f32;
+
+                let limits = layout::Limits::new(
+                    Size::ZERO,
+                    Size::new((max_width - offset).max(0.0), f32::INFINITY),
+                )
+                .width(content.width());
+
+                content.layout(renderer, &limits)
+            })
+            .collect();
+
+        let intrinsic_width = self
+            .entries
+            .iter()
+            .zip(&contents)
+            .map(|(entry, content)| {
+                indent * (entry.depth + 1) as f32 + content.size().width
+            })
+            .fold(0.0, f32::max);
+
+        let intrinsic_height = contents
+            .iter()
+            .map(|content| content.size().height.max(indent) + spacing)
+            .sum::<f32>()
+            - if contents.is_empty() { 0.0 } else { spacing };
+
+        let size = limits.resolve(Size::new(intrinsic_width, intrinsic_height));
+
+        let mut y = 0.0;
+
+        let rows = self
+            .entries
+            .iter()
+            .zip(contents)
+            .map(|(entry, mut content)| {
+                let content_size = content.size();
+                let height = content_size.height.max(indent);
+
+                content.move_to(Point::new(
+                    indent * (entry.depth + 1) as f32,
+                    (height - content_size.height) / 2.0,
+                ));
+
+                let mut row = layout::Node::with_children(
+                    Size::new(size.width, height),
+                    vec![content],
+                );
+
+                row.move_to(Point::new(0.0, y));
+                y += height + spacing;
+
+                row
+            })
+            .collect();
+
+        layout::Node::with_children(size, rows)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> EventInteraction {
+        let interaction = self.contents.iter_mut().zip(layout.children()).fold(
+            EventInteraction::default(),
+            |interaction, (content, row)| {
+                content
+                    .widget
+                    .on_event(
+                        event.clone(),
+                        row.children().next().unwrap(),
+                        cursor_position,
+                        messages,
+                        renderer,
+                        clipboard,
+                    )
+                    .union(&interaction)
+            },
+        );
+
+        if interaction.consumed {
+            return interaction;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                self.focus.is_focused =
+                    layout.bounds().contains(cursor_position);
+
+                let indent = f32::from(self.indent);
+
+                let clicked = layout
+                    .children()
+                    .position(|row| row.bounds().contains(cursor_position));
+
+                if let Some(index) = clicked {
+                    let row = layout.children().nth(index).unwrap().bounds();
+                    let arrow_x =
+                        row.x + indent * self.entries[index].depth as f32;
+
+                    if cursor_position.x >= arrow_x
+                        && cursor_position.x < arrow_x + indent
+                    {
+                        self.toggle(index, messages);
+                    } else {
+                        self.select(index, messages);
+                    }
+
+                    return EventInteraction { consumed: true };
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) if self.focus.is_focused => {
+                return EventInteraction {
+                    consumed: self.navigate(key_code, messages),
+                };
+            }
+            _ => {}
+        }
+
+        interaction
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let selected = self.selected_index();
+
+        let rows: Vec<Row> = self
+            .entries
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| Row {
+                depth: entry.depth,
+                is_expandable: entry.is_expandable,
+                is_expanded: entry.is_expanded,
+                is_selected: selected == Some(index),
+            })
+            .collect();
+
+        renderer.draw(
+            defaults,
+            layout,
+            cursor_position,
+            &rows,
+            &self.contents,
+            self.indent,
+            self.focus.is_focused,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.indent.hash(state);
+        self.spacing.hash(state);
+
+        for (entry, content) in self.entries.iter().zip(&self.contents) {
+            entry.depth.hash(state);
+            content.widget.hash_layout(state);
+        }
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        overlay::Group::with_children(
+            self.contents
+                .iter_mut()
+                .zip(layout.children())
+                .filter_map(|(content, row)| {
+                    content.widget.overlay(row.children().next().unwrap())
+                })
+                .collect(),
+        )
+        .overlay()
+    }
+
+    fn on_shortcut(
+        &mut self,
+        key_code: keyboard::KeyCode,
+        modifiers: keyboard::ModifiersState,
+        messages: &mut Vec<Message>,
+    ) -> EventInteraction {
+        self.contents.iter_mut().fold(
+            EventInteraction::default(),
+            |interaction, content| {
+                content
+                    .widget
+                    .on_shortcut(key_code, modifiers, messages)
+                    .union(&interaction)
+            },
+        )
+    }
+
+    fn focusables<'b>(
+        &'b mut self,
+        focusables: &mut Vec<&'b mut dyn focus::Focusable>,
+    ) {
+        focusables.push(&mut self.focus);
+
+        for content in &mut self.contents {
+            content.widget.focusables(focusables);
+        }
+    }
+
+    fn targets<'b>(&'b mut self, targets: &mut Vec<(&'b Id, &'b mut dyn Any)>) {
+        for content in &mut self.contents {
+            content.widget.targets(targets);
+        }
+    }
+
+    fn redraw_request(&self) -> Option<Instant> {
+        self.contents
+            .iter()
+            .filter_map(|content| content.widget.redraw_request())
+            .min()
+    }
+}
+
+/// The keyboard focus of a [`TreeView`].
+///
+/// Like a [`Checkbox`], a [`TreeView`] has no local state, so the
+/// [`UserInterface`] keeps track of its focus.
+///
+/// [`TreeView`]: struct.TreeView.html
+/// [`Checkbox`]: ../checkbox/struct.Checkbox.html
+/// [`UserInterface`]: ../../struct.UserInterface.html
+#[derive(Debug, Clone, Default)]
+struct Focus {
+    id: Option<Id>,
+    is_focused: bool,
+}
+
+impl focus::Focusable for Focus {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+
+    fn id(&self) -> Option<&Id> {
+        self.id.as_ref()
+    }
+
+    fn is_stateful(&self) -> bool {
+        false
+    }
+}
+
+/// A visible node of a [`TreeView`], given to its [`Renderer`].
+///
+/// [`TreeView`]: struct.TreeView.html
+/// [`Renderer`]: trait.Renderer.html
+#[derive(Debug, Clone, Copy)]
+pub struct Row {
+    /// The depth of the node, `0` for the roots.
+    pub depth: usize,
+
+    /// Whether the node can be expanded.
+    pub is_expandable: bool,
+
+    /// Whether the node is expanded.
+    pub is_expanded: bool,
+
+    /// Whether the node is selected.
+    pub is_selected: bool,
+}
+
+/// The renderer of a [`TreeView`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`TreeView`] in your user interface.
+///
+/// [`TreeView`]: struct.TreeView.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer + Sized {
+    /// The default indentation of every level of a [`TreeView`].
+    ///
+    /// [`TreeView`]: struct.TreeView.html
+    const DEFAULT_INDENT: u16;
+
+    /// The style supported by this renderer.
+    type Style: Default;
+
+    /// Draws a [`TreeView`].
+    ///
+    /// It receives:
+    ///   * the [`Layout`] of the [`TreeView`], with a child for every [`Row`]
+    ///     wrapping the [`Layout`] of its content
+    ///   * the cursor position
+    ///   * the visible [`Row`] list
+    ///   * the content of every [`Row`]
+    ///   * the indentation of every level, which is also the size of the
+    ///     disclosure arrows
+    ///   * whether the [`TreeView`] is focused
+    ///   * the style of the [`TreeView`]
+    ///
+    /// [`TreeView`]: struct.TreeView.html
+    /// [`Layout`]: ../../struct.Layout.html
+    /// [`Row`]: struct.Row.html
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        rows: &[Row],
+        contents: &[Element<'_, Message, Self>],
+        indent: u16,
+        is_focused: bool,
+        style: &Self::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, K, Message, Renderer> From<TreeView<'a, K, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    K: 'a + Clone + PartialEq,
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        tree_view: TreeView<'a, K, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(tree_view)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{renderer::Null, Text};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Select(&'static str),
+        Toggle(&'static str, bool),
+    }
+
+    fn tree_view(
+        selected: &'static str,
+    ) -> TreeView<'static, &'static str, Message, Null> {
+        TreeView::new(vec![
+            Node::new("a", Text::new("a"))
+                .push(Node::new("a/b", Text::new("b")))
+                .expanded(true),
+            Node::new("c", Text::new("c")).expandable(true),
+        ])
+        .selected(Some(selected))
+        .on_select(Message::Select)
+        .on_toggle(Message::Toggle)
+    }
+
+    fn navigate(
+        selected: &'static str,
+        key_code: keyboard::KeyCode,
+    ) -> Vec<Message> {
+        let mut messages = Vec::new();
+        let _ = tree_view(selected).navigate(key_code, &mut messages);
+
+        messages
+    }
+
+    #[test]
+    fn navigates_with_arrow_keys() {
+        use keyboard::KeyCode;
+
+        assert_eq!(navigate("a", KeyCode::Down), vec![Message::Select("a/b")]);
+        assert_eq!(navigate("a/b", KeyCode::Down), vec![Message::Select("c")]);
+        assert_eq!(navigate("a/b", KeyCode::Left), vec![Message::Select("a")]);
+        assert_eq!(navigate("a", KeyCode::Right), vec![Message::Select("a/b")]);
+        assert_eq!(
+            navigate("a", KeyCode::Left),
+            vec![Message::Toggle("a", false)]
+        );
+        assert_eq!(
+            navigate("c", KeyCode::Right),
+            vec![Message::Toggle("c", true)]
+        );
+        assert_eq!(navigate("c", KeyCode::Down), vec![]);
+    }
+}
@@ -0,0 +1,666 @@
+//! Display a scrollable list with a very large amount of rows, building only
+//! the visible ones.
+use crate::{
+    focus, keyboard, layout, overlay, scrollable, Clipboard, Element, Event,
+    EventInteraction, Hasher, Id, Layout, Length, Point, Rectangle, Size,
+    Vector, Widget,
+};
+
+use std::any::Any;
+use std::cell::{RefCell, RefMut};
+use std::hash::Hash;
+use std::ops::Range;
+use std::time::Instant;
+
+/// The amount of rows built above and below the visible ones.
+const BUFFER: usize = 3;
+
+/// A scrollable list that builds, lays out and draws only the rows
+/// intersecting its viewport.
+///
+/// Rows are produced on demand by a closure receiving the index of the row.
+///
+/// ```
+/// # use iced_native::{renderer::Null, Text};
+/// # use iced_native::virtual_list::{self, RowHeight};
+/// #
+/// # pub type VirtualList<'a, Message> =
+/// #     iced_native::VirtualList<'a, Message, Null>;
+/// #
+/// let mut state = virtual_list::State::new();
+///
+/// let list: VirtualList<'_, ()> =
+///     VirtualList::new(&mut state, 1_000_000, |index| {
+///         Text::new(format!("Row {}", index)).into()
+///     })
+///     .row_height(RowHeight::Fixed(20));
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct VirtualList<'a, Message, Renderer: scrollable::Renderer> {
+    state: &'a mut State,
+    count: usize,
+    width: Length,
+    height: Length,
+    row_height: RowHeight,
+    style: <Renderer as scrollable::Renderer>::Style,
+    view: Box<dyn Fn(usize) -> Element<'a, Message, Renderer> + 'a>,
+    rows: RefCell<Option<Rows<'a, Message, Renderer>>>,
+}
+
+/// The height of the rows of a [`VirtualList`].
+///
+/// [`VirtualList`]: struct.VirtualList.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RowHeight {
+    /// Every row has exactly the given height.
+    Fixed(u16),
+
+    /// Rows are laid out with their own height, which is estimated with the
+    /// given value for the rows that have not been built.
+    ///
+    /// The estimate should not be bigger than the actual height of most
+    /// rows, as it is used to decide how many rows fill the viewport.
+    Estimated(u16),
+}
+
+impl RowHeight {
+    fn value(self) -> f32 {
+        match self {
+            RowHeight::Fixed(height) | RowHeight::Estimated(height) => {
+                f32::from(height.max(1))
+            }
+        }
+    }
+}
+
+impl Default for RowHeight {
+    fn default() -> Self {
+        RowHeight::Estimated(20)
+    }
+}
+
+/// The local state of a [`VirtualList`].
+///
+/// [`VirtualList`]: struct.VirtualList.html
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    scrollable: scrollable::State,
+
+    // The bounds of the list the last time it was processed, which allow
+    // building the visible rows before the list is drawn again
+    viewport: Option<Rectangle>,
+}
+
+impl State {
+    /// Creates a new [`State`] with the list scrolled to the top.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> Self {
+        State::default()
+    }
+
+    /// Snaps the scroll position of the [`VirtualList`] to the given
+    /// [`Offset`].
+    ///
+    /// [`VirtualList`]: struct.VirtualList.html
+    /// [`Offset`]: ../scrollable/enum.Offset.html
+    pub fn snap_to(&mut self, offset: scrollable::Offset) {
+        self.scrollable.snap_to(offset);
+    }
+}
+
+/// The rows of a [`VirtualList`] that are currently built.
+///
+/// [`VirtualList`]: struct.VirtualList.html
+struct Rows<'a, Message, Renderer> {
+    range: Range<usize>,
+    elements: Vec<Element<'a, Message, Renderer>>,
+
+    // The width and the layout of the rows, once they are laid out
+    content: Option<(f32, layout::Node)>,
+}
+
+impl<'a, Message, Renderer> VirtualList<'a, Message, Renderer>
+where
+    Renderer: scrollable::Renderer,
+{
+    /// Creates a new [`VirtualList`] with the given [`State`], the amount of
+    /// rows and a closure producing the row with a given index.
+    ///
+    /// [`VirtualList`]: struct.VirtualList.html
+    /// [`State`]: struct.State.html
+    pub fn new<F>(state: &'a mut State, count: usize, view: F) -> Self
+    where
+        F: 'a + Fn(usize) -> Element<'a, Message, Renderer>,
+    {
+        VirtualList {
+            state,
+            count,
+            width: Length::Fill,
+            height: Length::Fill,
+            row_height: RowHeight::default(),
+            style: Default::default(),
+            view: Box::new(view),
+            rows: RefCell::new(None),
+        }
+    }
+
+    /// Sets the width of the [`VirtualList`].
+    ///
+    /// [`VirtualList`]: struct.VirtualList.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`VirtualList`].
+    ///
+    /// [`VirtualList`]: struct.VirtualList.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the [`RowHeight`] of the [`VirtualList`].
+    ///
+    /// [`VirtualList`]: struct.VirtualList.html
+    /// [`RowHeight`]: enum.RowHeight.html
+    pub fn row_height(mut self, row_height: RowHeight) -> Self {
+        self.row_height = row_height;
+        self
+    }
+
+    /// Sets the style of the [`VirtualList`].
+    ///
+    /// [`VirtualList`]: struct.VirtualList.html
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as scrollable::Renderer>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the indices of the rows that need to be built to fill the
+    /// given bounds.
+    fn visible_range(&self, bounds: Rectangle) -> Range<usize> {
+        let row_height = self.row_height.value();
+
+        // The offset is limited using the estimated height of the whole
+        // list, so the range does not depend on the rows already built
+        let estimated_bounds = Rectangle {
+            height: self.count as f32 * row_height,
+            ..bounds
+        };
+
+        let offset =
+            self.state.scrollable.offset(bounds, estimated_bounds) as f32;
+
+        let end = ((offset + bounds.height) / row_height).ceil() as usize;
+        let end = end.saturating_add(BUFFER).min(self.count);
+        let start = ((offset / row_height) as usize).saturating_sub(BUFFER);
+
+        start.min(end)..end
+    }
+
+    /// Returns the rows filling the given bounds, building them if the
+    /// visible range has changed.
+    fn elements(
+        &self,
+        bounds: Rectangle,
+    ) -> RefMut<'_, Rows<'a, Message, Renderer>> {
+        let range = self.visible_range(bounds);
+        let mut rows = self.rows.borrow_mut();
+
+        let is_outdated = match rows.as_ref() {
+            Some(rows) => rows.range != range,
+            None => true,
+        };
+
+        if is_outdated {
+            *rows = Some(Rows {
+                elements: range.clone().map(|i| (self.view)(i)).collect(),
+                range,
+                content: None,
+            });
+        }
+
+        RefMut::map(rows, |rows| rows.as_mut().unwrap())
+    }
+
+    /// Returns the rows visible the last time the [`VirtualList`] was
+    /// processed, building them if needed.
+    ///
+    /// This lets focus, targets and redraw requests reach the rows before the
+    /// [`VirtualList`] is processed again.
+    ///
+    /// [`VirtualList`]: struct.VirtualList.html
+    fn visible_rows(&self) -> Option<RefMut<'_, Rows<'a, Message, Renderer>>> {
+        let viewport = self.state.viewport?;
+
+        Some(self.elements(viewport))
+    }
+
+    /// Returns the rows filling the given bounds, building and laying them
+    /// out if needed.
+    fn rows(
+        &self,
+        renderer: &Renderer,
+        bounds: Rectangle,
+    ) -> RefMut<'_, Rows<'a, Message, Renderer>> {
+        let mut rows = self.elements(bounds);
+
+        let is_outdated = match &rows.content {
+            Some((width, _)) => *width != bounds.width,
+            None => true,
+        };
+
+        if is_outdated {
+            let content = self.layout_rows(renderer, &rows, bounds.width);
+            rows.content = Some((bounds.width, content));
+        }
+
+        rows
+    }
+
+    fn layout_rows(
+        &self,
+        renderer: &Renderer,
+        rows: &Rows<'a, Message, Renderer>,
+        width: f32,
+    ) -> layout::Node {
+        let row_height = self.row_height.value();
+
+        let limits = match self.row_height {
+            RowHeight::Fixed(_) => layout::Limits::new(
+                Size::new(0.0, row_height),
+                Size::new(width, row_height),
+            ),
+            RowHeight::Estimated(_) => {
+                layout::Limits::new(Size::ZERO, Size::new(width, f32::INFINITY))
+            }
+        };
+
+        let mut y = rows.range.start as f32 * row_height;
+
+        let nodes = rows
+            .elements
+            .iter()
+            .map(|element| {
+                let mut node = element.layout(renderer, &limits);
+                node.move_to(Point::new(0.0, y));
+
+                y += match self.row_height {
+                    RowHeight::Fixed(_) => row_height,
+                    RowHeight::Estimated(_) => node.size().height,
+                };
+
+                node
+            })
+            .collect();
+
+        let height = y + (self.count - rows.range.end) as f32 * row_height;
+
+        layout::Node::with_children(Size::new(width, height), nodes)
+    }
+}
+
+impl<'a, Message, Renderer> Rows<'a, Message, Renderer> {
+    fn content(&self) -> &layout::Node {
+        let (_, content) =
+            self.content.as_ref().expect("Rows must be laid out");
+
+        content
+    }
+
+    fn bounds(&self, bounds: Rectangle) -> Rectangle {
+        Rectangle {
+            height: self.content().size().height,
+            ..bounds
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for VirtualList<'a, Message, Renderer>
+where
+    Renderer: scrollable::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        // The rows are laid out lazily, when the viewport is known
+        let size = limits.resolve(Size::new(
+            0.0,
+            self.count as f32 * self.row_height.value(),
+        ));
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> EventInteraction {
+        let bounds = layout.bounds();
+        self.state.viewport = Some(bounds);
+
+        // Build the visible rows before borrowing them along with the state
+        let _ = self.rows(renderer, bounds);
+        let rows = self.rows.get_mut().as_mut().unwrap();
+
+        let cursor_position = scrollable::update(
+            &mut self.state.scrollable,
+            scrollable::Direction::Vertical,
+            &event,
+            bounds,
+            rows.bounds(bounds),
+            cursor_position,
+            renderer,
+        );
+
+        let (_, content) = rows.content.as_ref().unwrap();
+        let content = self::layout(content, bounds);
+
+        rows.elements.iter_mut().zip(content.children()).fold(
+            EventInteraction::default(),
+            |interaction, (row, layout)| {
+                row.widget
+                    .on_event(
+                        event.clone(),
+                        layout,
+                        cursor_position,
+                        messages,
+                        renderer,
+                        clipboard,
+                    )
+                    .union(&interaction)
+            },
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+        let rows = self.rows(renderer, bounds);
+        let content_bounds = rows.bounds(bounds);
+
+        let offset = self.state.scrollable.offset(bounds, content_bounds);
+        let scrollbars =
+            renderer.scrollbars(bounds, content_bounds, Vector::new(0, offset));
+
+        let is_mouse_over = bounds.contains(cursor_position);
+        let is_mouse_over_scrollbar = scrollbars.is_mouse_over(cursor_position);
+
+        let content = {
+            let cursor_position = if is_mouse_over && !is_mouse_over_scrollbar {
+                Point::new(cursor_position.x, cursor_position.y + offset as f32)
+            } else {
+                Point::new(cursor_position.x, -1.0)
+            };
+
+            crate::column::Renderer::draw(
+                renderer,
+                defaults,
+                &rows.elements,
+                self::layout(rows.content(), bounds),
+                cursor_position,
+            )
+        };
+
+        scrollable::Renderer::draw(
+            renderer,
+            &self.state.scrollable,
+            bounds,
+            content_bounds,
+            is_mouse_over,
+            cursor_position,
+            scrollbars,
+            Vector::new(0, offset),
+            &self.style,
+            content,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.count.hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+        self.row_height.hash(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let bounds = layout.bounds();
+        self.state.viewport = Some(bounds);
+
+        // Overlays need the layout of the rows, which is only known once
+        // they have been processed with a renderer
+        let rows = self.rows.get_mut().as_mut()?;
+        let (_, content) = rows.content.as_ref()?;
+        let offset = self.state.scrollable.offset(
+            bounds,
+            Rectangle {
+                height: content.size().height,
+                ..bounds
+            },
+        );
+        let content = self::layout(content, bounds);
+
+        let overlay = overlay::Group::with_children(
+            rows.elements
+                .iter_mut()
+                .zip(content.children())
+                .filter_map(|(row, layout)| row.widget.overlay(layout))
+                .collect(),
+        )
+        .overlay();
+
+        overlay.map(|overlay| {
+            overlay.translate(Vector::new(0.0, -(offset as f32)))
+        })
+    }
+
+    fn on_shortcut(
+        &mut self,
+        key_code: keyboard::KeyCode,
+        modifiers: keyboard::ModifiersState,
+        messages: &mut Vec<Message>,
+    ) -> EventInteraction {
+        let _ = self.visible_rows();
+
+        match self.rows.get_mut() {
+            Some(rows) => rows.elements.iter_mut().fold(
+                EventInteraction::default(),
+                |interaction, row| {
+                    row.widget
+                        .on_shortcut(key_code, modifiers, messages)
+                        .union(&interaction)
+                },
+            ),
+            None => EventInteraction::default(),
+        }
+    }
+
+    fn focusables<'b>(
+        &'b mut self,
+        focusables: &mut Vec<&'b mut dyn focus::Focusable>,
+    ) {
+        let _ = self.visible_rows();
+
+        if let Some(rows) = self.rows.get_mut() {
+            for row in &mut rows.elements {
+                row.widget.focusables(focusables);
+            }
+        }
+    }
+
+    fn targets<'b>(&'b mut self, targets: &mut Vec<(&'b Id, &'b mut dyn Any)>) {
+        let _ = self.visible_rows();
+
+        if let Some(rows) = self.rows.get_mut() {
+            for row in &mut rows.elements {
+                row.widget.targets(targets);
+            }
+        }
+    }
+
+    fn redraw_request(&self) -> Option<Instant> {
+        let _ = self.visible_rows();
+
+        self.rows
+            .borrow()
+            .as_ref()?
+            .elements
+            .iter()
+            .filter_map(|row| row.widget.redraw_request())
+            .min()
+    }
+}
+
+/// Positions the content of the rows at the origin of the given bounds.
+fn layout(content: &layout::Node, bounds: Rectangle) -> Layout<'_> {
+    Layout::with_offset(Vector::new(bounds.x, bounds.y), content)
+}
+
+impl<'a, Message, Renderer> From<VirtualList<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + scrollable::Renderer,
+    Message: 'a,
+{
+    fn from(
+        virtual_list: VirtualList<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(virtual_list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{renderer::Null, Cache, Checkbox, Scrollable, UserInterface};
+
+    use std::cell::Cell;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Toggled(bool),
+        Focused(Option<Id>),
+    }
+
+    #[test]
+    fn focuses_widgets_inside_rows() {
+        fn view(state: &mut State) -> VirtualList<'_, Message, Null> {
+            VirtualList::new(state, 1_000, |index| {
+                Checkbox::new(false, "Row", Message::Toggled)
+                    .id(Id::new(format!("row-{}", index)))
+                    .into()
+            })
+            .row_height(RowHeight::Fixed(20))
+        }
+
+        let mut state = State::new();
+        let bounds = Size::new(100.0, 100.0);
+
+        let mut user_interface = UserInterface::build(
+            view(&mut state),
+            bounds,
+            Cache::new(),
+            &mut Null,
+        );
+        user_interface.draw(&mut Null, Point::ORIGIN);
+
+        let cache = user_interface.into_cache();
+        let mut user_interface =
+            UserInterface::build(view(&mut state), bounds, cache, &mut Null);
+        let _ = user_interface.focus(focus::Action::Focus(Id::new("row-2")));
+
+        // The focus is restored when the rows are built again
+        let cache = user_interface.into_cache();
+        let mut user_interface =
+            UserInterface::build(view(&mut state), bounds, cache, &mut Null);
+
+        assert_eq!(
+            user_interface
+                .focus(focus::Action::Focused(Box::new(Message::Focused))),
+            Some(Message::Focused(Some(Id::new("row-2"))))
+        );
+    }
+
+    #[test]
+    fn scrolls_scrollables_inside_rows() {
+        fn view<'a>(
+            state: &'a mut State,
+            scrollable: &'a mut scrollable::State,
+        ) -> VirtualList<'a, Message, Null> {
+            let scrollable = Cell::new(Some(scrollable));
+
+            VirtualList::new(state, 1, move |_| {
+                Scrollable::new(scrollable.take().expect("Build row once"))
+                    .id(Id::new("scrollable"))
+                    .height(Length::Units(10))
+                    .push(Checkbox::new(false, "Row", Message::Toggled))
+                    .push(Checkbox::new(false, "Row", Message::Toggled))
+                    .into()
+            })
+        }
+
+        let mut state = State::new();
+        let mut scrollable = scrollable::State::new();
+        let bounds = Size::new(100.0, 100.0);
+
+        let mut user_interface = UserInterface::build(
+            view(&mut state, &mut scrollable),
+            bounds,
+            Cache::new(),
+            &mut Null,
+        );
+        user_interface.draw(&mut Null, Point::ORIGIN);
+
+        let cache = user_interface.into_cache();
+        let mut user_interface = UserInterface::build(
+            view(&mut state, &mut scrollable),
+            bounds,
+            cache,
+            &mut Null,
+        );
+        user_interface.scroll(scrollable::Action::SnapTo(
+            Id::new("scrollable"),
+            scrollable::Offset::Relative(1.0),
+        ));
+        let _ = user_interface.into_cache();
+
+        let viewport = Rectangle::new(Point::ORIGIN, Size::new(100.0, 10.0));
+        let content = Rectangle::new(Point::ORIGIN, Size::new(100.0, 30.0));
+
+        assert_eq!(scrollable.offset(viewport, content), 20);
+    }
+}
@@ -0,0 +1,332 @@
+//! Distribute content horizontally, wrapping it in many lines.
+use std::hash::Hash;
+
+use crate::{
+    focus, keyboard, layout, overlay, Align, Clipboard, Element, Event,
+    EventInteraction, Hasher, Id, Layout, Length, Padding, Point, Widget,
+};
+
+use std::any::Any;
+use std::time::Instant;
+
+/// A container that distributes its contents horizontally, breaking them
+/// onto a new line when they do not fit in its width.
+///
+/// Useful for tag chips, thumbnail galleries and toolbars.
+///
+/// [`Wrap`]: struct.Wrap.html
+#[allow(missing_debug_implementations)]
+pub struct Wrap<'a, Message, Renderer> {
+    spacing: u16,
+    line_spacing: u16,
+    padding: Padding,
+    width: Length,
+    height: Length,
+    max_width: u32,
+    max_height: u32,
+    align_items: Align,
+    align_lines: Align,
+    children: Vec<Element<'a, Message, Renderer>>,
+}
+
+impl<'a, Message, Renderer> Wrap<'a, Message, Renderer> {
+    /// Creates an empty [`Wrap`].
+    ///
+    /// [`Wrap`]: struct.Wrap.html
+    pub fn new() -> Self {
+        Self::with_children(Vec::new())
+    }
+
+    /// Creates a [`Wrap`] with the given elements.
+    ///
+    /// [`Wrap`]: struct.Wrap.html
+    pub fn with_children(
+        children: Vec<Element<'a, Message, Renderer>>,
+    ) -> Self {
+        Wrap {
+            spacing: 0,
+            line_spacing: 0,
+            padding: Padding::ZERO,
+            width: Length::Shrink,
+            height: Length::Shrink,
+            max_width: u32::MAX,
+            max_height: u32::MAX,
+            align_items: Align::Start,
+            align_lines: Align::Start,
+            children,
+        }
+    }
+
+    /// Sets the horizontal spacing _between_ elements of the same line.
+    ///
+    /// Custom margins per element do not exist in Iced. You should use this
+    /// method instead! While less flexible, it helps you keep spacing between
+    /// elements consistent.
+    pub fn spacing(mut self, units: u16) -> Self {
+        self.spacing = units;
+        self
+    }
+
+    /// Sets the vertical spacing _between_ the lines of the [`Wrap`].
+    ///
+    /// [`Wrap`]: struct.Wrap.html
+    pub fn line_spacing(mut self, units: u16) -> Self {
+        self.line_spacing = units;
+        self
+    }
+
+    /// Sets the padding of the [`Wrap`].
+    ///
+    /// [`Wrap`]: struct.Wrap.html
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the width of the [`Wrap`].
+    ///
+    /// [`Wrap`]: struct.Wrap.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Wrap`].
+    ///
+    /// [`Wrap`]: struct.Wrap.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the maximum width of the [`Wrap`].
+    ///
+    /// [`Wrap`]: struct.Wrap.html
+    pub fn max_width(mut self, max_width: u32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the maximum height of the [`Wrap`].
+    ///
+    /// [`Wrap`]: struct.Wrap.html
+    pub fn max_height(mut self, max_height: u32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// Sets the vertical alignment of the contents of every line of the
+    /// [`Wrap`].
+    ///
+    /// [`Wrap`]: struct.Wrap.html
+    pub fn align_items(mut self, align: Align) -> Self {
+        self.align_items = align;
+        self
+    }
+
+    /// Sets the horizontal alignment of every line of the [`Wrap`].
+    ///
+    /// [`Wrap`]: struct.Wrap.html
+    pub fn align_lines(mut self, align: Align) -> Self {
+        self.align_lines = align;
+        self
+    }
+
+    /// Adds an [`Element`] to the [`Wrap`].
+    ///
+    /// [`Element`]: ../struct.Element.html
+    /// [`Wrap`]: struct.Wrap.html
+    pub fn push<E>(mut self, child: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.children.push(child.into());
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Wrap<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits
+            .max_width(self.max_width)
+            .max_height(self.max_height)
+            .width(self.width)
+            .height(self.height);
+
+        layout::wrap::resolve(
+            layout::flex::Axis::Horizontal,
+            renderer,
+            &limits,
+            self.padding,
+            self.spacing as f32,
+            self.line_spacing as f32,
+            self.align_items,
+            self.align_lines,
+            &self.children,
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) -> EventInteraction {
+        self.children.iter_mut().zip(layout.children()).fold(
+            EventInteraction::default(),
+            |interaction, (child, layout)| {
+                child
+                    .widget
+                    .on_event(
+                        event.clone(),
+                        layout,
+                        cursor_position,
+                        messages,
+                        renderer,
+                        clipboard,
+                    )
+                    .union(&interaction)
+            },
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        renderer.draw(defaults, &self.children, layout, cursor_position)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.max_width.hash(state);
+        self.max_height.hash(state);
+        self.align_items.hash(state);
+        self.align_lines.hash(state);
+        self.spacing.hash(state);
+        self.line_spacing.hash(state);
+        self.padding.hash(state);
+
+        for child in &self.children {
+            child.widget.hash_layout(state);
+        }
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        overlay::Group::with_children(
+            self.children
+                .iter_mut()
+                .zip(layout.children())
+                .filter_map(|(child, layout)| child.widget.overlay(layout))
+                .collect(),
+        )
+        .overlay()
+    }
+
+    fn on_shortcut(
+        &mut self,
+        key_code: keyboard::KeyCode,
+        modifiers: keyboard::ModifiersState,
+        messages: &mut Vec<Message>,
+    ) -> EventInteraction {
+        self.children.iter_mut().fold(
+            EventInteraction::default(),
+            |interaction, child| {
+                child
+                    .widget
+                    .on_shortcut(key_code, modifiers, messages)
+                    .union(&interaction)
+            },
+        )
+    }
+
+    fn focusables<'b>(
+        &'b mut self,
+        focusables: &mut Vec<&'b mut dyn focus::Focusable>,
+    ) {
+        for child in &mut self.children {
+            child.widget.focusables(focusables);
+        }
+    }
+
+    fn targets<'b>(&'b mut self, targets: &mut Vec<(&'b Id, &'b mut dyn Any)>) {
+        for child in &mut self.children {
+            child.widget.targets(targets);
+        }
+    }
+
+    fn redraw_request(&self) -> Option<Instant> {
+        self.children
+            .iter()
+            .filter_map(|child| child.widget.redraw_request())
+            .min()
+    }
+}
+
+/// The renderer of a [`Wrap`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Wrap`] in your user interface.
+///
+/// [`Wrap`]: struct.Wrap.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer + Sized {
+    /// Draws a [`Wrap`].
+    ///
+    /// It receives:
+    /// - the children of the [`Wrap`]
+    /// - the [`Layout`] of the [`Wrap`] and its children
+    /// - the cursor position
+    ///
+    /// [`Wrap`]: struct.Wrap.html
+    /// [`Layout`]: ../layout/struct.Layout.html
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        children: &[Element<'_, Message, Self>],
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Wrap<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'a,
+{
+    fn from(
+        wrap: Wrap<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(wrap)
+    }
+}
@@ -12,6 +12,12 @@ pub enum Event {
         height: u32,
     },
 
+    /// The window gained focus.
+    Focused,
+
+    /// The window lost focus.
+    Unfocused,
+
     /// A file is being hovered over the window.
     ///
     /// When the user hovers multiple files at once, this event will be emitted
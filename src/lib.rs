@@ -229,7 +229,11 @@ pub use executor::Executor;
 pub use sandbox::Sandbox;
 pub use settings::Settings;
 
+#[cfg(not(target_arch = "wasm32"))]
+pub use runtime::{animation, clipboard, focus};
+
 pub use runtime::{
-    futures, Align, Background, Color, Command, Font, HorizontalAlignment,
-    Length, Point, Rectangle, Size, Subscription, Vector, VerticalAlignment,
+    futures, Align, AlignItems, Background, Color, Command, Font,
+    HorizontalAlignment, Justify, Length, Padding, Point, Rectangle, Size,
+    Subscription, Vector, VerticalAlignment,
 };
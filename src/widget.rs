@@ -19,10 +19,14 @@
 #[cfg(not(target_arch = "wasm32"))]
 mod platform {
     pub use crate::renderer::widget::{
-        button, checkbox, container, pane_grid, pick_list, progress_bar, radio,
-        scrollable, slider, text_input, Column, Row, Space, Text,
+        button, checkbox, container, context_menu, grid, menu_bar, modal,
+        pane_grid, pick_list, progress_bar, radio, scrollable, slider, table,
+        tabs, text_editor, text_input, tooltip, tree_view, virtual_list,
+        Column, Row, Space, Stack, Text, Wrap,
     };
 
+    pub use crate::runtime::widget::Id;
+
     #[cfg(any(feature = "canvas", feature = "glow_canvas"))]
     #[cfg_attr(
         docsrs,
@@ -44,10 +48,13 @@ mod platform {
 
     #[doc(no_inline)]
     pub use {
-        button::Button, checkbox::Checkbox, container::Container, image::Image,
-        pane_grid::PaneGrid, pick_list::PickList, progress_bar::ProgressBar,
-        radio::Radio, scrollable::Scrollable, slider::Slider, svg::Svg,
-        text_input::TextInput,
+        button::Button, checkbox::Checkbox, container::Container,
+        context_menu::ContextMenu, grid::Grid, image::Image, menu_bar::MenuBar,
+        modal::Modal, pane_grid::PaneGrid, pick_list::PickList,
+        progress_bar::ProgressBar, radio::Radio, scrollable::Scrollable,
+        slider::Slider, svg::Svg, table::Table, tabs::Tabs,
+        text_editor::TextEditor, text_input::TextInput, tooltip::Tooltip,
+        tree_view::TreeView, virtual_list::VirtualList,
     };
 
     #[cfg(any(feature = "canvas", feature = "glow_canvas"))]
@@ -1,4 +1,5 @@
 //! Allow your users to perform actions by pressing a button.
+use iced_core::animation::Interpolate;
 use iced_core::{Background, Color, Vector};
 
 /// The appearance of a button.
@@ -25,6 +26,31 @@ impl std::default::Default for Style {
     }
 }
 
+impl Interpolate for Style {
+    fn interpolate(&self, other: &Self, progress: f32) -> Self {
+        Style {
+            shadow_offset: self
+                .shadow_offset
+                .interpolate(&other.shadow_offset, progress),
+            background: self
+                .background
+                .interpolate(&other.background, progress),
+            border_radius: self
+                .border_radius
+                .interpolate(&other.border_radius, progress),
+            border_width: self
+                .border_width
+                .interpolate(&other.border_width, progress),
+            border_color: self
+                .border_color
+                .interpolate(&other.border_color, progress),
+            text_color: self
+                .text_color
+                .interpolate(&other.text_color, progress),
+        }
+    }
+}
+
 /// A set of rules that dictate the style of a button.
 pub trait StyleSheet {
     fn active(&self) -> Style;
@@ -45,6 +71,10 @@ pub trait StyleSheet {
         }
     }
 
+    fn focused(&self) -> Style {
+        self.hovered()
+    }
+
     fn disabled(&self) -> Style {
         let active = self.active();
 
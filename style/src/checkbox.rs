@@ -1,4 +1,5 @@
 //! Show toggle controls using checkboxes.
+use iced_core::animation::Interpolate;
 use iced_core::{Background, Color};
 
 /// The appearance of a checkbox.
@@ -11,11 +12,37 @@ pub struct Style {
     pub border_color: Color,
 }
 
+impl Interpolate for Style {
+    fn interpolate(&self, other: &Self, progress: f32) -> Self {
+        Style {
+            background: self
+                .background
+                .interpolate(&other.background, progress),
+            checkmark_color: self
+                .checkmark_color
+                .interpolate(&other.checkmark_color, progress),
+            border_radius: self
+                .border_radius
+                .interpolate(&other.border_radius, progress),
+            border_width: self
+                .border_width
+                .interpolate(&other.border_width, progress),
+            border_color: self
+                .border_color
+                .interpolate(&other.border_color, progress),
+        }
+    }
+}
+
 /// A set of rules that dictate the style of a checkbox.
 pub trait StyleSheet {
     fn active(&self, is_checked: bool) -> Style;
 
     fn hovered(&self, is_checked: bool) -> Style;
+
+    fn focused(&self, is_checked: bool) -> Style {
+        self.hovered(is_checked)
+    }
 }
 
 struct Default;
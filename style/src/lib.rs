@@ -8,9 +8,16 @@ pub mod button;
 pub mod checkbox;
 pub mod container;
 pub mod menu;
+pub mod menu_bar;
+pub mod modal;
 pub mod pick_list;
 pub mod progress_bar;
 pub mod radio;
 pub mod scrollable;
 pub mod slider;
+pub mod table;
+pub mod tabs;
+pub mod text_editor;
 pub mod text_input;
+pub mod tooltip;
+pub mod tree_view;
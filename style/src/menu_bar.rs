@@ -0,0 +1,63 @@
+//! Show an application menu bar.
+use crate::menu;
+use iced_core::{Background, Color};
+
+/// The appearance of a menu bar.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub text_color: Color,
+    pub background: Background,
+    pub border_width: u16,
+    pub border_color: Color,
+    pub selected_text_color: Color,
+    pub selected_background: Background,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            text_color: Color::BLACK,
+            background: Background::Color([0.93, 0.93, 0.93].into()),
+            border_width: 0,
+            border_color: Color::TRANSPARENT,
+            selected_text_color: Color::WHITE,
+            selected_background: Background::Color([0.4, 0.4, 1.0].into()),
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a menu bar.
+pub trait StyleSheet {
+    /// Produces the style of the menus opened from the menu bar.
+    fn menu(&self) -> menu::Style;
+
+    /// Produces the style of a menu bar.
+    fn active(&self) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn menu(&self) -> menu::Style {
+        menu::Style::default()
+    }
+
+    fn active(&self) -> Style {
+        Style::default()
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
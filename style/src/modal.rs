@@ -0,0 +1,47 @@
+//! Display a dialog on top of other widgets.
+use iced_core::{Background, Color};
+
+/// The appearance of a modal.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub backdrop: Background,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            backdrop: Background::Color(Color::TRANSPARENT),
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a modal.
+pub trait StyleSheet {
+    /// Produces the style of a modal.
+    fn style(&self) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn style(&self) -> Style {
+        Style {
+            backdrop: Background::Color(Color::from_rgba(0.0, 0.0, 0.0, 0.5)),
+        }
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
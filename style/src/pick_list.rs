@@ -33,6 +33,11 @@ pub trait StyleSheet {
 
     /// Produces the style of a container.
     fn hovered(&self) -> Style;
+
+    /// Produces the style of a focused pick list.
+    fn focused(&self) -> Style {
+        self.hovered()
+    }
 }
 
 struct Default;
@@ -1,4 +1,5 @@
 //! Create choices using radio buttons.
+use iced_core::animation::Interpolate;
 use iced_core::{Background, Color};
 
 /// The appearance of a radio button.
@@ -10,11 +11,32 @@ pub struct Style {
     pub border_color: Color,
 }
 
+impl Interpolate for Style {
+    fn interpolate(&self, other: &Self, progress: f32) -> Self {
+        Style {
+            background: self
+                .background
+                .interpolate(&other.background, progress),
+            dot_color: self.dot_color.interpolate(&other.dot_color, progress),
+            border_width: self
+                .border_width
+                .interpolate(&other.border_width, progress),
+            border_color: self
+                .border_color
+                .interpolate(&other.border_color, progress),
+        }
+    }
+}
+
 /// A set of rules that dictate the style of a radio button.
 pub trait StyleSheet {
     fn active(&self) -> Style;
 
     fn hovered(&self) -> Style;
+
+    fn focused(&self) -> Style {
+        self.hovered()
+    }
 }
 
 struct Default;
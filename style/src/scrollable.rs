@@ -32,6 +32,27 @@ pub trait StyleSheet {
     fn dragging(&self) -> Scrollbar {
         self.hovered()
     }
+
+    /// Produces the style of an active horizontal scrollbar.
+    ///
+    /// By default, it is the same as a vertical one.
+    fn horizontal_active(&self) -> Scrollbar {
+        self.active()
+    }
+
+    /// Produces the style of an hovered horizontal scrollbar.
+    ///
+    /// By default, it is the same as a vertical one.
+    fn horizontal_hovered(&self) -> Scrollbar {
+        self.hovered()
+    }
+
+    /// Produces the style of a horizontal scrollbar that is being dragged.
+    ///
+    /// By default, it is the same as a vertical one.
+    fn horizontal_dragging(&self) -> Scrollbar {
+        self.dragging()
+    }
 }
 
 struct Default;
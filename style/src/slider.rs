@@ -1,4 +1,5 @@
 //! Display an interactive selector of a single value from a range of values.
+use iced_core::animation::Interpolate;
 use iced_core::Color;
 
 /// The appearance of a slider.
@@ -24,6 +25,72 @@ pub enum HandleShape {
     Rectangle { width: u16, border_radius: u16 },
 }
 
+impl Interpolate for Style {
+    fn interpolate(&self, other: &Self, progress: f32) -> Self {
+        Style {
+            rail_colors: (
+                self.rail_colors
+                    .0
+                    .interpolate(&other.rail_colors.0, progress),
+                self.rail_colors
+                    .1
+                    .interpolate(&other.rail_colors.1, progress),
+            ),
+            handle: self.handle.interpolate(&other.handle, progress),
+        }
+    }
+}
+
+impl Interpolate for Handle {
+    fn interpolate(&self, other: &Self, progress: f32) -> Self {
+        Handle {
+            shape: self.shape.interpolate(&other.shape, progress),
+            color: self.color.interpolate(&other.color, progress),
+            border_width: self
+                .border_width
+                .interpolate(&other.border_width, progress),
+            border_color: self
+                .border_color
+                .interpolate(&other.border_color, progress),
+        }
+    }
+}
+
+impl Interpolate for HandleShape {
+    fn interpolate(&self, other: &Self, progress: f32) -> Self {
+        match (*self, *other) {
+            (
+                HandleShape::Circle { radius: a },
+                HandleShape::Circle { radius: b },
+            ) => HandleShape::Circle {
+                radius: a.interpolate(&b, progress),
+            },
+            (
+                HandleShape::Rectangle {
+                    width: width_a,
+                    border_radius: border_radius_a,
+                },
+                HandleShape::Rectangle {
+                    width: width_b,
+                    border_radius: border_radius_b,
+                },
+            ) => HandleShape::Rectangle {
+                width: width_a.interpolate(&width_b, progress),
+                border_radius: border_radius_a
+                    .interpolate(&border_radius_b, progress),
+            },
+            // Different shapes cannot be blended, so we swap them halfway
+            (a, b) => {
+                if progress < 0.5 {
+                    a
+                } else {
+                    b
+                }
+            }
+        }
+    }
+}
+
 /// A set of rules that dictate the style of a slider.
 pub trait StyleSheet {
     /// Produces the style of an active slider.
@@ -34,6 +101,11 @@ pub trait StyleSheet {
 
     /// Produces the style of a slider that is being dragged.
     fn dragging(&self) -> Style;
+
+    /// Produces the style of a focused slider.
+    fn focused(&self) -> Style {
+        self.hovered()
+    }
 }
 
 struct Default;
@@ -0,0 +1,75 @@
+//! Display tabular data with sortable and resizable columns.
+use iced_core::{Background, Color};
+
+/// The appearance of a table.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub text_color: Color,
+    pub background: Background,
+    pub border_width: u16,
+    pub border_color: Color,
+    pub header_text_color: Color,
+    pub header_background: Background,
+    pub hovered_header_background: Background,
+    pub divider_color: Color,
+    pub row_background: Option<Background>,
+    pub alternate_row_background: Option<Background>,
+    pub hovered_row_background: Option<Background>,
+    pub selected_text_color: Color,
+    pub selected_row_background: Background,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            text_color: Color::BLACK,
+            background: Background::Color(Color::WHITE),
+            border_width: 1,
+            border_color: [0.7, 0.7, 0.7].into(),
+            header_text_color: Color::BLACK,
+            header_background: Background::Color([0.93, 0.93, 0.93].into()),
+            hovered_header_background: Background::Color(
+                [0.87, 0.87, 0.87].into(),
+            ),
+            divider_color: [0.8, 0.8, 0.8].into(),
+            row_background: None,
+            alternate_row_background: Some(Background::Color(
+                [0.97, 0.97, 0.97].into(),
+            )),
+            hovered_row_background: Some(Background::Color(
+                [0.92, 0.92, 1.0].into(),
+            )),
+            selected_text_color: Color::WHITE,
+            selected_row_background: Background::Color([0.4, 0.4, 1.0].into()),
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a table.
+pub trait StyleSheet {
+    /// Produces the style of a table.
+    fn active(&self) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style::default()
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -0,0 +1,67 @@
+//! Show one of many contents, switching between them with a tab bar.
+use iced_core::{Background, Color};
+
+/// The appearance of a tab bar.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub bar_background: Background,
+    pub border_width: u16,
+    pub border_color: Color,
+    pub text_color: Color,
+    pub tab_background: Option<Background>,
+    pub hovered_tab_background: Option<Background>,
+    pub active_text_color: Color,
+    pub active_tab_background: Background,
+    pub active_indicator_color: Color,
+    pub close_color: Color,
+    pub hovered_close_background: Background,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            bar_background: Background::Color([0.93, 0.93, 0.93].into()),
+            border_width: 1,
+            border_color: [0.8, 0.8, 0.8].into(),
+            text_color: [0.3, 0.3, 0.3].into(),
+            tab_background: None,
+            hovered_tab_background: Some(Background::Color(
+                [0.87, 0.87, 0.87].into(),
+            )),
+            active_text_color: Color::BLACK,
+            active_tab_background: Background::Color(Color::WHITE),
+            active_indicator_color: [0.4, 0.4, 1.0].into(),
+            close_color: [0.5, 0.5, 0.5].into(),
+            hovered_close_background: Background::Color([0.8, 0.8, 0.8].into()),
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a tab bar.
+pub trait StyleSheet {
+    /// Produces the style of a tab bar.
+    fn active(&self) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style::default()
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
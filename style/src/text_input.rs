@@ -1,4 +1,5 @@
 //! Display fields that can be filled with text.
+use iced_core::animation::Interpolate;
 use iced_core::{Background, Color};
 
 /// The appearance of a text input.
@@ -21,6 +22,25 @@ impl std::default::Default for Style {
     }
 }
 
+impl Interpolate for Style {
+    fn interpolate(&self, other: &Self, progress: f32) -> Self {
+        Style {
+            background: self
+                .background
+                .interpolate(&other.background, progress),
+            border_radius: self
+                .border_radius
+                .interpolate(&other.border_radius, progress),
+            border_width: self
+                .border_width
+                .interpolate(&other.border_width, progress),
+            border_color: self
+                .border_color
+                .interpolate(&other.border_color, progress),
+        }
+    }
+}
+
 /// A set of rules that dictate the style of a text input.
 pub trait StyleSheet {
     /// Produces the style of an active text input.
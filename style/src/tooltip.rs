@@ -0,0 +1,59 @@
+//! Display a hint when hovering over other widgets.
+use iced_core::{Background, Color};
+
+/// The appearance of a tooltip.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub text_color: Option<Color>,
+    pub background: Option<Background>,
+    pub border_radius: u16,
+    pub border_width: u16,
+    pub border_color: Color,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            text_color: None,
+            background: None,
+            border_radius: 0,
+            border_width: 0,
+            border_color: Color::TRANSPARENT,
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a tooltip.
+pub trait StyleSheet {
+    /// Produces the style of a tooltip.
+    fn style(&self) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn style(&self) -> Style {
+        Style {
+            text_color: Some(Color::BLACK),
+            background: Some(Background::Color([0.98, 0.98, 0.98].into())),
+            border_radius: 3,
+            border_width: 1,
+            border_color: [0.7, 0.7, 0.7].into(),
+        }
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
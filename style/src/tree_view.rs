@@ -0,0 +1,71 @@
+//! Display a hierarchy of nodes that can be expanded and collapsed.
+use iced_core::{Background, Color};
+
+/// The appearance of a tree view.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub text_color: Color,
+    pub background: Option<Background>,
+    pub arrow_color: Color,
+    pub guide_color: Color,
+    pub hovered_background: Option<Background>,
+    pub selected_text_color: Color,
+    pub selected_background: Background,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            text_color: Color::BLACK,
+            background: None,
+            arrow_color: [0.4, 0.4, 0.4].into(),
+            guide_color: [0.85, 0.85, 0.85].into(),
+            hovered_background: Some(Background::Color(
+                [0.92, 0.92, 1.0].into(),
+            )),
+            selected_text_color: Color::WHITE,
+            selected_background: Background::Color([0.4, 0.4, 1.0].into()),
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a tree view.
+pub trait StyleSheet {
+    /// Produces the style of a tree view.
+    fn active(&self) -> Style;
+
+    /// Produces the style of a focused tree view.
+    fn focused(&self) -> Style {
+        self.active()
+    }
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style {
+            selected_background: Background::Color([0.6, 0.6, 0.8].into()),
+            ..Style::default()
+        }
+    }
+
+    fn focused(&self) -> Style {
+        Style::default()
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
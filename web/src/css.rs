@@ -1,5 +1,7 @@
 //! Style your widgets.
-use crate::{bumpalo, Align, Background, Color, Length};
+use crate::{
+    bumpalo, Align, AlignItems, Background, Color, Justify, Length, Padding,
+};
 
 use std::collections::BTreeMap;
 
@@ -13,7 +15,7 @@ pub enum Rule {
     Row,
 
     /// Padding of the container
-    Padding(u16),
+    Padding(Padding),
 
     /// Spacing between elements
     Spacing(u16),
@@ -27,7 +29,10 @@ impl Rule {
         match self {
             Rule::Column => String::from("c"),
             Rule::Row => String::from("r"),
-            Rule::Padding(padding) => format!("p-{}", padding),
+            Rule::Padding(padding) => format!(
+                "p-{}-{}-{}-{}",
+                padding.top, padding.right, padding.bottom, padding.left
+            ),
             Rule::Spacing(spacing) => format!("s-{}", spacing),
         }
     }
@@ -51,9 +56,12 @@ impl Rule {
             }
             Rule::Padding(padding) => bumpalo::format!(
                 in bump,
-                ".{} {{ box-sizing: border-box; padding: {}px }}",
+                ".{} {{ box-sizing: border-box; padding: {}px {}px {}px {}px }}",
                 class,
-                padding
+                padding.top,
+                padding.right,
+                padding.bottom,
+                padding.left
             )
             .into_bump_str(),
             Rule::Spacing(spacing) => bumpalo::format!(
@@ -189,3 +197,29 @@ pub fn align(align: Align) -> &'static str {
         Align::End => "flex-end",
     }
 }
+
+/// Returns the style value for the given [`AlignItems`].
+///
+/// [`AlignItems`]: ../enum.AlignItems.html
+pub fn align_items(align_items: AlignItems) -> &'static str {
+    match align_items {
+        AlignItems::Start => "flex-start",
+        AlignItems::Center => "center",
+        AlignItems::End => "flex-end",
+        AlignItems::Stretch => "stretch",
+    }
+}
+
+/// Returns the style value for the given [`Justify`].
+///
+/// [`Justify`]: ../enum.Justify.html
+pub fn justify(justify: Justify) -> &'static str {
+    match justify {
+        Justify::Start => "flex-start",
+        Justify::Center => "center",
+        Justify::End => "flex-end",
+        Justify::SpaceBetween => "space-between",
+        Justify::SpaceAround => "space-around",
+        Justify::SpaceEvenly => "space-evenly",
+    }
+}
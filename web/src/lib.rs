@@ -74,8 +74,9 @@ pub use dodrio;
 pub use element::Element;
 pub use hasher::Hasher;
 pub use iced_core::{
-    keyboard, mouse, Align, Background, Color, Font, HorizontalAlignment,
-    Length, Point, Rectangle, Size, Vector, VerticalAlignment,
+    keyboard, mouse, Align, AlignItems, Background, Color, Font,
+    HorizontalAlignment, Justify, Length, Padding, Point, Rectangle, Size,
+    Vector, VerticalAlignment,
 };
 pub use iced_futures::{executor, futures, Command};
 pub use subscription::Subscription;
@@ -1,4 +1,6 @@
-use crate::{css, Align, Bus, Css, Element, Length, Widget};
+use crate::{
+    css, AlignItems, Bus, Css, Element, Justify, Length, Padding, Widget,
+};
 
 use dodrio::bumpalo;
 use std::u32;
@@ -11,12 +13,13 @@ use std::u32;
 #[allow(missing_debug_implementations)]
 pub struct Column<'a, Message> {
     spacing: u16,
-    padding: u16,
+    padding: Padding,
     width: Length,
     height: Length,
     max_width: u32,
     max_height: u32,
-    align_items: Align,
+    justify_content: Justify,
+    align_items: AlignItems,
     children: Vec<Element<'a, Message>>,
 }
 
@@ -34,12 +37,13 @@ impl<'a, Message> Column<'a, Message> {
     pub fn with_children(children: Vec<Element<'a, Message>>) -> Self {
         Column {
             spacing: 0,
-            padding: 0,
+            padding: Padding::ZERO,
             width: Length::Fill,
             height: Length::Shrink,
             max_width: u32::MAX,
             max_height: u32::MAX,
-            align_items: Align::Start,
+            justify_content: Justify::Start,
+            align_items: AlignItems::Start,
             children,
         }
     }
@@ -57,8 +61,8 @@ impl<'a, Message> Column<'a, Message> {
     /// Sets the padding of the [`Column`].
     ///
     /// [`Column`]: struct.Column.html
-    pub fn padding(mut self, units: u16) -> Self {
-        self.padding = units;
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
         self
     }
 
@@ -94,11 +98,19 @@ impl<'a, Message> Column<'a, Message> {
         self
     }
 
+    /// Sets the vertical distribution of the contents of the [`Column`].
+    ///
+    /// [`Column`]: struct.Column.html
+    pub fn justify_content(mut self, justify: Justify) -> Self {
+        self.justify_content = justify;
+        self
+    }
+
     /// Sets the horizontal alignment of the contents of the [`Column`] .
     ///
     /// [`Column`]: struct.Column.html
-    pub fn align_items(mut self, align: Align) -> Self {
-        self.align_items = align;
+    pub fn align_items<A: Into<AlignItems>>(mut self, align: A) -> Self {
+        self.align_items = align.into();
         self
     }
 
@@ -146,12 +158,13 @@ impl<'a, Message> Widget<Message> for Column<'a, Message> {
             )
             .attr("style", bumpalo::format!(
                     in bump,
-                    "width: {}; height: {}; max-width: {}; max-height: {}; align-items: {}",
+                    "width: {}; height: {}; max-width: {}; max-height: {}; align-items: {}; justify-content: {}",
                     css::length(self.width),
                     css::length(self.height),
                     css::max_length(self.max_width),
                     css::max_length(self.max_height),
-                    css::align(self.align_items)
+                    css::align_items(self.align_items),
+                    css::justify(self.justify_content)
                 ).into_bump_str()
             )
             .children(children)
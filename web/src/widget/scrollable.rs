@@ -1,5 +1,8 @@
 //! Navigate an endless amount of content with a scrollbar.
-use crate::{bumpalo, css, Align, Bus, Column, Css, Element, Length, Widget};
+use crate::{
+    bumpalo, css, AlignItems, Bus, Column, Css, Element, Justify, Length,
+    Padding, Widget,
+};
 
 pub use iced_style::scrollable::{Scrollbar, Scroller, StyleSheet};
 
@@ -44,8 +47,8 @@ impl<'a, Message> Scrollable<'a, Message> {
     /// Sets the padding of the [`Scrollable`].
     ///
     /// [`Scrollable`]: struct.Scrollable.html
-    pub fn padding(mut self, units: u16) -> Self {
-        self.content = self.content.padding(units);
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.content = self.content.padding(padding);
         self
     }
 
@@ -81,10 +84,18 @@ impl<'a, Message> Scrollable<'a, Message> {
         self
     }
 
+    /// Sets the vertical distribution of the contents of the [`Scrollable`].
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn justify_content(mut self, justify: Justify) -> Self {
+        self.content = self.content.justify_content(justify);
+        self
+    }
+
     /// Sets the horizontal alignment of the contents of the [`Scrollable`] .
     ///
     /// [`Scrollable`]: struct.Scrollable.html
-    pub fn align_items(mut self, align_items: Align) -> Self {
+    pub fn align_items<A: Into<AlignItems>>(mut self, align_items: A) -> Self {
         self.content = self.content.align_items(align_items);
         self
     }
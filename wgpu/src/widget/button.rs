@@ -7,7 +7,7 @@
 use crate::Renderer;
 
 pub use iced_graphics::button::{Style, StyleSheet};
-pub use iced_native::button::State;
+pub use iced_native::button::{State, Status};
 
 /// A widget that produces a message when clicked.
 ///
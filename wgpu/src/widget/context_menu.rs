@@ -0,0 +1,18 @@
+//! Open a menu of actions when right-clicking other widgets.
+//!
+//! A [`ContextMenu`] has some local [`State`].
+//!
+//! [`ContextMenu`]: type.ContextMenu.html
+//! [`State`]: struct.State.html
+use crate::Renderer;
+
+pub use iced_graphics::context_menu::{
+    Accelerator, Item, Label, State, Style, Toggle,
+};
+
+/// An element that opens a menu at the cursor position when right-clicked.
+///
+/// This is an alias of an `iced_native` context menu with an
+/// `iced_wgpu::Renderer`.
+pub type ContextMenu<'a, Message> =
+    iced_native::ContextMenu<'a, Message, Renderer>;
@@ -0,0 +1,15 @@
+//! Show one of many contents, switching between them with a tab bar.
+//!
+//! A [`Tabs`] widget has some local [`State`].
+//!
+//! [`Tabs`]: type.Tabs.html
+//! [`State`]: struct.State.html
+use crate::Renderer;
+
+pub use iced_graphics::tabs::{Label, State, StyleSheet, Tab};
+
+/// A tab bar on top of the content of its active tab.
+///
+/// This is an alias of an `iced_native` tabs widget with an
+/// `iced_wgpu::Renderer`.
+pub type Tabs<'a, Message> = iced_native::Tabs<'a, Message, Renderer>;
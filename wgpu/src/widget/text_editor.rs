@@ -0,0 +1,17 @@
+//! Edit multi-line text.
+//!
+//! A [`TextEditor`] has some local [`State`].
+//!
+//! [`TextEditor`]: struct.TextEditor.html
+//! [`State`]: struct.State.html
+use crate::Renderer;
+
+pub use iced_graphics::text_editor::{Style, StyleSheet};
+pub use iced_native::text_editor::State;
+
+/// A field that can be filled with multiple lines of text.
+///
+/// This is an alias of an `iced_native` text editor with an
+/// `iced_wgpu::Renderer`.
+pub type TextEditor<'a, Message> =
+    iced_native::TextEditor<'a, Message, Renderer>;
@@ -7,6 +7,8 @@ use iced_graphics::window;
 use iced_graphics::Viewport;
 use iced_native::program::{self, Program};
 
+use std::time::Instant;
+
 /// An interactive, native cross-platform application.
 ///
 /// This trait is the main entrypoint of Iced. Once implemented, you can run
@@ -131,7 +133,6 @@ pub fn run<A, E, C>(
 
     let flags = settings.flags;
     let (application, init_command) = runtime.enter(|| A::new(flags));
-    runtime.spawn(init_command);
 
     let subscription = application.subscription();
     runtime.track(subscription);
@@ -176,11 +177,26 @@ pub fn run<A, E, C>(
         &mut renderer,
         &mut debug,
     );
+
+    let init_command = state.perform(
+        init_command,
+        viewport.logical_size(),
+        conversion::cursor_position(cursor_position, viewport.scale_factor()),
+        clipboard.as_ref().map(|c| c as _),
+        &mut renderer,
+        &mut debug,
+    );
+    runtime.spawn(init_command);
     debug.startup_finished();
 
     event_loop.run(move |event, _, control_flow| match event {
         event::Event::MainEventsCleared => {
-            if state.is_queue_empty() {
+            let is_redraw_due = state
+                .redraw_request()
+                .map(|at| at <= Instant::now())
+                .unwrap_or(false);
+
+            if state.is_queue_empty() && !is_redraw_due {
                 return;
             }
 
@@ -300,9 +316,6 @@ pub fn run<A, E, C>(
 
                 mouse_interaction = new_mouse_interaction;
             }
-
-            // TODO: Handle animations!
-            // Maybe we can use `ControlFlow::WaitUntil` for this.
         }
         event::Event::WindowEvent {
             event: window_event,
@@ -330,7 +343,10 @@ pub fn run<A, E, C>(
             }
         }
         _ => {
-            *control_flow = ControlFlow::Wait;
+            *control_flow = match state.redraw_request() {
+                Some(at) => ControlFlow::WaitUntil(at),
+                None => ControlFlow::Wait,
+            };
         }
     })
 }
@@ -103,6 +103,12 @@ pub fn window_event(
         WindowEvent::ModifiersChanged(new_modifiers) => Some(Event::Keyboard(
             keyboard::Event::ModifiersChanged(modifiers_state(*new_modifiers)),
         )),
+        WindowEvent::Focused(true) => {
+            Some(Event::Window(window::Event::Focused))
+        }
+        WindowEvent::Focused(false) => {
+            Some(Event::Window(window::Event::Unfocused))
+        }
         WindowEvent::HoveredFile(path) => {
             Some(Event::Window(window::Event::FileHovered(path.clone())))
         }
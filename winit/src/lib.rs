@@ -26,10 +26,10 @@ pub use iced_native::*;
 pub use winit;
 
 pub mod application;
+pub mod clipboard;
 pub mod conversion;
 pub mod settings;
 
-mod clipboard;
 mod mode;
 mod proxy;
 